@@ -21,6 +21,18 @@ const STATICS_TO_IGNORE: &[&str] = &[
     "pub static mut sys_nerr:",
     "pub static mut sys_errlist:",
 ];
+// Fields of the structs kept by `should_strip_struct` whose type is either removed by
+// `first_pass` (anonymous unions) or only declared in newer GSL versions.
+const FIELDS_TO_REWRITE: &[(&str, &str)] = &[
+    (
+        r"^    pub tree: \*mut gsl_bst_workspace,$",
+        "    pub tree: *mut ::std::os::raw::c_void,",
+    ),
+    (
+        r"^    pub work: gsl_spmatrix\w*__bindgen_ty_\d+,$",
+        "    pub work: *mut ::std::os::raw::c_void,",
+    ),
+];
 const VERSIONS: &[(&str, &str)] = &[
     // 2.1
     ("pub fn gsl_multifit_linear_rcond(", "v2_1"),
@@ -28,6 +40,8 @@ const VERSIONS: &[(&str, &str)] = &[
     ("pub fn gsl_multilarge_linear_matrix_ptr(", "v2_7"),
     // --> little exception here...
     ("pub fn gsl_multilarge_linear_rhs_ptr(", "v2_7"),
+    ("pub struct gsl_multilarge_nlinear_fdf {", "v2_1"),
+    ("pub struct gsl_multilarge_nlinear_parameters {", "v2_1"),
     ("pub struct gsl_multilarge_*;", "v2_1"),
     // --> little exception here...
     ("pub fn gsl_multilarge_linear_lcurve(", "v2_2"),
@@ -50,6 +64,25 @@ const VERSIONS: &[(&str, &str)] = &[
     ("pub fn gsl_multifit_linear_tsvd(", "v2_3"),
     ("pub fn gsl_multifit_wlinear_tsvd(", "v2_3"),
     ("pub fn gsl_multifit_linear_rank(", "v2_3"),
+    // --> little exception here...
+    ("pub fn gsl_sf_hermite(", "v2_6"),
+    // --> little exception here...
+    ("pub fn gsl_sf_hermite_e(", "v2_6"),
+    // --> little exception here...
+    ("pub fn gsl_sf_hermite_deriv*(", "v2_6"),
+    // --> little exception here...
+    ("pub fn gsl_sf_hermite_array*(", "v2_6"),
+    // --> little exception here...
+    ("pub fn gsl_sf_hermite_series*(", "v2_6"),
+    // --> little exception here...
+    ("pub fn gsl_sf_hermite_zero*(", "v2_6"),
+    // --> little exception here...
+    ("pub fn gsl_sf_hermite_prob_deriv*(", "v2_6"),
+    // --> little exception here...
+    ("pub fn gsl_sf_hermite_prob_array_deriv(", "v2_6"),
+    // --> little exception here...
+    ("pub fn gsl_sf_hermite_func_fast*(", "v2_6"),
+    ("pub fn gsl_sf_hermite_*(", "v2_3"),
     // 2.5
     ("pub fn gsl_ran_wishart*(", "v2_5"),
     ("pub const gsl_filter_*:", "v2_5"),
@@ -59,6 +92,7 @@ const VERSIONS: &[(&str, &str)] = &[
     ("pub const gsl_movstat_*:", "v2_5"),
     ("pub static mut gsl_movstat_*:", "v2_5"),
     ("pub type gsl_movstat_* = ", "v2_5"),
+    ("pub struct gsl_movstat_function {", "v2_5"),
     ("pub struct gsl_movstat_*;", "v2_5"),
     ("pub fn gsl_movstat_*(", "v2_5"),
     ("pub fn gsl_stats_median(", "v2_5"),
@@ -78,6 +112,9 @@ const VERSIONS: &[(&str, &str)] = &[
     ("pub type gsl_bst_* = ", "v2_6"),
     ("pub struct gsl_bst_*;", "v2_6"),
     ("pub fn gsl_bst_*(", "v2_6"),
+    ("pub const GSL_SPMATRIX_COO:", "v2_6"),
+    ("pub const GSL_SPMATRIX_CSC:", "v2_6"),
+    ("pub const GSL_SPMATRIX_CSR:", "v2_6"),
     ("pub fn gsl_spmatrix_scale_columns(", "v2_6"),
     ("pub fn gsl_spmatrix_scale_rows(", "v2_6"),
     ("pub fn gsl_spmatrix_add_to_dense(", "v2_6"),
@@ -151,7 +188,9 @@ fn create_header_file(folder: &Path) {
 fn should_strip_struct(line: &str) -> bool {
     !line.contains("pub struct gsl_function_struct ")
     && !line.contains("pub struct gsl_multiroot_function_struct")
-    && !line.contains("pub struct gsl_multiroot_fdf_function_struct")
+    && !line.contains("pub struct gsl_multiroot_function_fdf_struct")
+    && !line.contains("pub struct gsl_multimin_function_struct ")
+    && !line.contains("pub struct gsl_multimin_function_fdf_struct ")
     && !line.contains("pub struct gsl_vector")
     && !line.contains("pub struct gsl_ntuple")
     && !line.contains("pub struct gsl_function_fdf_struct ")
@@ -167,6 +206,19 @@ fn should_strip_struct(line: &str) -> bool {
     && !line.contains("pub struct gsl_odeiv2_system ")
     && !line.contains("pub struct gsl_multiset_struct ")
     && !line.contains("pub struct gsl_multifit_fdfsolver ")
+    && !line.contains("pub struct gsl_multifit_nlinear_fdf ")
+    && !line.contains("pub struct gsl_multifit_nlinear_parameters ")
+    && !line.contains("pub struct gsl_multilarge_nlinear_fdf ")
+    && !line.contains("pub struct gsl_multilarge_nlinear_parameters ")
+    && !line.contains("pub struct gsl_rstat_quantile_workspace ")
+    && !line.contains("pub struct gsl_rstat_workspace ")
+    && !line.contains("pub struct gsl_sf_mathieu_workspace ")
+    && !line.contains("pub struct gsl_multifit_robust_stats ")
+    && !line.contains("pub struct gsl_spmatrix ")
+    && !line.contains("pub struct gsl_spmatrix_float ")
+    && !line.contains("pub struct gsl_spmatrix_int ")
+    && !line.contains("pub struct gsl_spmatrix_uint ")
+    && !line.contains("pub struct gsl_movstat_function ")
     && !line.contains("pub struct gsl_matrix")
     && !line.contains("pub struct gsl_sf_result_e10_struct ")
     && !line.contains("pub struct _gsl_matrix_")
@@ -340,6 +392,24 @@ fn clean_structs(content: &mut Vec<String>) {
     }
 }
 
+fn rewrite_fields(content: &mut Vec<String>) {
+    println!("=> Running rewrite_fields...");
+    let fields = FIELDS_TO_REWRITE
+        .iter()
+        .map(|(matcher, replacement)| {
+            (
+                Regex::new(matcher).expect("failed to create regex"),
+                replacement,
+            )
+        })
+        .collect::<Vec<_>>();
+    for line in content.iter_mut() {
+        if let Some((_, replacement)) = fields.iter().find(|(matcher, _)| matcher.is_match(line)) {
+            *line = replacement.to_string();
+        }
+    }
+}
+
 fn run_bindgen(folder: &Path, commit_hash: &str) {
     println!("=> Running bindgen...");
     let bindings = bindgen::Builder::default()
@@ -358,6 +428,7 @@ fn run_bindgen(folder: &Path, commit_hash: &str) {
     let content = bindings.to_string();
     let mut content = first_pass(content.lines().collect::<Vec<_>>());
     clean_structs(&mut content);
+    rewrite_fields(&mut content);
     add_features(&mut content);
 
     let out = "../src/auto.rs";
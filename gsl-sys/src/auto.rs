@@ -16228,7 +16228,12 @@ pub struct gsl_movstat_accum;
 #[derive(Debug, Copy, Clone)]
 #[cfg(feature = "v2_5")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
-pub struct gsl_movstat_function;
+pub struct gsl_movstat_function {
+    pub function: ::std::option::Option<
+        unsafe extern "C" fn(n: usize, x: *mut f64, params: *mut ::std::os::raw::c_void) -> f64,
+    >,
+    pub params: *mut ::std::os::raw::c_void,
+}
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 #[cfg(feature = "v2_5")]
@@ -20771,7 +20776,20 @@ extern "C" {
 pub struct gsl_multifit_robust_type;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-pub struct gsl_multifit_robust_stats;
+pub struct gsl_multifit_robust_stats {
+    pub sigma_ols: f64,
+    pub sigma_mad: f64,
+    pub sigma_rob: f64,
+    pub sigma: f64,
+    pub Rsq: f64,
+    pub adj_Rsq: f64,
+    pub rmse: f64,
+    pub sse: f64,
+    pub dof: usize,
+    pub numit: usize,
+    pub weights: *mut gsl_vector,
+    pub r: *mut gsl_vector,
+}
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct gsl_multifit_robust_workspace;
@@ -21194,7 +21212,36 @@ pub const gsl_multifit_nlinear_fdtype_GSL_MULTIFIT_NLINEAR_CTRDIFF: gsl_multifit
 pub type gsl_multifit_nlinear_fdtype = ::std::os::raw::c_uint;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-pub struct gsl_multifit_nlinear_fdf;
+pub struct gsl_multifit_nlinear_fdf {
+    pub f: ::std::option::Option<
+        unsafe extern "C" fn(
+            x: *const gsl_vector,
+            params: *mut ::std::os::raw::c_void,
+            f: *mut gsl_vector,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub df: ::std::option::Option<
+        unsafe extern "C" fn(
+            x: *const gsl_vector,
+            params: *mut ::std::os::raw::c_void,
+            df: *mut gsl_matrix,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub fvv: ::std::option::Option<
+        unsafe extern "C" fn(
+            x: *const gsl_vector,
+            v: *const gsl_vector,
+            params: *mut ::std::os::raw::c_void,
+            fvv: *mut gsl_vector,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub n: usize,
+    pub p: usize,
+    pub params: *mut ::std::os::raw::c_void,
+    pub nevalf: usize,
+    pub nevaldf: usize,
+    pub nevalfvv: usize,
+}
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct gsl_multifit_nlinear_trs;
@@ -21206,7 +21253,17 @@ pub struct gsl_multifit_nlinear_scale;
 pub struct gsl_multifit_nlinear_solver;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-pub struct gsl_multifit_nlinear_parameters;
+pub struct gsl_multifit_nlinear_parameters {
+    pub trs: *const gsl_multifit_nlinear_trs,
+    pub scale: *const gsl_multifit_nlinear_scale,
+    pub solver: *const gsl_multifit_nlinear_solver,
+    pub fdtype: gsl_multifit_nlinear_fdtype,
+    pub factor_up: f64,
+    pub factor_down: f64,
+    pub avmax: f64,
+    pub h_df: f64,
+    pub h_fvv: f64,
+}
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct gsl_multifit_nlinear_type;
@@ -21605,7 +21662,40 @@ pub type gsl_multilarge_nlinear_fdtype = ::std::os::raw::c_uint;
 #[derive(Debug, Copy, Clone)]
 #[cfg(feature = "v2_1")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
-pub struct gsl_multilarge_nlinear_fdf;
+pub struct gsl_multilarge_nlinear_fdf {
+    pub f: ::std::option::Option<
+        unsafe extern "C" fn(
+            x: *const gsl_vector,
+            params: *mut ::std::os::raw::c_void,
+            f: *mut gsl_vector,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub df: ::std::option::Option<
+        unsafe extern "C" fn(
+            TransJ: CBLAS_TRANSPOSE_t,
+            x: *const gsl_vector,
+            u: *const gsl_vector,
+            params: *mut ::std::os::raw::c_void,
+            v: *mut gsl_vector,
+            JTJ: *mut gsl_matrix,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub fvv: ::std::option::Option<
+        unsafe extern "C" fn(
+            x: *const gsl_vector,
+            v: *const gsl_vector,
+            params: *mut ::std::os::raw::c_void,
+            fvv: *mut gsl_vector,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub n: usize,
+    pub p: usize,
+    pub params: *mut ::std::os::raw::c_void,
+    pub nevalf: usize,
+    pub nevaldfu: usize,
+    pub nevaldf2: usize,
+    pub nevalfvv: usize,
+}
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 #[cfg(feature = "v2_1")]
@@ -21625,7 +21715,19 @@ pub struct gsl_multilarge_nlinear_solver;
 #[derive(Debug, Copy, Clone)]
 #[cfg(feature = "v2_1")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
-pub struct gsl_multilarge_nlinear_parameters;
+pub struct gsl_multilarge_nlinear_parameters {
+    pub trs: *const gsl_multilarge_nlinear_trs,
+    pub scale: *const gsl_multilarge_nlinear_scale,
+    pub solver: *const gsl_multilarge_nlinear_solver,
+    pub fdtype: gsl_multilarge_nlinear_fdtype,
+    pub factor_up: f64,
+    pub factor_down: f64,
+    pub avmax: f64,
+    pub h_df: f64,
+    pub h_fvv: f64,
+    pub max_iter: usize,
+    pub tol: f64,
+}
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 #[cfg(feature = "v2_1")]
@@ -21916,11 +22018,38 @@ extern "C" {
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-pub struct gsl_multimin_function_struct;
+pub struct gsl_multimin_function_struct {
+    pub f: ::std::option::Option<
+        unsafe extern "C" fn(x: *const gsl_vector, params: *mut ::std::os::raw::c_void) -> f64,
+    >,
+    pub n: usize,
+    pub params: *mut ::std::os::raw::c_void,
+}
 pub type gsl_multimin_function = gsl_multimin_function_struct;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-pub struct gsl_multimin_function_fdf_struct;
+pub struct gsl_multimin_function_fdf_struct {
+    pub f: ::std::option::Option<
+        unsafe extern "C" fn(x: *const gsl_vector, params: *mut ::std::os::raw::c_void) -> f64,
+    >,
+    pub df: ::std::option::Option<
+        unsafe extern "C" fn(
+            x: *const gsl_vector,
+            params: *mut ::std::os::raw::c_void,
+            df: *mut gsl_vector,
+        ),
+    >,
+    pub fdf: ::std::option::Option<
+        unsafe extern "C" fn(
+            x: *const gsl_vector,
+            params: *mut ::std::os::raw::c_void,
+            f: *mut f64,
+            df: *mut gsl_vector,
+        ),
+    >,
+    pub n: usize,
+    pub params: *mut ::std::os::raw::c_void,
+}
 pub type gsl_multimin_function_fdf = gsl_multimin_function_fdf_struct;
 extern "C" {
     pub fn gsl_multimin_diff(
@@ -22119,7 +22248,32 @@ extern "C" {
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-pub struct gsl_multiroot_function_fdf_struct;
+pub struct gsl_multiroot_function_fdf_struct {
+    pub f: ::std::option::Option<
+        unsafe extern "C" fn(
+            x: *const gsl_vector,
+            params: *mut ::std::os::raw::c_void,
+            f: *mut gsl_vector,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub df: ::std::option::Option<
+        unsafe extern "C" fn(
+            x: *const gsl_vector,
+            params: *mut ::std::os::raw::c_void,
+            df: *mut gsl_matrix,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub fdf: ::std::option::Option<
+        unsafe extern "C" fn(
+            x: *const gsl_vector,
+            params: *mut ::std::os::raw::c_void,
+            f: *mut gsl_vector,
+            df: *mut gsl_matrix,
+        ) -> ::std::os::raw::c_int,
+    >,
+    pub n: usize,
+    pub params: *mut ::std::os::raw::c_void,
+}
 pub type gsl_multiroot_function_fdf = gsl_multiroot_function_fdf_struct;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -23970,7 +24124,14 @@ extern "C" {
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-pub struct gsl_rstat_quantile_workspace;
+pub struct gsl_rstat_quantile_workspace {
+    pub p: f64,
+    pub q: [f64; 5usize],
+    pub npos: [::std::os::raw::c_int; 5usize],
+    pub np: [f64; 5usize],
+    pub dnp: [f64; 5usize],
+    pub n: usize,
+}
 extern "C" {
     pub fn gsl_rstat_quantile_alloc(p: f64) -> *mut gsl_rstat_quantile_workspace;
 }
@@ -23993,7 +24154,16 @@ extern "C" {
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-pub struct gsl_rstat_workspace;
+pub struct gsl_rstat_workspace {
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    pub M2: f64,
+    pub M3: f64,
+    pub M4: f64,
+    pub n: usize,
+    pub median_workspace_p: *mut gsl_rstat_quantile_workspace,
+}
 extern "C" {
     pub fn gsl_rstat_alloc() -> *mut gsl_rstat_workspace;
 }
@@ -25580,6 +25750,8 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
+    #[cfg(feature = "v2_3")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     pub fn gsl_sf_hermite_prob_e(
         n: ::std::os::raw::c_int,
         x: f64,
@@ -25587,9 +25759,13 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
+    #[cfg(feature = "v2_3")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     pub fn gsl_sf_hermite_prob(n: ::std::os::raw::c_int, x: f64) -> f64;
 }
 extern "C" {
+    #[cfg(feature = "v2_6")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_sf_hermite_prob_deriv_e(
         m: ::std::os::raw::c_int,
         n: ::std::os::raw::c_int,
@@ -25598,6 +25774,8 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
+    #[cfg(feature = "v2_6")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_sf_hermite_prob_deriv(
         m: ::std::os::raw::c_int,
         n: ::std::os::raw::c_int,
@@ -25605,6 +25783,8 @@ extern "C" {
     ) -> f64;
 }
 extern "C" {
+    #[cfg(feature = "v2_6")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_sf_hermite_e(
         n: ::std::os::raw::c_int,
         x: f64,
@@ -25612,9 +25792,13 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
+    #[cfg(feature = "v2_6")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_sf_hermite(n: ::std::os::raw::c_int, x: f64) -> f64;
 }
 extern "C" {
+    #[cfg(feature = "v2_6")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_sf_hermite_deriv_e(
         m: ::std::os::raw::c_int,
         n: ::std::os::raw::c_int,
@@ -25623,9 +25807,13 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
+    #[cfg(feature = "v2_6")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_sf_hermite_deriv(m: ::std::os::raw::c_int, n: ::std::os::raw::c_int, x: f64) -> f64;
 }
 extern "C" {
+    #[cfg(feature = "v2_3")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     pub fn gsl_sf_hermite_func_e(
         n: ::std::os::raw::c_int,
         x: f64,
@@ -25633,9 +25821,13 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
+    #[cfg(feature = "v2_3")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     pub fn gsl_sf_hermite_func(n: ::std::os::raw::c_int, x: f64) -> f64;
 }
 extern "C" {
+    #[cfg(feature = "v2_6")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_sf_hermite_func_fast_e(
         n: ::std::os::raw::c_int,
         x: f64,
@@ -25643,9 +25835,13 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
+    #[cfg(feature = "v2_6")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_sf_hermite_func_fast(n: ::std::os::raw::c_int, x: f64) -> f64;
 }
 extern "C" {
+    #[cfg(feature = "v2_3")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     pub fn gsl_sf_hermite_prob_array(
         nmax: ::std::os::raw::c_int,
         x: f64,
@@ -25653,6 +25849,8 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
+    #[cfg(feature = "v2_6")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_sf_hermite_prob_array_deriv(
         m: ::std::os::raw::c_int,
         nmax: ::std::os::raw::c_int,
@@ -25661,6 +25859,8 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
+    #[cfg(feature = "v2_6")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_sf_hermite_prob_deriv_array(
         mmax: ::std::os::raw::c_int,
         n: ::std::os::raw::c_int,
@@ -25669,6 +25869,8 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
+    #[cfg(feature = "v2_3")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     pub fn gsl_sf_hermite_prob_series_e(
         n: ::std::os::raw::c_int,
         x: f64,
@@ -25677,9 +25879,13 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
+    #[cfg(feature = "v2_3")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     pub fn gsl_sf_hermite_prob_series(n: ::std::os::raw::c_int, x: f64, a: *const f64) -> f64;
 }
 extern "C" {
+    #[cfg(feature = "v2_6")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_sf_hermite_array(
         nmax: ::std::os::raw::c_int,
         x: f64,
@@ -25687,6 +25893,8 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
+    #[cfg(feature = "v2_6")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_sf_hermite_array_deriv(
         m: ::std::os::raw::c_int,
         nmax: ::std::os::raw::c_int,
@@ -25695,6 +25903,8 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
+    #[cfg(feature = "v2_6")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_sf_hermite_deriv_array(
         mmax: ::std::os::raw::c_int,
         n: ::std::os::raw::c_int,
@@ -25703,6 +25913,8 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
+    #[cfg(feature = "v2_6")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_sf_hermite_series_e(
         n: ::std::os::raw::c_int,
         x: f64,
@@ -25711,9 +25923,13 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
+    #[cfg(feature = "v2_6")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_sf_hermite_series(n: ::std::os::raw::c_int, x: f64, a: *const f64) -> f64;
 }
 extern "C" {
+    #[cfg(feature = "v2_3")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     pub fn gsl_sf_hermite_func_array(
         nmax: ::std::os::raw::c_int,
         x: f64,
@@ -25721,6 +25937,8 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
+    #[cfg(feature = "v2_3")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     pub fn gsl_sf_hermite_func_series_e(
         n: ::std::os::raw::c_int,
         x: f64,
@@ -25729,9 +25947,13 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
+    #[cfg(feature = "v2_3")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     pub fn gsl_sf_hermite_func_series(n: ::std::os::raw::c_int, x: f64, a: *const f64) -> f64;
 }
 extern "C" {
+    #[cfg(feature = "v2_3")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     pub fn gsl_sf_hermite_func_der_e(
         m: ::std::os::raw::c_int,
         n: ::std::os::raw::c_int,
@@ -25740,6 +25962,8 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
+    #[cfg(feature = "v2_3")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     pub fn gsl_sf_hermite_func_der(
         m: ::std::os::raw::c_int,
         n: ::std::os::raw::c_int,
@@ -25747,6 +25971,8 @@ extern "C" {
     ) -> f64;
 }
 extern "C" {
+    #[cfg(feature = "v2_3")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     pub fn gsl_sf_hermite_prob_zero_e(
         n: ::std::os::raw::c_int,
         s: ::std::os::raw::c_int,
@@ -25754,9 +25980,13 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
+    #[cfg(feature = "v2_3")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     pub fn gsl_sf_hermite_prob_zero(n: ::std::os::raw::c_int, s: ::std::os::raw::c_int) -> f64;
 }
 extern "C" {
+    #[cfg(feature = "v2_6")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_sf_hermite_zero_e(
         n: ::std::os::raw::c_int,
         s: ::std::os::raw::c_int,
@@ -25764,9 +25994,13 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
+    #[cfg(feature = "v2_6")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
     pub fn gsl_sf_hermite_zero(n: ::std::os::raw::c_int, s: ::std::os::raw::c_int) -> f64;
 }
 extern "C" {
+    #[cfg(feature = "v2_3")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     pub fn gsl_sf_hermite_func_zero_e(
         n: ::std::os::raw::c_int,
         s: ::std::os::raw::c_int,
@@ -25774,9 +26008,13 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
+    #[cfg(feature = "v2_3")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     pub fn gsl_sf_hermite_func_zero(n: ::std::os::raw::c_int, s: ::std::os::raw::c_int) -> f64;
 }
 extern "C" {
+    #[cfg(feature = "v2_3")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     pub fn gsl_sf_hermite_phys_e(
         n: ::std::os::raw::c_int,
         x: f64,
@@ -25784,9 +26022,13 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
+    #[cfg(feature = "v2_3")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     pub fn gsl_sf_hermite_phys(n: ::std::os::raw::c_int, x: f64) -> f64;
 }
 extern "C" {
+    #[cfg(feature = "v2_3")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     pub fn gsl_sf_hermite_phys_der_e(
         m: ::std::os::raw::c_int,
         n: ::std::os::raw::c_int,
@@ -25795,6 +26037,8 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
+    #[cfg(feature = "v2_3")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     pub fn gsl_sf_hermite_phys_der(
         m: ::std::os::raw::c_int,
         n: ::std::os::raw::c_int,
@@ -25802,6 +26046,8 @@ extern "C" {
     ) -> f64;
 }
 extern "C" {
+    #[cfg(feature = "v2_3")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     pub fn gsl_sf_hermite_phys_array(
         nmax: ::std::os::raw::c_int,
         x: f64,
@@ -25809,6 +26055,8 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
+    #[cfg(feature = "v2_3")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     pub fn gsl_sf_hermite_phys_series_e(
         n: ::std::os::raw::c_int,
         x: f64,
@@ -25817,9 +26065,13 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
+    #[cfg(feature = "v2_3")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     pub fn gsl_sf_hermite_phys_series(n: ::std::os::raw::c_int, x: f64, a: *const f64) -> f64;
 }
 extern "C" {
+    #[cfg(feature = "v2_3")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     pub fn gsl_sf_hermite_phys_array_der(
         m: ::std::os::raw::c_int,
         nmax: ::std::os::raw::c_int,
@@ -25828,6 +26080,8 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
+    #[cfg(feature = "v2_3")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     pub fn gsl_sf_hermite_phys_der_array(
         mmax: ::std::os::raw::c_int,
         n: ::std::os::raw::c_int,
@@ -25836,6 +26090,8 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
+    #[cfg(feature = "v2_3")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     pub fn gsl_sf_hermite_phys_zero_e(
         n: ::std::os::raw::c_int,
         s: ::std::os::raw::c_int,
@@ -25843,9 +26099,13 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
+    #[cfg(feature = "v2_3")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     pub fn gsl_sf_hermite_phys_zero(n: ::std::os::raw::c_int, s: ::std::os::raw::c_int) -> f64;
 }
 extern "C" {
+    #[cfg(feature = "v2_3")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     pub fn gsl_sf_hermite_prob_array_der(
         m: ::std::os::raw::c_int,
         nmax: ::std::os::raw::c_int,
@@ -25854,6 +26114,8 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
+    #[cfg(feature = "v2_3")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     pub fn gsl_sf_hermite_prob_der_array(
         mmax: ::std::os::raw::c_int,
         n: ::std::os::raw::c_int,
@@ -25862,6 +26124,8 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
+    #[cfg(feature = "v2_3")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     pub fn gsl_sf_hermite_prob_der_e(
         m: ::std::os::raw::c_int,
         n: ::std::os::raw::c_int,
@@ -25870,6 +26134,8 @@ extern "C" {
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
+    #[cfg(feature = "v2_3")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     pub fn gsl_sf_hermite_prob_der(
         m: ::std::os::raw::c_int,
         n: ::std::os::raw::c_int,
@@ -26424,7 +26690,24 @@ extern "C" {
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-pub struct gsl_sf_mathieu_workspace;
+pub struct gsl_sf_mathieu_workspace {
+    pub size: usize,
+    pub even_order: usize,
+    pub odd_order: usize,
+    pub extra_values: ::std::os::raw::c_int,
+    pub qa: f64,
+    pub qb: f64,
+    pub aa: *mut f64,
+    pub bb: *mut f64,
+    pub dd: *mut f64,
+    pub ee: *mut f64,
+    pub tt: *mut f64,
+    pub e2: *mut f64,
+    pub zz: *mut f64,
+    pub eval: *mut gsl_vector,
+    pub evec: *mut gsl_matrix,
+    pub wmat: *mut gsl_eigen_symmv_workspace,
+}
 extern "C" {
     pub fn gsl_sf_mathieu_a_array(
         order_min: ::std::os::raw::c_int,
@@ -27938,8 +28221,14 @@ extern "C" {
         v: *const gsl_vector_char,
     ) -> ::std::os::raw::c_int;
 }
+#[cfg(feature = "v2_6")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
 pub const GSL_SPMATRIX_COO: ::std::os::raw::c_uint = 0;
+#[cfg(feature = "v2_6")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
 pub const GSL_SPMATRIX_CSC: ::std::os::raw::c_uint = 1;
+#[cfg(feature = "v2_6")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
 pub const GSL_SPMATRIX_CSR: ::std::os::raw::c_uint = 2;
 pub const GSL_SPMATRIX_TRIPLET: ::std::os::raw::c_uint = 0;
 pub const GSL_SPMATRIX_CCS: ::std::os::raw::c_uint = 1;
@@ -28774,7 +29063,21 @@ extern "C" {
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
-pub struct gsl_spmatrix;
+pub struct gsl_spmatrix {
+    pub size1: usize,
+    pub size2: usize,
+    pub i: *mut ::std::os::raw::c_int,
+    pub data: *mut f64,
+    pub p: *mut ::std::os::raw::c_int,
+    pub nzmax: usize,
+    pub nz: usize,
+    pub tree: *mut ::std::os::raw::c_void,
+    pub pool: *mut gsl_spmatrix_pool,
+    pub node_size: usize,
+    pub work: *mut ::std::os::raw::c_void,
+    pub sptype: ::std::os::raw::c_int,
+    pub spflags: usize,
+}
 extern "C" {
     pub fn gsl_spmatrix_alloc(n1: usize, n2: usize) -> *mut gsl_spmatrix;
 }
@@ -28962,7 +29265,21 @@ extern "C" {
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
-pub struct gsl_spmatrix_float;
+pub struct gsl_spmatrix_float {
+    pub size1: usize,
+    pub size2: usize,
+    pub i: *mut ::std::os::raw::c_int,
+    pub data: *mut f32,
+    pub p: *mut ::std::os::raw::c_int,
+    pub nzmax: usize,
+    pub nz: usize,
+    pub tree: *mut ::std::os::raw::c_void,
+    pub pool: *mut gsl_spmatrix_pool,
+    pub node_size: usize,
+    pub work: *mut ::std::os::raw::c_void,
+    pub sptype: ::std::os::raw::c_int,
+    pub spflags: usize,
+}
 extern "C" {
     pub fn gsl_spmatrix_float_alloc(n1: usize, n2: usize) -> *mut gsl_spmatrix_float;
 }
@@ -29557,7 +29874,21 @@ extern "C" {
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
-pub struct gsl_spmatrix_uint;
+pub struct gsl_spmatrix_uint {
+    pub size1: usize,
+    pub size2: usize,
+    pub i: *mut ::std::os::raw::c_int,
+    pub data: *mut ::std::os::raw::c_uint,
+    pub p: *mut ::std::os::raw::c_int,
+    pub nzmax: usize,
+    pub nz: usize,
+    pub tree: *mut ::std::os::raw::c_void,
+    pub pool: *mut gsl_spmatrix_pool,
+    pub node_size: usize,
+    pub work: *mut ::std::os::raw::c_void,
+    pub sptype: ::std::os::raw::c_int,
+    pub spflags: usize,
+}
 extern "C" {
     pub fn gsl_spmatrix_uint_alloc(n1: usize, n2: usize) -> *mut gsl_spmatrix_uint;
 }
@@ -29759,7 +30090,21 @@ extern "C" {
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
-pub struct gsl_spmatrix_int;
+pub struct gsl_spmatrix_int {
+    pub size1: usize,
+    pub size2: usize,
+    pub i: *mut ::std::os::raw::c_int,
+    pub data: *mut ::std::os::raw::c_int,
+    pub p: *mut ::std::os::raw::c_int,
+    pub nzmax: usize,
+    pub nz: usize,
+    pub tree: *mut ::std::os::raw::c_void,
+    pub pool: *mut gsl_spmatrix_pool,
+    pub node_size: usize,
+    pub work: *mut ::std::os::raw::c_void,
+    pub sptype: ::std::os::raw::c_int,
+    pub spflags: usize,
+}
 extern "C" {
     pub fn gsl_spmatrix_int_alloc(n1: usize, n2: usize) -> *mut gsl_spmatrix_int;
 }
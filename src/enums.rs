@@ -669,6 +669,46 @@ impl From<sys::gsl_filter_end_t> for FilterEnd {
     }
 }
 
+/// How the moving window statistics handle the windows near the end points of the signal, where
+/// fewer than H samples are available on one side of the window.
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+pub enum MovstatEnd {
+    /// Inserts zeros into the window near the signal end points.
+    PadZero,
+    /// Pads the window with the first and last sample in the input signal.
+    PadValue,
+    /// No padding is performed, the windows are truncated as the end points are approached.
+    Truncate,
+}
+
+#[doc(hidden)]
+#[allow(clippy::from_over_into)]
+#[cfg(feature = "v2_5")]
+impl Into<sys::gsl_movstat_end_t> for MovstatEnd {
+    fn into(self) -> sys::gsl_movstat_end_t {
+        match self {
+            Self::PadZero => sys::gsl_movstat_end_t_GSL_MOVSTAT_END_PADZERO,
+            Self::PadValue => sys::gsl_movstat_end_t_GSL_MOVSTAT_END_PADVALUE,
+            Self::Truncate => sys::gsl_movstat_end_t_GSL_MOVSTAT_END_TRUNCATE,
+        }
+    }
+}
+
+#[doc(hidden)]
+#[cfg(feature = "v2_5")]
+impl From<sys::gsl_movstat_end_t> for MovstatEnd {
+    fn from(v: sys::gsl_movstat_end_t) -> MovstatEnd {
+        match v {
+            sys::gsl_movstat_end_t_GSL_MOVSTAT_END_PADZERO => Self::PadZero,
+            sys::gsl_movstat_end_t_GSL_MOVSTAT_END_PADVALUE => Self::PadValue,
+            sys::gsl_movstat_end_t_GSL_MOVSTAT_END_TRUNCATE => Self::Truncate,
+            _ => panic!("Unknown MovstatEnd value"),
+        }
+    }
+}
+
 #[cfg(feature = "v2_5")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
 #[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
@@ -706,3 +746,146 @@ impl From<sys::gsl_filter_scale_t> for FilterScale {
         }
     }
 }
+
+/// Finite difference method used to approximate the Jacobian when no analytic one is provided.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+pub enum MultiFitNlinearFdType {
+    /// Forward finite differences, requiring p function evaluations.
+    Forward,
+    /// Centered finite differences, more accurate but requiring 2p function evaluations.
+    Central,
+}
+
+#[doc(hidden)]
+#[allow(clippy::from_over_into)]
+impl Into<sys::gsl_multifit_nlinear_fdtype> for MultiFitNlinearFdType {
+    fn into(self) -> sys::gsl_multifit_nlinear_fdtype {
+        match self {
+            Self::Forward => sys::gsl_multifit_nlinear_fdtype_GSL_MULTIFIT_NLINEAR_FWDIFF,
+            Self::Central => sys::gsl_multifit_nlinear_fdtype_GSL_MULTIFIT_NLINEAR_CTRDIFF,
+        }
+    }
+}
+
+#[doc(hidden)]
+impl From<sys::gsl_multifit_nlinear_fdtype> for MultiFitNlinearFdType {
+    fn from(v: sys::gsl_multifit_nlinear_fdtype) -> MultiFitNlinearFdType {
+        match v {
+            sys::gsl_multifit_nlinear_fdtype_GSL_MULTIFIT_NLINEAR_FWDIFF => Self::Forward,
+            sys::gsl_multifit_nlinear_fdtype_GSL_MULTIFIT_NLINEAR_CTRDIFF => Self::Central,
+            _ => panic!("Unknown MultiFitNlinearFdType value"),
+        }
+    }
+}
+
+/// Finite difference method used to approximate the Jacobian products when no analytic ones are
+/// provided.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+#[cfg(feature = "v2_1")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
+pub enum MultilargeNlinearFdType {
+    /// Forward finite differences.
+    Forward,
+    /// Centered finite differences, more accurate but requiring twice as many function
+    /// evaluations.
+    Central,
+}
+
+#[doc(hidden)]
+#[allow(clippy::from_over_into)]
+#[cfg(feature = "v2_1")]
+impl Into<sys::gsl_multilarge_nlinear_fdtype> for MultilargeNlinearFdType {
+    fn into(self) -> sys::gsl_multilarge_nlinear_fdtype {
+        match self {
+            Self::Forward => sys::gsl_multilarge_nlinear_fdtype_GSL_MULTILARGE_NLINEAR_FWDIFF,
+            Self::Central => sys::gsl_multilarge_nlinear_fdtype_GSL_MULTILARGE_NLINEAR_CTRDIFF,
+        }
+    }
+}
+
+#[doc(hidden)]
+#[cfg(feature = "v2_1")]
+impl From<sys::gsl_multilarge_nlinear_fdtype> for MultilargeNlinearFdType {
+    fn from(v: sys::gsl_multilarge_nlinear_fdtype) -> MultilargeNlinearFdType {
+        match v {
+            sys::gsl_multilarge_nlinear_fdtype_GSL_MULTILARGE_NLINEAR_FWDIFF => Self::Forward,
+            sys::gsl_multilarge_nlinear_fdtype_GSL_MULTILARGE_NLINEAR_CTRDIFF => Self::Central,
+            _ => panic!("Unknown MultilargeNlinearFdType value"),
+        }
+    }
+}
+
+/// Weighting function used by the robust linear regression.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+pub enum MultifitRobustType {
+    /// Bisquare weighting, w(e) = (1 - e^2)^2 for |e| <= 1 and 0 otherwise. This is the default
+    /// and is a good general purpose choice.
+    Bisquare,
+    /// Cauchy weighting, w(e) = 1 / (1 + e^2). Convergence is not guaranteed.
+    Cauchy,
+    /// Fair weighting, w(e) = 1 / (1 + |e|). Convergence is guaranteed.
+    Fair,
+    /// Huber weighting, w(e) = 1 for |e| <= 1 and 1 / |e| otherwise. Convergence is guaranteed.
+    Huber,
+    /// Ordinary least squares, w(e) = 1. This is useful to compare against a robust fit.
+    Ols,
+    /// Welsch weighting, w(e) = exp(-e^2). Convergence is not guaranteed.
+    Welsch,
+}
+
+#[doc(hidden)]
+#[allow(clippy::from_over_into)]
+impl Into<*const sys::gsl_multifit_robust_type> for MultifitRobustType {
+    fn into(self) -> *const sys::gsl_multifit_robust_type {
+        unsafe {
+            match self {
+                Self::Bisquare => sys::gsl_multifit_robust_bisquare,
+                Self::Cauchy => sys::gsl_multifit_robust_cauchy,
+                Self::Fair => sys::gsl_multifit_robust_fair,
+                Self::Huber => sys::gsl_multifit_robust_huber,
+                Self::Ols => sys::gsl_multifit_robust_ols,
+                Self::Welsch => sys::gsl_multifit_robust_welsch,
+            }
+        }
+    }
+}
+
+/// Storage format of a sparse matrix.
+#[cfg(feature = "v2_6")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
+#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+pub enum SpMatrixFormat {
+    /// Coordinate (triplet) format, where each nonzero element is stored with its row and column
+    /// index. This is the only format in which elements can be added.
+    Coo,
+    /// Compressed sparse column format.
+    Csc,
+    /// Compressed sparse row format.
+    Csr,
+}
+
+#[cfg(feature = "v2_6")]
+#[doc(hidden)]
+#[allow(clippy::from_over_into)]
+impl Into<c_int> for SpMatrixFormat {
+    fn into(self) -> c_int {
+        (match self {
+            Self::Coo => sys::GSL_SPMATRIX_COO,
+            Self::Csc => sys::GSL_SPMATRIX_CSC,
+            Self::Csr => sys::GSL_SPMATRIX_CSR,
+        }) as c_int
+    }
+}
+
+#[cfg(feature = "v2_6")]
+#[doc(hidden)]
+impl From<c_int> for SpMatrixFormat {
+    fn from(v: c_int) -> SpMatrixFormat {
+        match v as u32 {
+            sys::GSL_SPMATRIX_COO => Self::Coo,
+            sys::GSL_SPMATRIX_CSC => Self::Csc,
+            sys::GSL_SPMATRIX_CSR => Self::Csr,
+            _ => panic!("Unknown SpMatrixFormat value"),
+        }
+    }
+}
@@ -3,12 +3,21 @@
 //
 
 /*!
-Linear Regression
+# Linear Regression
 
 The functions described in this section can be used to perform least-squares fits to a straight line model, Y(c,x) = c_0 + c_1 x.
+
+# Nonlinear Curve Fitting
+
+[`curve_fit`] and [`curve_fit_jac`] fit an arbitrary model y = model(x, params) to a dataset with
+the nonlinear least squares trust region solver, and return the best-fit parameters together with
+their covariance matrix and standard errors.
 !*/
 
-use crate::Value;
+use crate::{
+    MatrixF64, MultiFitNlinearFdf, MultiFitNlinearParameters, MultiFitNlinearType,
+    MultiFitNlinearWorkspace, Value, VectorF64,
+};
 
 /// This function computes the best-fit linear regression coefficients (c0,c1) of the model
 /// Y = c_0 + c_1 X for the dataset (x, y), two vectors of length n with strides xstride and
@@ -202,3 +211,224 @@ pub fn mul_est(x: f64, c1: f64, cov11: f64) -> (Value, f64, f64) {
     let ret = unsafe { ::sys::gsl_fit_mul_est(x, c1, cov11, &mut y, &mut y_err) };
     (Value::from(ret), y, y_err)
 }
+
+/// Result of a nonlinear fit performed by [`curve_fit`] or [`curve_fit_jac`].
+#[derive(Debug)]
+pub struct CurveFit {
+    /// Best-fit parameters.
+    pub params: Vec<f64>,
+    /// Covariance matrix of the best-fit parameters.
+    pub covariance: MatrixF64,
+    /// Standard errors of the best-fit parameters, i.e. the square roots of the diagonal of
+    /// `covariance`.
+    pub std_errors: Vec<f64>,
+    /// Weighted sum of squared residuals \chi^2 = \sum_i ((y_i - model(x_i)) / \sigma_i)^2.
+    pub chisq: f64,
+    /// Number of degrees of freedom, n - p.
+    pub dof: usize,
+    /// \chi^2 / dof.
+    pub reduced_chisq: f64,
+    /// Number of iterations performed by the solver.
+    pub niter: usize,
+    /// Convergence status returned by the solver: `Value::Success` if the fit converged,
+    /// `Value::MaxIteration` if the iteration limit was reached, or the error which stopped the
+    /// iteration (e.g. `Value::NoProgress`).
+    pub status: Value,
+}
+
+/// This function fits the nonlinear model y = model(x, params) to the dataset (xs, ys) starting
+/// from the initial guess `p0`, using the trust region solver of
+/// [`MultiFitNlinearWorkspace`](crate::MultiFitNlinearWorkspace). The Jacobian is approximated
+/// with finite differences; use [`curve_fit_jac`] to provide it analytically.
+///
+/// If `sigma` is given, it contains the standard deviation of each y value and the fit is
+/// weighted by 1 / \sigma_i^2. The covariance matrix is then (J^T W J)^{-1}. Without `sigma`,
+/// the errors are estimated from the scatter of the points around the fit and the covariance
+/// matrix is scaled by the reduced \chi^2.
+///
+/// Returns `Err(Value::BadLength)` if the lengths of `xs`, `ys` and `sigma` differ,
+/// `Err(Value::Invalid)` if a `sigma` is not positive and finite, if `p0` is empty or if there
+/// are not more points than parameters (the
+/// reduced \chi^2 needs at least one degree of freedom), `Err(Value::NoMemory)` if the solver
+/// could not be allocated, and the error code of the solver if its initialization or the
+/// computation of the covariance matrix fails.
+pub fn curve_fit<F: Fn(f64, &[f64]) -> f64>(
+    model: F,
+    xs: &[f64],
+    ys: &[f64],
+    sigma: Option<&[f64]>,
+    p0: &[f64],
+) -> Result<CurveFit, Value> {
+    fit_nlinear(
+        model,
+        None::<fn(f64, &[f64], &mut [f64])>,
+        xs,
+        ys,
+        sigma,
+        p0,
+    )
+}
+
+/// Same as [`curve_fit`], but with the derivatives of the model provided by `jac`. It is called
+/// as `jac(x, params, row)` and must store d model(x, params) / d params_j in `row[j]`.
+pub fn curve_fit_jac<F: Fn(f64, &[f64]) -> f64, J: Fn(f64, &[f64], &mut [f64])>(
+    model: F,
+    jac: J,
+    xs: &[f64],
+    ys: &[f64],
+    sigma: Option<&[f64]>,
+    p0: &[f64],
+) -> Result<CurveFit, Value> {
+    fit_nlinear(model, Some(jac), xs, ys, sigma, p0)
+}
+
+fn fit_nlinear<F: Fn(f64, &[f64]) -> f64, J: Fn(f64, &[f64], &mut [f64])>(
+    model: F,
+    jac: Option<J>,
+    xs: &[f64],
+    ys: &[f64],
+    sigma: Option<&[f64]>,
+    p0: &[f64],
+) -> Result<CurveFit, Value> {
+    let n = xs.len();
+    let p = p0.len();
+    if ys.len() != n || sigma.map(|s| s.len() != n).unwrap_or(false) {
+        return Err(Value::BadLength);
+    }
+    if p == 0 || n <= p || sigma.is_some_and(|s| s.iter().any(|s| !(s.is_finite() && *s > 0.))) {
+        return Err(Value::Invalid);
+    }
+
+    let mut params = vec![0.; p];
+    let mut fdf = MultiFitNlinearFdf::new(n, p, move |x, f| {
+        for (j, param) in params.iter_mut().enumerate() {
+            *param = x.get(j);
+        }
+        for i in 0..n {
+            f.set(i, model(xs[i], &params) - ys[i]);
+        }
+        Value::Success
+    });
+    if let Some(jac) = jac {
+        let mut params = vec![0.; p];
+        let mut row = vec![0.; p];
+        fdf = fdf.df(move |x, J| {
+            for (j, param) in params.iter_mut().enumerate() {
+                *param = x.get(j);
+            }
+            for (i, xi) in xs.iter().enumerate() {
+                jac(*xi, &params, &mut row);
+                for (j, v) in row.iter().enumerate() {
+                    J.set(i, j, *v);
+                }
+            }
+            Value::Success
+        });
+    }
+
+    let mut w = MultiFitNlinearWorkspace::new(
+        &MultiFitNlinearType::trust(),
+        &MultiFitNlinearParameters::new(),
+        n,
+        p,
+    )
+    .ok_or(Value::NoMemory)?;
+    let x = VectorF64::from_slice(p0).ok_or(Value::NoMemory)?;
+    let init = match sigma {
+        Some(sigma) => {
+            let wts: Vec<f64> = sigma.iter().map(|s| 1. / (s * s)).collect();
+            let wts = VectorF64::from_slice(&wts).ok_or(Value::NoMemory)?;
+            w.winit(&x, &wts, fdf)
+        }
+        None => w.init(&x, fdf),
+    };
+    if init != Value::Success {
+        return Err(init);
+    }
+    let status = w.driver(1000, 1e-8, 1e-8, 0.).0;
+
+    let residual = w.residual();
+    let chisq = (0..n).map(|i| residual.get(i).powi(2)).sum::<f64>();
+    let dof = n - p;
+    let reduced_chisq = chisq / dof as f64;
+
+    let mut covariance = MatrixF64::new(p, p).ok_or(Value::NoMemory)?;
+    let ret = w.covar(0., &mut covariance);
+    if ret != Value::Success {
+        return Err(ret);
+    }
+    if sigma.is_none() {
+        covariance.scale(reduced_chisq);
+    }
+
+    let position = w.position();
+    Ok(CurveFit {
+        params: (0..p).map(|i| position.get(i)).collect(),
+        std_errors: (0..p).map(|i| covariance.get(i, i).sqrt()).collect(),
+        covariance,
+        chisq,
+        dof,
+        reduced_chisq,
+        niter: w.niter(),
+        status,
+    })
+}
+
+#[cfg(any(test, doctest))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_curve_fit() {
+        let xs: Vec<f64> = (0..40).map(|i| i as f64 * 0.1).collect();
+        let ys: Vec<f64> = xs.iter().map(|x| 5. * (-1.5 * x).exp() + 1.).collect();
+        let model = |x: f64, p: &[f64]| p[0] * (-p[1] * x).exp() + p[2];
+
+        let fit = curve_fit(model, &xs, &ys, None, &[1., 1., 0.]).unwrap();
+        assert_eq!(fit.status, Value::Success);
+        assert_eq!(fit.dof, 37);
+        assert!((fit.params[0] - 5.).abs() < 1e-6);
+        assert!((fit.params[1] - 1.5).abs() < 1e-6);
+        assert!((fit.params[2] - 1.).abs() < 1e-6);
+        assert!(fit.chisq < 1e-10);
+
+        let sigma = vec![0.1; xs.len()];
+        let fit = curve_fit_jac(
+            model,
+            |x, p, row| {
+                let e = (-p[1] * x).exp();
+                row[0] = e;
+                row[1] = -x * p[0] * e;
+                row[2] = 1.;
+            },
+            &xs,
+            &ys,
+            Some(&sigma),
+            &[1., 1., 0.],
+        )
+        .unwrap();
+        assert_eq!(fit.status, Value::Success);
+        assert!((fit.params[1] - 1.5).abs() < 1e-6);
+        assert!(fit.std_errors.iter().all(|e| *e > 0.));
+
+        assert_eq!(
+            curve_fit(model, &xs, &ys[1..], None, &[1., 1., 0.]).unwrap_err(),
+            Value::BadLength
+        );
+        assert_eq!(
+            curve_fit(model, &xs[..3], &ys[..3], None, &[1., 1., 0.]).unwrap_err(),
+            Value::Invalid
+        );
+        let mut sigma = vec![0.1; xs.len()];
+        sigma[3] = 0.;
+        assert_eq!(
+            curve_fit(model, &xs, &ys, Some(&sigma), &[1., 1., 0.]).unwrap_err(),
+            Value::Invalid
+        );
+        sigma[3] = f64::NAN;
+        assert_eq!(
+            curve_fit(model, &xs, &ys, Some(&sigma), &[1., 1., 0.]).unwrap_err(),
+            Value::Invalid
+        );
+    }
+}
@@ -0,0 +1,517 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+Hermite polynomials and functions are discussed in Abramowitz & Stegun, Chapter 22 and Szego, Gabor (1939, 1958, 1967), Orthogonal Polynomials, American Mathematical Society.
+
+The physicist version of the Hermite polynomials H_n(x) is defined by H_n(x) = (-1)^n e^{x^2} (d/dx)^n e^{-x^2}, and the probabilist version He_n(x) by He_n(x) = (-1)^n e^{x^2/2} (d/dx)^n e^{-x^2/2}. They are related by H_n(x) = 2^{n/2} He_n(\sqrt{2} x) and He_n(x) = 2^{-n/2} H_n(x / \sqrt{2}).
+
+The Hermite functions are defined by \psi_n(x) = (2^n n! \sqrt{\pi})^{-1/2} e^{-x^2/2} H_n(x). They are the eigenfunctions of the quantum harmonic oscillator and are normalized with respect to the L^2 norm.
+
+The array functions compute all the values up to `nmax` (or `mmax`), which is given by the length of `result_array` minus one.
+!*/
+
+use crate::Value;
+use std::mem::MaybeUninit;
+
+/// This function evaluates the probabilist Hermite polynomial He_n(x) of order n at position x.
+#[doc(alias = "gsl_sf_hermite_prob")]
+pub fn hermite_prob(n: i32, x: f64) -> f64 {
+    unsafe { sys::gsl_sf_hermite_prob(n, x) }
+}
+
+/// This function evaluates the probabilist Hermite polynomial He_n(x) of order n at position x.
+#[doc(alias = "gsl_sf_hermite_prob_e")]
+pub fn hermite_prob_e(n: i32, x: f64) -> (Value, ::types::Result) {
+    let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let ret = unsafe { sys::gsl_sf_hermite_prob_e(n, x, result.as_mut_ptr()) };
+
+    (::Value::from(ret), unsafe { result.assume_init() }.into())
+}
+
+/// This function evaluates the physicist Hermite polynomial H_n(x) of order n at position x.
+#[cfg(feature = "v2_6")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
+#[doc(alias = "gsl_sf_hermite")]
+pub fn hermite(n: i32, x: f64) -> f64 {
+    unsafe { sys::gsl_sf_hermite(n, x) }
+}
+
+/// This function evaluates the physicist Hermite polynomial H_n(x) of order n at position x.
+#[cfg(feature = "v2_6")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
+#[doc(alias = "gsl_sf_hermite_e")]
+pub fn hermite_e(n: i32, x: f64) -> (Value, ::types::Result) {
+    let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let ret = unsafe { sys::gsl_sf_hermite_e(n, x, result.as_mut_ptr()) };
+
+    (::Value::from(ret), unsafe { result.assume_init() }.into())
+}
+
+/// This function evaluates the physicist Hermite polynomial H_n(x) of order n at position x. It is the older name of [`hermite`].
+#[doc(alias = "gsl_sf_hermite_phys")]
+pub fn hermite_phys(n: i32, x: f64) -> f64 {
+    unsafe { sys::gsl_sf_hermite_phys(n, x) }
+}
+
+/// This function evaluates the physicist Hermite polynomial H_n(x) of order n at position x. It is the older name of [`hermite`].
+#[doc(alias = "gsl_sf_hermite_phys_e")]
+pub fn hermite_phys_e(n: i32, x: f64) -> (Value, ::types::Result) {
+    let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let ret = unsafe { sys::gsl_sf_hermite_phys_e(n, x, result.as_mut_ptr()) };
+
+    (::Value::from(ret), unsafe { result.assume_init() }.into())
+}
+
+/// This function evaluates the Hermite function \psi_n(x) of order n at position x. A recurrence relation is used, which is stable for large n.
+#[doc(alias = "gsl_sf_hermite_func")]
+pub fn hermite_func(n: i32, x: f64) -> f64 {
+    unsafe { sys::gsl_sf_hermite_func(n, x) }
+}
+
+/// This function evaluates the Hermite function \psi_n(x) of order n at position x. A recurrence relation is used, which is stable for large n.
+#[doc(alias = "gsl_sf_hermite_func_e")]
+pub fn hermite_func_e(n: i32, x: f64) -> (Value, ::types::Result) {
+    let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let ret = unsafe { sys::gsl_sf_hermite_func_e(n, x, result.as_mut_ptr()) };
+
+    (::Value::from(ret), unsafe { result.assume_init() }.into())
+}
+
+/// This function evaluates the Hermite function \psi_n(x) of order n at position x, using the Cauchy integral algorithm of Bunck (2009) for large n, which is faster than [`hermite_func`] but less accurate.
+#[cfg(feature = "v2_6")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
+#[doc(alias = "gsl_sf_hermite_func_fast")]
+pub fn hermite_func_fast(n: i32, x: f64) -> f64 {
+    unsafe { sys::gsl_sf_hermite_func_fast(n, x) }
+}
+
+/// This function evaluates the Hermite function \psi_n(x) of order n at position x, using the Cauchy integral algorithm of Bunck (2009) for large n, which is faster than [`hermite_func`] but less accurate.
+#[cfg(feature = "v2_6")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
+#[doc(alias = "gsl_sf_hermite_func_fast_e")]
+pub fn hermite_func_fast_e(n: i32, x: f64) -> (Value, ::types::Result) {
+    let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let ret = unsafe { sys::gsl_sf_hermite_func_fast_e(n, x, result.as_mut_ptr()) };
+
+    (::Value::from(ret), unsafe { result.assume_init() }.into())
+}
+
+/// This function evaluates the m-th derivative of the probabilist Hermite polynomial He_n(x) of order n at position x.
+#[cfg(feature = "v2_6")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
+#[doc(alias = "gsl_sf_hermite_prob_deriv")]
+pub fn hermite_prob_deriv(m: i32, n: i32, x: f64) -> f64 {
+    unsafe { sys::gsl_sf_hermite_prob_deriv(m, n, x) }
+}
+
+/// This function evaluates the m-th derivative of the probabilist Hermite polynomial He_n(x) of order n at position x.
+#[cfg(feature = "v2_6")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
+#[doc(alias = "gsl_sf_hermite_prob_deriv_e")]
+pub fn hermite_prob_deriv_e(m: i32, n: i32, x: f64) -> (Value, ::types::Result) {
+    let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let ret = unsafe { sys::gsl_sf_hermite_prob_deriv_e(m, n, x, result.as_mut_ptr()) };
+
+    (::Value::from(ret), unsafe { result.assume_init() }.into())
+}
+
+/// This function evaluates the m-th derivative of the probabilist Hermite polynomial He_n(x) of order n at position x. It is the older name of [`hermite_prob_deriv`].
+#[doc(alias = "gsl_sf_hermite_prob_der")]
+pub fn hermite_prob_der(m: i32, n: i32, x: f64) -> f64 {
+    unsafe { sys::gsl_sf_hermite_prob_der(m, n, x) }
+}
+
+/// This function evaluates the m-th derivative of the probabilist Hermite polynomial He_n(x) of order n at position x. It is the older name of [`hermite_prob_deriv`].
+#[doc(alias = "gsl_sf_hermite_prob_der_e")]
+pub fn hermite_prob_der_e(m: i32, n: i32, x: f64) -> (Value, ::types::Result) {
+    let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let ret = unsafe { sys::gsl_sf_hermite_prob_der_e(m, n, x, result.as_mut_ptr()) };
+
+    (::Value::from(ret), unsafe { result.assume_init() }.into())
+}
+
+/// This function evaluates the m-th derivative of the physicist Hermite polynomial H_n(x) of order n at position x.
+#[cfg(feature = "v2_6")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
+#[doc(alias = "gsl_sf_hermite_deriv")]
+pub fn hermite_deriv(m: i32, n: i32, x: f64) -> f64 {
+    unsafe { sys::gsl_sf_hermite_deriv(m, n, x) }
+}
+
+/// This function evaluates the m-th derivative of the physicist Hermite polynomial H_n(x) of order n at position x.
+#[cfg(feature = "v2_6")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
+#[doc(alias = "gsl_sf_hermite_deriv_e")]
+pub fn hermite_deriv_e(m: i32, n: i32, x: f64) -> (Value, ::types::Result) {
+    let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let ret = unsafe { sys::gsl_sf_hermite_deriv_e(m, n, x, result.as_mut_ptr()) };
+
+    (::Value::from(ret), unsafe { result.assume_init() }.into())
+}
+
+/// This function evaluates the m-th derivative of the physicist Hermite polynomial H_n(x) of order n at position x. It is the older name of [`hermite_deriv`].
+#[doc(alias = "gsl_sf_hermite_phys_der")]
+pub fn hermite_phys_der(m: i32, n: i32, x: f64) -> f64 {
+    unsafe { sys::gsl_sf_hermite_phys_der(m, n, x) }
+}
+
+/// This function evaluates the m-th derivative of the physicist Hermite polynomial H_n(x) of order n at position x. It is the older name of [`hermite_deriv`].
+#[doc(alias = "gsl_sf_hermite_phys_der_e")]
+pub fn hermite_phys_der_e(m: i32, n: i32, x: f64) -> (Value, ::types::Result) {
+    let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let ret = unsafe { sys::gsl_sf_hermite_phys_der_e(m, n, x, result.as_mut_ptr()) };
+
+    (::Value::from(ret), unsafe { result.assume_init() }.into())
+}
+
+/// This function evaluates the m-th derivative of the Hermite function \psi_n(x) of order n at position x.
+#[doc(alias = "gsl_sf_hermite_func_der")]
+pub fn hermite_func_der(m: i32, n: i32, x: f64) -> f64 {
+    unsafe { sys::gsl_sf_hermite_func_der(m, n, x) }
+}
+
+/// This function evaluates the m-th derivative of the Hermite function \psi_n(x) of order n at position x.
+#[doc(alias = "gsl_sf_hermite_func_der_e")]
+pub fn hermite_func_der_e(m: i32, n: i32, x: f64) -> (Value, ::types::Result) {
+    let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let ret = unsafe { sys::gsl_sf_hermite_func_der_e(m, n, x, result.as_mut_ptr()) };
+
+    (::Value::from(ret), unsafe { result.assume_init() }.into())
+}
+
+/// This function evaluates all probabilist Hermite polynomials He_n(x) up to order nmax at position x. The results are stored in `result_array`.
+#[doc(alias = "gsl_sf_hermite_prob_array")]
+pub fn hermite_prob_array(x: f64, result_array: &mut [f64]) -> Value {
+    Value::from(unsafe {
+        sys::gsl_sf_hermite_prob_array(result_array.len() as i32 - 1, x, result_array.as_mut_ptr())
+    })
+}
+
+/// This function evaluates all physicist Hermite polynomials H_n(x) up to order nmax at position x. The results are stored in `result_array`.
+#[cfg(feature = "v2_6")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
+#[doc(alias = "gsl_sf_hermite_array")]
+pub fn hermite_array(x: f64, result_array: &mut [f64]) -> Value {
+    Value::from(unsafe {
+        sys::gsl_sf_hermite_array(result_array.len() as i32 - 1, x, result_array.as_mut_ptr())
+    })
+}
+
+/// This function evaluates all physicist Hermite polynomials H_n(x) up to order nmax at position x. The results are stored in `result_array`. It is the older name of [`hermite_array`].
+#[doc(alias = "gsl_sf_hermite_phys_array")]
+pub fn hermite_phys_array(x: f64, result_array: &mut [f64]) -> Value {
+    Value::from(unsafe {
+        sys::gsl_sf_hermite_phys_array(result_array.len() as i32 - 1, x, result_array.as_mut_ptr())
+    })
+}
+
+/// This function evaluates all Hermite functions \psi_n(x) up to order nmax at position x. The results are stored in `result_array`.
+#[doc(alias = "gsl_sf_hermite_func_array")]
+pub fn hermite_func_array(x: f64, result_array: &mut [f64]) -> Value {
+    Value::from(unsafe {
+        sys::gsl_sf_hermite_func_array(result_array.len() as i32 - 1, x, result_array.as_mut_ptr())
+    })
+}
+
+/// This function evaluates the m-th derivative of all probabilist Hermite polynomials He_n(x) up to order nmax at position x. The results are stored in `result_array`.
+#[cfg(feature = "v2_6")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
+#[doc(alias = "gsl_sf_hermite_prob_array_deriv")]
+pub fn hermite_prob_array_deriv(m: i32, x: f64, result_array: &mut [f64]) -> Value {
+    Value::from(unsafe {
+        sys::gsl_sf_hermite_prob_array_deriv(
+            m,
+            result_array.len() as i32 - 1,
+            x,
+            result_array.as_mut_ptr(),
+        )
+    })
+}
+
+/// This function evaluates the m-th derivative of all probabilist Hermite polynomials He_n(x) up to order nmax at position x. The results are stored in `result_array`. It is the older name of [`hermite_prob_array_deriv`].
+#[doc(alias = "gsl_sf_hermite_prob_array_der")]
+pub fn hermite_prob_array_der(m: i32, x: f64, result_array: &mut [f64]) -> Value {
+    Value::from(unsafe {
+        sys::gsl_sf_hermite_prob_array_der(
+            m,
+            result_array.len() as i32 - 1,
+            x,
+            result_array.as_mut_ptr(),
+        )
+    })
+}
+
+/// This function evaluates the m-th derivative of all physicist Hermite polynomials H_n(x) up to order nmax at position x. The results are stored in `result_array`.
+#[cfg(feature = "v2_6")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
+#[doc(alias = "gsl_sf_hermite_array_deriv")]
+pub fn hermite_array_deriv(m: i32, x: f64, result_array: &mut [f64]) -> Value {
+    Value::from(unsafe {
+        sys::gsl_sf_hermite_array_deriv(
+            m,
+            result_array.len() as i32 - 1,
+            x,
+            result_array.as_mut_ptr(),
+        )
+    })
+}
+
+/// This function evaluates the m-th derivative of all physicist Hermite polynomials H_n(x) up to order nmax at position x. The results are stored in `result_array`. It is the older name of [`hermite_array_deriv`].
+#[doc(alias = "gsl_sf_hermite_phys_array_der")]
+pub fn hermite_phys_array_der(m: i32, x: f64, result_array: &mut [f64]) -> Value {
+    Value::from(unsafe {
+        sys::gsl_sf_hermite_phys_array_der(
+            m,
+            result_array.len() as i32 - 1,
+            x,
+            result_array.as_mut_ptr(),
+        )
+    })
+}
+
+/// This function evaluates all derivatives (starting from 0) up to the mmax-th derivative of the probabilist Hermite polynomial He_n(x) of order n at position x. The results are stored in `result_array`.
+#[cfg(feature = "v2_6")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
+#[doc(alias = "gsl_sf_hermite_prob_deriv_array")]
+pub fn hermite_prob_deriv_array(n: i32, x: f64, result_array: &mut [f64]) -> Value {
+    Value::from(unsafe {
+        sys::gsl_sf_hermite_prob_deriv_array(
+            result_array.len() as i32 - 1,
+            n,
+            x,
+            result_array.as_mut_ptr(),
+        )
+    })
+}
+
+/// This function evaluates all derivatives (starting from 0) up to the mmax-th derivative of the probabilist Hermite polynomial He_n(x) of order n at position x. The results are stored in `result_array`. It is the older name of [`hermite_prob_deriv_array`].
+#[doc(alias = "gsl_sf_hermite_prob_der_array")]
+pub fn hermite_prob_der_array(n: i32, x: f64, result_array: &mut [f64]) -> Value {
+    Value::from(unsafe {
+        sys::gsl_sf_hermite_prob_der_array(
+            result_array.len() as i32 - 1,
+            n,
+            x,
+            result_array.as_mut_ptr(),
+        )
+    })
+}
+
+/// This function evaluates all derivatives (starting from 0) up to the mmax-th derivative of the physicist Hermite polynomial H_n(x) of order n at position x. The results are stored in `result_array`.
+#[cfg(feature = "v2_6")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
+#[doc(alias = "gsl_sf_hermite_deriv_array")]
+pub fn hermite_deriv_array(n: i32, x: f64, result_array: &mut [f64]) -> Value {
+    Value::from(unsafe {
+        sys::gsl_sf_hermite_deriv_array(
+            result_array.len() as i32 - 1,
+            n,
+            x,
+            result_array.as_mut_ptr(),
+        )
+    })
+}
+
+/// This function evaluates all derivatives (starting from 0) up to the mmax-th derivative of the physicist Hermite polynomial H_n(x) of order n at position x. The results are stored in `result_array`. It is the older name of [`hermite_deriv_array`].
+#[doc(alias = "gsl_sf_hermite_phys_der_array")]
+pub fn hermite_phys_der_array(n: i32, x: f64, result_array: &mut [f64]) -> Value {
+    Value::from(unsafe {
+        sys::gsl_sf_hermite_phys_der_array(
+            result_array.len() as i32 - 1,
+            n,
+            x,
+            result_array.as_mut_ptr(),
+        )
+    })
+}
+
+/// This function evaluates the series \sum_{j=0}^n a_j He_j(x) with He_j being the j-th probabilist Hermite polynomial, using the Clenshaw algorithm. The order n is given by the length of `a` minus one.
+#[doc(alias = "gsl_sf_hermite_prob_series")]
+pub fn hermite_prob_series(x: f64, a: &[f64]) -> f64 {
+    unsafe { sys::gsl_sf_hermite_prob_series(a.len() as i32 - 1, x, a.as_ptr()) }
+}
+
+/// This function evaluates the series \sum_{j=0}^n a_j He_j(x) with He_j being the j-th probabilist Hermite polynomial, using the Clenshaw algorithm. The order n is given by the length of `a` minus one.
+#[doc(alias = "gsl_sf_hermite_prob_series_e")]
+pub fn hermite_prob_series_e(x: f64, a: &[f64]) -> (Value, ::types::Result) {
+    let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let ret = unsafe {
+        sys::gsl_sf_hermite_prob_series_e(a.len() as i32 - 1, x, a.as_ptr(), result.as_mut_ptr())
+    };
+
+    (::Value::from(ret), unsafe { result.assume_init() }.into())
+}
+
+/// This function evaluates the series \sum_{j=0}^n a_j H_j(x) with H_j being the j-th physicist Hermite polynomial, using the Clenshaw algorithm. The order n is given by the length of `a` minus one.
+#[cfg(feature = "v2_6")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
+#[doc(alias = "gsl_sf_hermite_series")]
+pub fn hermite_series(x: f64, a: &[f64]) -> f64 {
+    unsafe { sys::gsl_sf_hermite_series(a.len() as i32 - 1, x, a.as_ptr()) }
+}
+
+/// This function evaluates the series \sum_{j=0}^n a_j H_j(x) with H_j being the j-th physicist Hermite polynomial, using the Clenshaw algorithm. The order n is given by the length of `a` minus one.
+#[cfg(feature = "v2_6")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
+#[doc(alias = "gsl_sf_hermite_series_e")]
+pub fn hermite_series_e(x: f64, a: &[f64]) -> (Value, ::types::Result) {
+    let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let ret = unsafe {
+        sys::gsl_sf_hermite_series_e(a.len() as i32 - 1, x, a.as_ptr(), result.as_mut_ptr())
+    };
+
+    (::Value::from(ret), unsafe { result.assume_init() }.into())
+}
+
+/// This function evaluates the series \sum_{j=0}^n a_j H_j(x) with H_j being the j-th physicist Hermite polynomial, using the Clenshaw algorithm. The order n is given by the length of `a` minus one. It is the older name of [`hermite_series`].
+#[doc(alias = "gsl_sf_hermite_phys_series")]
+pub fn hermite_phys_series(x: f64, a: &[f64]) -> f64 {
+    unsafe { sys::gsl_sf_hermite_phys_series(a.len() as i32 - 1, x, a.as_ptr()) }
+}
+
+/// This function evaluates the series \sum_{j=0}^n a_j H_j(x) with H_j being the j-th physicist Hermite polynomial, using the Clenshaw algorithm. The order n is given by the length of `a` minus one. It is the older name of [`hermite_series`].
+#[doc(alias = "gsl_sf_hermite_phys_series_e")]
+pub fn hermite_phys_series_e(x: f64, a: &[f64]) -> (Value, ::types::Result) {
+    let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let ret = unsafe {
+        sys::gsl_sf_hermite_phys_series_e(a.len() as i32 - 1, x, a.as_ptr(), result.as_mut_ptr())
+    };
+
+    (::Value::from(ret), unsafe { result.assume_init() }.into())
+}
+
+/// This function evaluates the series \sum_{j=0}^n a_j \psi_j(x) with \psi_j being the j-th Hermite function, using the Clenshaw algorithm. The order n is given by the length of `a` minus one.
+#[doc(alias = "gsl_sf_hermite_func_series")]
+pub fn hermite_func_series(x: f64, a: &[f64]) -> f64 {
+    unsafe { sys::gsl_sf_hermite_func_series(a.len() as i32 - 1, x, a.as_ptr()) }
+}
+
+/// This function evaluates the series \sum_{j=0}^n a_j \psi_j(x) with \psi_j being the j-th Hermite function, using the Clenshaw algorithm. The order n is given by the length of `a` minus one.
+#[doc(alias = "gsl_sf_hermite_func_series_e")]
+pub fn hermite_func_series_e(x: f64, a: &[f64]) -> (Value, ::types::Result) {
+    let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let ret = unsafe {
+        sys::gsl_sf_hermite_func_series_e(a.len() as i32 - 1, x, a.as_ptr(), result.as_mut_ptr())
+    };
+
+    (::Value::from(ret), unsafe { result.assume_init() }.into())
+}
+
+/// This function returns the s-th zero of the probabilist Hermite polynomial He_n(x) of order n. Only positive zeros are returned, counted from 1: by symmetry, the negative zeros are their opposites and, for odd n, zero is also a root.
+#[doc(alias = "gsl_sf_hermite_prob_zero")]
+pub fn hermite_prob_zero(n: i32, s: i32) -> f64 {
+    unsafe { sys::gsl_sf_hermite_prob_zero(n, s) }
+}
+
+/// This function returns the s-th zero of the probabilist Hermite polynomial He_n(x) of order n. Only positive zeros are returned, counted from 1: by symmetry, the negative zeros are their opposites and, for odd n, zero is also a root.
+#[doc(alias = "gsl_sf_hermite_prob_zero_e")]
+pub fn hermite_prob_zero_e(n: i32, s: i32) -> (Value, ::types::Result) {
+    let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let ret = unsafe { sys::gsl_sf_hermite_prob_zero_e(n, s, result.as_mut_ptr()) };
+
+    (::Value::from(ret), unsafe { result.assume_init() }.into())
+}
+
+/// This function returns the s-th zero of the physicist Hermite polynomial H_n(x) of order n. Only positive zeros are returned, counted from 1: by symmetry, the negative zeros are their opposites and, for odd n, zero is also a root.
+#[cfg(feature = "v2_6")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
+#[doc(alias = "gsl_sf_hermite_zero")]
+pub fn hermite_zero(n: i32, s: i32) -> f64 {
+    unsafe { sys::gsl_sf_hermite_zero(n, s) }
+}
+
+/// This function returns the s-th zero of the physicist Hermite polynomial H_n(x) of order n. Only positive zeros are returned, counted from 1: by symmetry, the negative zeros are their opposites and, for odd n, zero is also a root.
+#[cfg(feature = "v2_6")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
+#[doc(alias = "gsl_sf_hermite_zero_e")]
+pub fn hermite_zero_e(n: i32, s: i32) -> (Value, ::types::Result) {
+    let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let ret = unsafe { sys::gsl_sf_hermite_zero_e(n, s, result.as_mut_ptr()) };
+
+    (::Value::from(ret), unsafe { result.assume_init() }.into())
+}
+
+/// This function returns the s-th zero of the physicist Hermite polynomial H_n(x) of order n. It is the older name of [`hermite_zero`].
+#[doc(alias = "gsl_sf_hermite_phys_zero")]
+pub fn hermite_phys_zero(n: i32, s: i32) -> f64 {
+    unsafe { sys::gsl_sf_hermite_phys_zero(n, s) }
+}
+
+/// This function returns the s-th zero of the physicist Hermite polynomial H_n(x) of order n. It is the older name of [`hermite_zero`].
+#[doc(alias = "gsl_sf_hermite_phys_zero_e")]
+pub fn hermite_phys_zero_e(n: i32, s: i32) -> (Value, ::types::Result) {
+    let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let ret = unsafe { sys::gsl_sf_hermite_phys_zero_e(n, s, result.as_mut_ptr()) };
+
+    (::Value::from(ret), unsafe { result.assume_init() }.into())
+}
+
+/// This function returns the s-th zero of the Hermite function \psi_n(x) of order n. Only positive zeros are returned, counted from 1: by symmetry, the negative zeros are their opposites and, for odd n, zero is also a root.
+#[doc(alias = "gsl_sf_hermite_func_zero")]
+pub fn hermite_func_zero(n: i32, s: i32) -> f64 {
+    unsafe { sys::gsl_sf_hermite_func_zero(n, s) }
+}
+
+/// This function returns the s-th zero of the Hermite function \psi_n(x) of order n. Only positive zeros are returned, counted from 1: by symmetry, the negative zeros are their opposites and, for odd n, zero is also a root.
+#[doc(alias = "gsl_sf_hermite_func_zero_e")]
+pub fn hermite_func_zero_e(n: i32, s: i32) -> (Value, ::types::Result) {
+    let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+    let ret = unsafe { sys::gsl_sf_hermite_func_zero_e(n, s, result.as_mut_ptr()) };
+
+    (::Value::from(ret), unsafe { result.assume_init() }.into())
+}
+
+#[cfg(any(test, doctest))]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() <= 1e-10 * b.abs().max(1.), "{} != {}", a, b);
+    }
+
+    #[test]
+    fn test_hermite() {
+        // H_3(x) = 8x^3 - 12x and He_3(x) = x^3 - 3x
+        assert_close(hermite_phys(3, 0.5), -5.);
+        assert_close(hermite_prob(3, 0.5), -1.375);
+        assert_close(hermite_phys_der(1, 3, 0.5), 24. * 0.25 - 12.);
+        let (ret, res) = hermite_phys_e(3, 2.);
+        assert_eq!(ret, Value::Success);
+        assert_close(res.val, 40.);
+
+        let mut array = [0.; 4];
+        assert_eq!(hermite_phys_array(0.5, &mut array), Value::Success);
+        assert_close(array[0], 1.);
+        assert_close(array[1], 1.);
+        assert_close(array[2], -1.);
+        assert_close(array[3], -5.);
+
+        // 2 H_0 + 3 H_3
+        assert_close(hermite_phys_series(0.5, &[2., 0., 0., 3.]), 2. - 15.);
+
+        // psi_0(x) = pi^{-1/4} e^{-x^2/2}
+        let pi = ::std::f64::consts::PI;
+        assert_close(hermite_func(0, 1.), pi.powf(-0.25) * (-0.5f64).exp());
+
+        // The positive zero of H_2(x) = 4x^2 - 2 is 1 / sqrt(2)
+        assert_close(hermite_phys_zero(2, 1), 0.5f64.sqrt());
+        assert_close(hermite_prob_zero(2, 1), 1.);
+    }
+
+    #[test]
+    #[cfg(feature = "v2_6")]
+    fn test_hermite_renamed() {
+        assert_close(hermite(3, 0.5), hermite_phys(3, 0.5));
+        assert_close(hermite_deriv(1, 3, 0.5), hermite_phys_der(1, 3, 0.5));
+        assert_close(hermite_prob_deriv(2, 3, 0.5), hermite_prob_der(2, 3, 0.5));
+        assert_close(hermite_series(0.5, &[2., 0., 0., 3.]), 2. - 15.);
+        assert_close(hermite_zero(2, 1), 0.5f64.sqrt());
+        assert_close(hermite_func_fast(4, 0.3), hermite_func(4, 0.3));
+
+        let mut array = [0.; 4];
+        assert_eq!(hermite_array(0.5, &mut array), Value::Success);
+        assert_close(array[3], -5.);
+    }
+}
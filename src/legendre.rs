@@ -225,6 +225,469 @@ pub mod associated_polynomials {
             )
         })
     }
+
+    /// Returns the number of (l, m) pairs for 0 <= m <= l <= lmax, i.e. (lmax + 1) (lmax + 2) / 2.
+    #[doc(alias = "gsl_sf_legendre_nlm")]
+    pub fn legendre_nlm(lmax: usize) -> usize {
+        unsafe { sys::gsl_sf_legendre_nlm(lmax) }
+    }
+
+    fn check_len(lmax: usize, arrays: &[&[f64]]) -> Result<(), Value> {
+        let n = legendre_array_n(lmax);
+        if arrays.iter().any(|a| a.len() < n) {
+            Err(Value::BadLength)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// This function calculates all normalized associated Legendre polynomials for 0 <= l <= lmax
+    /// and 0 <= m <= l for |x| <= 1, like [`legendre_array`], but with the Condon-Shortley phase
+    /// factor `csphase` (either 1 or -1) instead of the default 1, which leaves the phase out.
+    ///
+    /// The value for (l, m) is stored at index [`legendre_array_index`]`(l, m)`. `result` must
+    /// have at least [`legendre_array_n`]`(lmax)` elements, otherwise `Value::BadLength` is
+    /// returned.
+    #[doc(alias = "gsl_sf_legendre_array_e")]
+    pub fn legendre_array_e(
+        norm: enums::SfLegendreNorm,
+        lmax: usize,
+        x: f64,
+        csphase: f64,
+        result: &mut [f64],
+    ) -> Value {
+        if let Err(e) = check_len(lmax, &[result]) {
+            return e;
+        }
+        Value::from(unsafe {
+            sys::gsl_sf_legendre_array_e(norm.into(), lmax, x, csphase, result.as_mut_ptr())
+        })
+    }
+
+    /// This function calculates all normalized associated Legendre functions and their first
+    /// derivatives up to degree lmax for |x| < 1, with the Condon-Shortley phase factor
+    /// `csphase`. The derivatives dP_l^m(x)/dx are stored in `deriv`.
+    ///
+    /// Both arrays must have at least [`legendre_array_n`]`(lmax)` elements.
+    #[doc(alias = "gsl_sf_legendre_deriv_array_e")]
+    pub fn legendre_deriv_array_e(
+        norm: enums::SfLegendreNorm,
+        lmax: usize,
+        x: f64,
+        csphase: f64,
+        result: &mut [f64],
+        deriv: &mut [f64],
+    ) -> Value {
+        if let Err(e) = check_len(lmax, &[result, deriv]) {
+            return e;
+        }
+        Value::from(unsafe {
+            sys::gsl_sf_legendre_deriv_array_e(
+                norm.into(),
+                lmax,
+                x,
+                csphase,
+                result.as_mut_ptr(),
+                deriv.as_mut_ptr(),
+            )
+        })
+    }
+
+    /// This function calculates all normalized associated Legendre functions and their first
+    /// derivatives up to degree lmax for |x| < 1. The derivatives are computed with respect to
+    /// \theta, where x = \cos(\theta), i.e. dP_l^m(\cos\theta)/d\theta, and stored in `deriv`.
+    ///
+    /// Both arrays must have at least [`legendre_array_n`]`(lmax)` elements.
+    #[doc(alias = "gsl_sf_legendre_deriv_alt_array")]
+    pub fn legendre_deriv_alt_array(
+        norm: enums::SfLegendreNorm,
+        lmax: usize,
+        x: f64,
+        result: &mut [f64],
+        deriv: &mut [f64],
+    ) -> Value {
+        if let Err(e) = check_len(lmax, &[result, deriv]) {
+            return e;
+        }
+        Value::from(unsafe {
+            sys::gsl_sf_legendre_deriv_alt_array(
+                norm.into(),
+                lmax,
+                x,
+                result.as_mut_ptr(),
+                deriv.as_mut_ptr(),
+            )
+        })
+    }
+
+    /// Same as [`legendre_deriv_alt_array`], with the Condon-Shortley phase factor `csphase`.
+    #[doc(alias = "gsl_sf_legendre_deriv_alt_array_e")]
+    pub fn legendre_deriv_alt_array_e(
+        norm: enums::SfLegendreNorm,
+        lmax: usize,
+        x: f64,
+        csphase: f64,
+        result: &mut [f64],
+        deriv: &mut [f64],
+    ) -> Value {
+        if let Err(e) = check_len(lmax, &[result, deriv]) {
+            return e;
+        }
+        Value::from(unsafe {
+            sys::gsl_sf_legendre_deriv_alt_array_e(
+                norm.into(),
+                lmax,
+                x,
+                csphase,
+                result.as_mut_ptr(),
+                deriv.as_mut_ptr(),
+            )
+        })
+    }
+
+    /// This function calculates all normalized associated Legendre functions and their first and
+    /// second derivatives up to degree lmax for |x| < 1. The derivatives with respect to x are
+    /// stored in `deriv` and `deriv2`.
+    ///
+    /// All the arrays must have at least [`legendre_array_n`]`(lmax)` elements.
+    #[doc(alias = "gsl_sf_legendre_deriv2_array")]
+    pub fn legendre_deriv2_array(
+        norm: enums::SfLegendreNorm,
+        lmax: usize,
+        x: f64,
+        result: &mut [f64],
+        deriv: &mut [f64],
+        deriv2: &mut [f64],
+    ) -> Value {
+        if let Err(e) = check_len(lmax, &[result, deriv, deriv2]) {
+            return e;
+        }
+        Value::from(unsafe {
+            sys::gsl_sf_legendre_deriv2_array(
+                norm.into(),
+                lmax,
+                x,
+                result.as_mut_ptr(),
+                deriv.as_mut_ptr(),
+                deriv2.as_mut_ptr(),
+            )
+        })
+    }
+
+    /// Same as [`legendre_deriv2_array`], with the Condon-Shortley phase factor `csphase`.
+    #[doc(alias = "gsl_sf_legendre_deriv2_array_e")]
+    pub fn legendre_deriv2_array_e(
+        norm: enums::SfLegendreNorm,
+        lmax: usize,
+        x: f64,
+        csphase: f64,
+        result: &mut [f64],
+        deriv: &mut [f64],
+        deriv2: &mut [f64],
+    ) -> Value {
+        if let Err(e) = check_len(lmax, &[result, deriv, deriv2]) {
+            return e;
+        }
+        Value::from(unsafe {
+            sys::gsl_sf_legendre_deriv2_array_e(
+                norm.into(),
+                lmax,
+                x,
+                csphase,
+                result.as_mut_ptr(),
+                deriv.as_mut_ptr(),
+                deriv2.as_mut_ptr(),
+            )
+        })
+    }
+
+    /// This function calculates all normalized associated Legendre functions and their first and
+    /// second derivatives with respect to \theta, where x = \cos(\theta), up to degree lmax for
+    /// |x| < 1.
+    ///
+    /// All the arrays must have at least [`legendre_array_n`]`(lmax)` elements.
+    #[doc(alias = "gsl_sf_legendre_deriv2_alt_array")]
+    pub fn legendre_deriv2_alt_array(
+        norm: enums::SfLegendreNorm,
+        lmax: usize,
+        x: f64,
+        result: &mut [f64],
+        deriv: &mut [f64],
+        deriv2: &mut [f64],
+    ) -> Value {
+        if let Err(e) = check_len(lmax, &[result, deriv, deriv2]) {
+            return e;
+        }
+        Value::from(unsafe {
+            sys::gsl_sf_legendre_deriv2_alt_array(
+                norm.into(),
+                lmax,
+                x,
+                result.as_mut_ptr(),
+                deriv.as_mut_ptr(),
+                deriv2.as_mut_ptr(),
+            )
+        })
+    }
+
+    /// Same as [`legendre_deriv2_alt_array`], with the Condon-Shortley phase factor `csphase`.
+    #[doc(alias = "gsl_sf_legendre_deriv2_alt_array_e")]
+    pub fn legendre_deriv2_alt_array_e(
+        norm: enums::SfLegendreNorm,
+        lmax: usize,
+        x: f64,
+        csphase: f64,
+        result: &mut [f64],
+        deriv: &mut [f64],
+        deriv2: &mut [f64],
+    ) -> Value {
+        if let Err(e) = check_len(lmax, &[result, deriv, deriv2]) {
+            return e;
+        }
+        Value::from(unsafe {
+            sys::gsl_sf_legendre_deriv2_alt_array_e(
+                norm.into(),
+                lmax,
+                x,
+                csphase,
+                result.as_mut_ptr(),
+                deriv.as_mut_ptr(),
+                deriv2.as_mut_ptr(),
+            )
+        })
+    }
+
+    /// Which derivatives are computed by a [`LegendreArray`].
+    #[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+    pub enum LegendreDeriv {
+        /// Only the values of the functions.
+        None,
+        /// The first derivatives with respect to x.
+        First,
+        /// The first and second derivatives with respect to x.
+        Second,
+        /// The first derivatives with respect to \theta, where x = \cos(\theta).
+        FirstAlt,
+        /// The first and second derivatives with respect to \theta, where x = \cos(\theta).
+        SecondAlt,
+    }
+
+    /// All the associated Legendre functions P_l^m(x) for 0 <= m <= l <= lmax, and optionally
+    /// their derivatives, indexed by (l, m) instead of raw array offsets.
+    ///
+    /// ```text
+    /// let p = LegendreArray::new(SfLegendreNorm::Schmidt, 10, 0.5, LegendreDeriv::First).unwrap();
+    /// println!("P_3^2(0.5) = {}, derivative = {}", p.get(3, 2).unwrap(), p.deriv(3, 2).unwrap());
+    /// ```
+    #[derive(Clone, Debug)]
+    pub struct LegendreArray {
+        lmax: usize,
+        values: Vec<f64>,
+        deriv: Vec<f64>,
+        deriv2: Vec<f64>,
+    }
+
+    impl LegendreArray {
+        /// Computes the functions with normalization `norm` up to degree `lmax` at `x`, with the
+        /// default phase factor of 1 used by `gsl_sf_legendre_array`, i.e. without the
+        /// Condon-Shortley phase. The derivatives computed are given by `deriv`.
+        pub fn new(
+            norm: enums::SfLegendreNorm,
+            lmax: usize,
+            x: f64,
+            deriv: LegendreDeriv,
+        ) -> Result<LegendreArray, Value> {
+            Self::with_csphase(norm, lmax, x, 1., deriv)
+        }
+
+        /// Same as [`new`](Self::new), with the phase factor `csphase`, which must be 1 or -1.
+        /// Use -1 to include the Condon-Shortley phase (-1)^m.
+        pub fn with_csphase(
+            norm: enums::SfLegendreNorm,
+            lmax: usize,
+            x: f64,
+            csphase: f64,
+            deriv: LegendreDeriv,
+        ) -> Result<LegendreArray, Value> {
+            let n = legendre_array_n(lmax);
+            let mut values = vec![0.; n];
+            let (n1, n2) = match deriv {
+                LegendreDeriv::None => (0, 0),
+                LegendreDeriv::First | LegendreDeriv::FirstAlt => (n, 0),
+                LegendreDeriv::Second | LegendreDeriv::SecondAlt => (n, n),
+            };
+            let mut d1 = vec![0.; n1];
+            let mut d2 = vec![0.; n2];
+
+            let ret = match deriv {
+                LegendreDeriv::None => legendre_array_e(norm, lmax, x, csphase, &mut values),
+                LegendreDeriv::First => {
+                    legendre_deriv_array_e(norm, lmax, x, csphase, &mut values, &mut d1)
+                }
+                LegendreDeriv::FirstAlt => {
+                    legendre_deriv_alt_array_e(norm, lmax, x, csphase, &mut values, &mut d1)
+                }
+                LegendreDeriv::Second => {
+                    legendre_deriv2_array_e(norm, lmax, x, csphase, &mut values, &mut d1, &mut d2)
+                }
+                LegendreDeriv::SecondAlt => legendre_deriv2_alt_array_e(
+                    norm,
+                    lmax,
+                    x,
+                    csphase,
+                    &mut values,
+                    &mut d1,
+                    &mut d2,
+                ),
+            };
+            if ret != Value::Success {
+                return Err(ret);
+            }
+
+            // Drop the GSL workspace at the end of the arrays.
+            let nlm = legendre_nlm(lmax);
+            for a in [&mut values, &mut d1, &mut d2].iter_mut() {
+                a.truncate(nlm);
+            }
+            Ok(LegendreArray {
+                lmax,
+                values,
+                deriv: d1,
+                deriv2: d2,
+            })
+        }
+
+        /// Returns the maximum degree lmax.
+        pub fn lmax(&self) -> usize {
+            self.lmax
+        }
+
+        /// Returns the offset of (l, m) in the arrays, or `None` if it's not 0 <= m <= l <= lmax.
+        pub fn index(&self, l: usize, m: usize) -> Option<usize> {
+            if m <= l && l <= self.lmax {
+                Some(legendre_array_index(l, m))
+            } else {
+                None
+            }
+        }
+
+        /// Returns P_l^m(x).
+        pub fn get(&self, l: usize, m: usize) -> Option<f64> {
+            self.index(l, m).map(|i| self.values[i])
+        }
+
+        /// Returns the first derivative of P_l^m(x), or `None` if it wasn't computed.
+        pub fn deriv(&self, l: usize, m: usize) -> Option<f64> {
+            self.index(l, m).and_then(|i| self.deriv.get(i).copied())
+        }
+
+        /// Returns the second derivative of P_l^m(x), or `None` if it wasn't computed.
+        pub fn deriv2(&self, l: usize, m: usize) -> Option<f64> {
+            self.index(l, m).and_then(|i| self.deriv2.get(i).copied())
+        }
+
+        /// Returns all the values, ordered by [`legendre_array_index`].
+        pub fn values(&self) -> &[f64] {
+            &self.values
+        }
+    }
+
+    /// Computes the spherical harmonic Y_l^m(\theta, \phi) for l >= 0 and |m| <= l, with the
+    /// Condon-Shortley phase, using the [`SphericalHarmonic`](enums::SfLegendreNorm::SphericalHarmonic)
+    /// normalization:
+    ///
+    /// Y_l^m(\theta, \phi) = \sqrt{(2l+1)/(4\pi)} \sqrt{(l-m)!/(l+m)!} P_l^m(\cos\theta) e^{i m \phi}
+    ///
+    /// and Y_l^{-m} = (-1)^m \overline{Y_l^m}. Both components are NaN if the arguments are out
+    /// of range.
+    pub fn spherical_harmonic(l: i32, m: i32, theta: f64, phi: f64) -> ::types::ComplexF64 {
+        let p = if l < 0 || m.abs() > l {
+            None
+        } else {
+            LegendreArray::with_csphase(
+                enums::SfLegendreNorm::SphericalHarmonic,
+                l as usize,
+                theta.cos(),
+                -1.,
+                LegendreDeriv::None,
+            )
+            .ok()
+            .and_then(|a| a.get(l as usize, m.unsigned_abs() as usize))
+        };
+        let p = match p {
+            Some(p) if m < 0 && m % 2 != 0 => -p,
+            Some(p) => p,
+            None => f64::NAN,
+        };
+        let mphi = m as f64 * phi;
+        ::types::ComplexF64 {
+            dat: [p * mphi.cos(), p * mphi.sin()],
+        }
+    }
+
+    #[cfg(any(test, doctest))]
+    mod tests {
+        use super::*;
+
+        fn assert_close(a: f64, b: f64) {
+            assert!((a - b).abs() <= 1e-12, "{} != {}", a, b);
+        }
+
+        #[test]
+        fn test_legendre_array() {
+            let x = 0.3;
+            let p = LegendreArray::new(enums::SfLegendreNorm::None, 4, x, LegendreDeriv::Second)
+                .unwrap();
+            assert_eq!(p.values().len(), legendre_nlm(4));
+            // legendre_Plm includes the Condon-Shortley phase, the default array doesn't.
+            assert_close(p.get(2, 0).unwrap(), legendre_Plm(2, 0, x));
+            assert_close(p.get(4, 3).unwrap(), -legendre_Plm(4, 3, x));
+            // P_2(x) = (3x^2 - 1) / 2
+            assert_close(p.deriv(2, 0).unwrap(), 3. * x);
+            assert_close(p.deriv2(2, 0).unwrap(), 3.);
+            assert_eq!(p.get(2, 3), None);
+            assert_eq!(p.get(5, 0), None);
+
+            let p =
+                LegendreArray::new(enums::SfLegendreNorm::None, 4, x, LegendreDeriv::None).unwrap();
+            assert_eq!(p.deriv(1, 0), None);
+
+            let p = LegendreArray::with_csphase(
+                enums::SfLegendreNorm::None,
+                4,
+                x,
+                -1.,
+                LegendreDeriv::None,
+            )
+            .unwrap();
+            assert_close(p.get(4, 3).unwrap(), legendre_Plm(4, 3, x));
+
+            let mut small = [0.; 2];
+            assert_eq!(
+                legendre_array_e(enums::SfLegendreNorm::None, 4, x, -1., &mut small),
+                Value::BadLength
+            );
+        }
+
+        #[test]
+        fn test_spherical_harmonic() {
+            let (theta, phi) = (0.7, 1.2);
+            let y = spherical_harmonic(2, 1, theta, phi);
+            let p = legendre_sphPlm(2, 1, theta.cos());
+            assert_close(y.dat[0], p * phi.cos());
+            assert_close(y.dat[1], p * phi.sin());
+
+            // Y_l^{-m} = (-1)^m conj(Y_l^m)
+            let y_neg = spherical_harmonic(2, -1, theta, phi);
+            assert_close(y_neg.dat[0], -y.dat[0]);
+            assert_close(y_neg.dat[1], y.dat[1]);
+
+            // Y_0^0 = 1 / sqrt(4 pi)
+            let y00 = spherical_harmonic(0, 0, theta, phi);
+            assert_close(y00.dat[0], 1. / (4. * ::std::f64::consts::PI).sqrt());
+            assert!(spherical_harmonic(1, 2, theta, phi).dat[0].is_nan());
+        }
+    }
 }
 
 /// The Conical Functions P^\mu_{-(1/2)+i\lambda}(x) and Q^\mu_{-(1/2)+i\lambda} are described in Abramowitz & Stegun, Section 8.12.
@@ -64,6 +64,9 @@ pub mod filter;
 pub mod fit;
 pub mod gamma_beta;
 pub mod gegenbauer;
+#[cfg(feature = "v2_3")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
+pub mod hermite;
 pub mod hypergeometric;
 pub mod integration;
 pub mod interpolation;
@@ -73,12 +76,19 @@ pub mod lambert_w;
 pub mod legendre;
 pub mod linear_algebra;
 pub mod logarithm;
+#[cfg(feature = "v2_6")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
+pub mod matrix_market;
 pub mod minimizer;
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+pub mod movstat;
 pub mod multifit;
 #[cfg(feature = "v2_1")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
 pub mod multilarge;
 pub mod multilinear;
+pub mod multimin;
 pub mod multiroot;
 pub mod numerical_differentiation;
 pub mod physical_constant;
@@ -87,7 +97,11 @@ pub mod pow;
 pub mod power;
 pub mod psi;
 pub mod roots;
+pub mod sf_batch;
 pub mod sort;
+#[cfg(feature = "v2_6")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
+pub mod spblas;
 pub mod statistics;
 pub mod stats;
 pub mod synchrotron;
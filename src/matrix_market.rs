@@ -0,0 +1,467 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+# MatrixMarket Files
+
+The MatrixMarket exchange format is a simple text format used by many tools and matrix
+collections to store sparse and dense matrices. A file starts with a banner describing the
+matrix, followed by optional comment lines starting with `%`, a size line and the entries:
+
+```text
+%%MatrixMarket matrix coordinate real general
+% comments
+3 3 4
+1 1 2.5
+2 1 -1
+3 2 4
+3 3 1e-3
+```
+
+The `coordinate` format stores one nonzero entry per line with 1-based row and column indices,
+while the `array` format stores all the entries in column-major order. Entries can be `real`,
+`integer`, `complex` (a real and an imaginary part) or `pattern` (no value, the entry is 1). For
+`symmetric` matrices only the lower triangle is stored.
+
+The functions of this module read from any [`Read`] and write to any [`Write`]:
+
+```text
+let file = std::fs::File::open("matrix.mtx").unwrap();
+let m = rgsl::matrix_market::read_spmatrix(file).unwrap();
+```
+!*/
+
+use crate::{ComplexF64, MatrixComplexF64, MatrixF64, SpMatrixF64};
+use std::io::{self, Read, Write};
+use std::str::FromStr;
+
+/// Type of the entries of a MatrixMarket file.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+pub enum MatrixMarketField {
+    /// Real entries, written with full `f64` precision.
+    Real,
+    /// Integer entries. They are read as `f64`.
+    Integer,
+    /// Complex entries, stored as a real and an imaginary part.
+    Complex,
+    /// Only the position of the entries is stored, their value is 1.
+    Pattern,
+}
+
+impl MatrixMarketField {
+    fn name(self) -> &'static str {
+        match self {
+            MatrixMarketField::Real => "real",
+            MatrixMarketField::Integer => "integer",
+            MatrixMarketField::Complex => "complex",
+            MatrixMarketField::Pattern => "pattern",
+        }
+    }
+}
+
+/// Symmetry of the matrix stored in a MatrixMarket file.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+pub enum MatrixMarketSymmetry {
+    /// No symmetry, all the entries are stored.
+    General,
+    /// A(i, j) = A(j, i). Only the entries with i >= j are stored.
+    Symmetric,
+}
+
+impl MatrixMarketSymmetry {
+    fn name(self) -> &'static str {
+        match self {
+            MatrixMarketSymmetry::General => "general",
+            MatrixMarketSymmetry::Symmetric => "symmetric",
+        }
+    }
+}
+
+fn invalid_data(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+fn invalid_input(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, msg)
+}
+
+// GSL reports a failed allocation through its error handler, which aborts the program by
+// default, so the dimensions read from a file are checked before allocating a dense matrix.
+fn check_dense_size(size1: usize, size2: usize, elem_size: usize) -> io::Result<()> {
+    let bytes = size1
+        .checked_mul(size2)
+        .and_then(|n| n.checked_mul(elem_size))
+        .filter(|&b| b <= isize::MAX as usize)
+        .ok_or_else(|| invalid_data("MatrixMarket matrix too large"))?;
+    Vec::<u8>::new()
+        .try_reserve_exact(bytes)
+        .map_err(|_| io::Error::new(io::ErrorKind::OutOfMemory, "cannot allocate matrix"))
+}
+
+fn next_token<'a, T: FromStr, I: Iterator<Item = &'a str>>(tokens: &mut I) -> io::Result<T> {
+    match tokens.next() {
+        Some(t) => t
+            .parse()
+            .map_err(|_| invalid_data(&format!("invalid MatrixMarket entry `{}`", t))),
+        None => Err(invalid_data("unexpected end of MatrixMarket file")),
+    }
+}
+
+/// Contents of a MatrixMarket file, with the symmetric entries already expanded.
+struct Entries {
+    field: MatrixMarketField,
+    size1: usize,
+    size2: usize,
+    /// `(row, column, real part, imaginary part)`
+    values: Vec<(usize, usize, f64, f64)>,
+}
+
+fn parse<R: Read>(mut reader: R) -> io::Result<Entries> {
+    let mut content = String::new();
+    reader.read_to_string(&mut content)?;
+    let mut lines = content.lines();
+
+    let banner = lines
+        .next()
+        .ok_or_else(|| invalid_data("empty MatrixMarket file"))?
+        .to_lowercase();
+    let banner: Vec<&str> = banner.split_whitespace().collect();
+    if banner.len() != 5 || banner[0] != "%%matrixmarket" || banner[1] != "matrix" {
+        return Err(invalid_data("invalid MatrixMarket banner"));
+    }
+    let coordinate = match banner[2] {
+        "coordinate" => true,
+        "array" => false,
+        _ => return Err(invalid_data("unknown MatrixMarket format")),
+    };
+    let field = match banner[3] {
+        "real" => MatrixMarketField::Real,
+        "integer" => MatrixMarketField::Integer,
+        "complex" => MatrixMarketField::Complex,
+        "pattern" if coordinate => MatrixMarketField::Pattern,
+        _ => return Err(invalid_data("unknown MatrixMarket field")),
+    };
+    let symmetric = match banner[4] {
+        "general" => false,
+        "symmetric" => true,
+        _ => return Err(invalid_data("unsupported MatrixMarket symmetry")),
+    };
+
+    let mut tokens = lines
+        .filter(|l| !l.trim_start().starts_with('%'))
+        .flat_map(|l| l.split_whitespace());
+    let size1: usize = next_token(&mut tokens)?;
+    let size2: usize = next_token(&mut tokens)?;
+    if symmetric && size1 != size2 {
+        return Err(invalid_data("symmetric matrix must be square"));
+    }
+    let count = if coordinate {
+        Some(next_token(&mut tokens)?)
+    } else if symmetric {
+        size1
+            .checked_add(1)
+            .and_then(|n| n.checked_mul(size1))
+            .map(|n| n / 2)
+    } else {
+        size1.checked_mul(size2)
+    }
+    .ok_or_else(|| invalid_data("MatrixMarket matrix too large"))?;
+
+    // The header cannot be trusted to allocate the entries up front: a truncated file is only
+    // detected once its tokens run out.
+    let mut values = Vec::with_capacity(count.min(1 << 20));
+    // Position of the next entry of an array file, in column-major order.
+    let (mut row, mut col) = (0, 0);
+    for _ in 0..count {
+        let (i, j) = if coordinate {
+            let i: usize = next_token(&mut tokens)?;
+            let j: usize = next_token(&mut tokens)?;
+            if i == 0 || j == 0 || i > size1 || j > size2 {
+                return Err(invalid_data("entry index out of range"));
+            }
+            (i - 1, j - 1)
+        } else {
+            let pos = (row, col);
+            row += 1;
+            if row == size1 {
+                col += 1;
+                row = if symmetric { col } else { 0 };
+            }
+            pos
+        };
+        let (re, im) = match field {
+            MatrixMarketField::Pattern => (1., 0.),
+            MatrixMarketField::Complex => (next_token(&mut tokens)?, next_token(&mut tokens)?),
+            _ => (next_token(&mut tokens)?, 0.),
+        };
+        if symmetric && i < j {
+            return Err(invalid_data("symmetric matrix entry above the diagonal"));
+        }
+        values.push((i, j, re, im));
+        if symmetric && i != j {
+            values.push((j, i, re, im));
+        }
+    }
+
+    Ok(Entries {
+        field,
+        size1,
+        size2,
+        values,
+    })
+}
+
+fn write_banner<W: Write>(
+    writer: &mut W,
+    coordinate: bool,
+    field: MatrixMarketField,
+    symmetry: MatrixMarketSymmetry,
+) -> io::Result<()> {
+    writeln!(
+        writer,
+        "%%MatrixMarket matrix {} {} {}",
+        if coordinate { "coordinate" } else { "array" },
+        field.name(),
+        symmetry.name()
+    )
+}
+
+/// Reads a real sparse matrix in MatrixMarket format. Both the `coordinate` and the `array`
+/// formats are accepted. Zero entries are not stored, including the ones explicitly listed in a
+/// `coordinate` file. The returned matrix is in the COO format.
+///
+/// Complex files are rejected with an [`io::ErrorKind::InvalidData`] error.
+pub fn read_spmatrix<R: Read>(reader: R) -> io::Result<SpMatrixF64> {
+    let entries = parse(reader)?;
+    if entries.field == MatrixMarketField::Complex {
+        return Err(invalid_data("cannot read a complex matrix into a real one"));
+    }
+    let mut m = SpMatrixF64::new_with_nzmax(
+        entries.size1,
+        entries.size2,
+        entries.values.len().max(1),
+        crate::SpMatrixFormat::Coo,
+    )
+    .ok_or_else(|| io::Error::other("cannot allocate sparse matrix"))?;
+    for &(i, j, x, _) in entries.values.iter().filter(|e| e.2 != 0.) {
+        m.set(i, j, x);
+    }
+    Ok(m)
+}
+
+/// Reads a real dense matrix in MatrixMarket format. Both the `coordinate` and the `array`
+/// formats are accepted, the entries missing from a `coordinate` file are set to zero.
+///
+/// Complex files are rejected with an [`io::ErrorKind::InvalidData`] error.
+pub fn read_matrix<R: Read>(reader: R) -> io::Result<MatrixF64> {
+    let entries = parse(reader)?;
+    if entries.field == MatrixMarketField::Complex {
+        return Err(invalid_data("cannot read a complex matrix into a real one"));
+    }
+    check_dense_size(entries.size1, entries.size2, ::std::mem::size_of::<f64>())?;
+    let mut m = MatrixF64::new(entries.size1, entries.size2)
+        .ok_or_else(|| io::Error::other("cannot allocate matrix"))?;
+    for &(i, j, x, _) in entries.values.iter() {
+        m.set(i, j, x);
+    }
+    Ok(m)
+}
+
+/// Reads a complex dense matrix in MatrixMarket format. Both the `coordinate` and the `array`
+/// formats are accepted, the entries missing from a `coordinate` file are set to zero. Real,
+/// integer and pattern files are read with a zero imaginary part.
+pub fn read_matrix_complex<R: Read>(reader: R) -> io::Result<MatrixComplexF64> {
+    let entries = parse(reader)?;
+    check_dense_size(
+        entries.size1,
+        entries.size2,
+        2 * ::std::mem::size_of::<f64>(),
+    )?;
+    let mut m = MatrixComplexF64::new(entries.size1, entries.size2)
+        .ok_or_else(|| io::Error::other("cannot allocate matrix"))?;
+    for &(i, j, re, im) in entries.values.iter() {
+        m.set(i, j, &ComplexF64::rect(re, im));
+    }
+    Ok(m)
+}
+
+/// Writes the sparse matrix `m` in MatrixMarket `coordinate` format. The matrix can be in any
+/// storage format. `field` must be [`MatrixMarketField::Real`] or
+/// [`MatrixMarketField::Pattern`], in which case only the position of the entries is written.
+///
+/// With [`MatrixMarketSymmetry::Symmetric`], only the lower triangle is written and an
+/// [`io::ErrorKind::InvalidInput`] error is returned if `m` is not symmetric.
+pub fn write_spmatrix<W: Write>(
+    mut writer: W,
+    m: &SpMatrixF64,
+    field: MatrixMarketField,
+    symmetry: MatrixMarketSymmetry,
+) -> io::Result<()> {
+    if field != MatrixMarketField::Real && field != MatrixMarketField::Pattern {
+        return Err(invalid_input(
+            "sparse matrices are written as real or pattern",
+        ));
+    }
+    let symmetric = symmetry == MatrixMarketSymmetry::Symmetric;
+    if symmetric
+        && m.iter()
+            .any(|(i, j, x)| m.size1() != m.size2() || m.get(j, i) != x)
+    {
+        return Err(invalid_input("matrix is not symmetric"));
+    }
+    let entries: Vec<_> = m.iter().filter(|e| !symmetric || e.0 >= e.1).collect();
+
+    write_banner(&mut writer, true, field, symmetry)?;
+    writeln!(writer, "{} {} {}", m.size1(), m.size2(), entries.len())?;
+    for (i, j, x) in entries {
+        if field == MatrixMarketField::Pattern {
+            writeln!(writer, "{} {}", i + 1, j + 1)?;
+        } else {
+            writeln!(writer, "{} {} {:e}", i + 1, j + 1, x)?;
+        }
+    }
+    Ok(())
+}
+
+/// Writes the dense matrix `m` in MatrixMarket `array` format.
+///
+/// With [`MatrixMarketSymmetry::Symmetric`], only the lower triangle is written and an
+/// [`io::ErrorKind::InvalidInput`] error is returned if `m` is not symmetric.
+pub fn write_matrix<W: Write>(
+    mut writer: W,
+    m: &MatrixF64,
+    symmetry: MatrixMarketSymmetry,
+) -> io::Result<()> {
+    let (size1, size2) = (m.size1(), m.size2());
+    let symmetric = symmetry == MatrixMarketSymmetry::Symmetric;
+    if symmetric
+        && (size1 != size2 || (0..size1).any(|i| (0..i).any(|j| m.get(i, j) != m.get(j, i))))
+    {
+        return Err(invalid_input("matrix is not symmetric"));
+    }
+
+    write_banner(&mut writer, false, MatrixMarketField::Real, symmetry)?;
+    writeln!(writer, "{} {}", size1, size2)?;
+    for j in 0..size2 {
+        for i in if symmetric { j } else { 0 }..size1 {
+            writeln!(writer, "{:e}", m.get(i, j))?;
+        }
+    }
+    Ok(())
+}
+
+/// Writes the complex dense matrix `m` in MatrixMarket `array` format.
+///
+/// With [`MatrixMarketSymmetry::Symmetric`], only the lower triangle is written and an
+/// [`io::ErrorKind::InvalidInput`] error is returned if `m` is not symmetric.
+pub fn write_matrix_complex<W: Write>(
+    mut writer: W,
+    m: &MatrixComplexF64,
+    symmetry: MatrixMarketSymmetry,
+) -> io::Result<()> {
+    let (size1, size2) = (m.size1(), m.size2());
+    let symmetric = symmetry == MatrixMarketSymmetry::Symmetric;
+    if symmetric
+        && (size1 != size2
+            || (0..size1).any(|i| (0..i).any(|j| m.get(i, j).dat != m.get(j, i).dat)))
+    {
+        return Err(invalid_input("matrix is not symmetric"));
+    }
+
+    write_banner(&mut writer, false, MatrixMarketField::Complex, symmetry)?;
+    writeln!(writer, "{} {}", size1, size2)?;
+    for j in 0..size2 {
+        for i in if symmetric { j } else { 0 }..size1 {
+            let z = m.get(i, j);
+            writeln!(writer, "{:e} {:e}", z.real(), z.imaginary())?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(any(test, doctest))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spmatrix_roundtrip() {
+        let input = "%%MatrixMarket matrix coordinate real symmetric\n\
+                     % lower triangle only\n\
+                     3 3 4\n\
+                     1 1 2.5\n\
+                     2 1 -1\n\
+                     3 2 4\n\
+                     3 3 1e-3\n";
+        let m = read_spmatrix(input.as_bytes()).unwrap();
+        assert_eq!((m.size1(), m.size2(), m.nnz()), (3, 3, 6));
+        assert_eq!(m.get(0, 1), -1.);
+        assert_eq!(m.get(1, 0), -1.);
+        assert_eq!(m.get(2, 2), 1e-3);
+
+        let mut out = Vec::new();
+        write_spmatrix(
+            &mut out,
+            &m,
+            MatrixMarketField::Real,
+            MatrixMarketSymmetry::Symmetric,
+        )
+        .unwrap();
+        let back = read_spmatrix(out.as_slice()).unwrap();
+        assert!(back.equal(&m));
+
+        let mut out = Vec::new();
+        write_spmatrix(
+            &mut out,
+            &m,
+            MatrixMarketField::Pattern,
+            MatrixMarketSymmetry::General,
+        )
+        .unwrap();
+        let pattern = read_spmatrix(out.as_slice()).unwrap();
+        assert_eq!(pattern.nnz(), 6);
+        assert_eq!(pattern.get(2, 1), 1.);
+
+        let complex = "%%MatrixMarket matrix coordinate complex general\n1 1 1\n1 1 1 2\n";
+        assert!(read_spmatrix(complex.as_bytes()).is_err());
+
+        let huge = "%%MatrixMarket matrix coordinate real general\n1 1 1000000000000\n1 1 1\n";
+        assert!(read_spmatrix(huge.as_bytes()).is_err());
+        let huge = format!(
+            "%%MatrixMarket matrix array real general\n{} {}\n1\n",
+            usize::MAX,
+            2
+        );
+        assert!(read_matrix(huge.as_bytes()).is_err());
+        let huge =
+            "%%MatrixMarket matrix coordinate real general\n4000000000 4000000000 1\n1 1 1\n";
+        assert!(read_matrix(huge.as_bytes()).is_err());
+        assert!(read_matrix_complex(huge.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_dense_roundtrip() {
+        let mut m = MatrixF64::new(2, 3).unwrap();
+        for i in 0..2 {
+            for j in 0..3 {
+                m.set(i, j, (i * 3 + j) as f64 / 7.);
+            }
+        }
+        let mut out = Vec::new();
+        write_matrix(&mut out, &m, MatrixMarketSymmetry::General).unwrap();
+        let back = read_matrix(out.as_slice()).unwrap();
+        assert_eq!(back.get(1, 2), 5. / 7.);
+        assert!(write_matrix(Vec::new(), &m, MatrixMarketSymmetry::Symmetric).is_err());
+
+        let mut z = MatrixComplexF64::new(2, 2).unwrap();
+        z.set(0, 0, &ComplexF64::rect(1., -1.));
+        z.set(1, 0, &ComplexF64::rect(0.5, 2.));
+        z.set(0, 1, &ComplexF64::rect(0.5, 2.));
+        z.set(1, 1, &ComplexF64::rect(3., 0.));
+        let mut out = Vec::new();
+        write_matrix_complex(&mut out, &z, MatrixMarketSymmetry::Symmetric).unwrap();
+        let back = read_matrix_complex(out.as_slice()).unwrap();
+        assert_eq!(back.get(0, 1).dat, [0.5, 2.]);
+        assert_eq!(back.get(1, 1).dat, [3., 0.]);
+    }
+}
@@ -0,0 +1,39 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+//! Moving window statistics. The statistics themselves are computed with
+//! [`MovstatWorkspace`](crate::types::MovstatWorkspace).
+
+use crate::{MovstatEnd, VectorF64};
+use ffi::FFI;
+
+/// This function explicitly constructs the sliding window for the input vector `x` which is
+/// centered on the sample `idx`, with `H` samples before it and `J` samples after it. On output,
+/// the first elements of `window` contain the samples of the window, with the end points handled
+/// according to `endtype`.
+///
+/// Returns the number of samples stored in `window`.
+///
+/// Panics if `window` is shorter than `H + J + 1`.
+#[doc(alias = "gsl_movstat_fill")]
+pub fn fill(
+    endtype: MovstatEnd,
+    x: &VectorF64,
+    idx: usize,
+    H: usize,
+    J: usize,
+    window: &mut [f64],
+) -> usize {
+    assert!(window.len() > H + J, "window is too small");
+    unsafe {
+        sys::gsl_movstat_fill(
+            endtype.into(),
+            x.unwrap_shared(),
+            idx,
+            H,
+            J,
+            window.as_mut_ptr(),
+        )
+    }
+}
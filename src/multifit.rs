@@ -2,7 +2,7 @@
 // A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
 //
 
-use crate::{MatrixF64, Value, VectorF64};
+use crate::{MatrixF64, MultiFitFunctionFdf, Permutation, Value, VectorF64};
 use ffi::FFI;
 
 /// Compute the covariance matrix cov = inv (J^T J) by QRP^T decomposition of J
@@ -13,6 +13,47 @@ pub fn covar(J: &MatrixF64, epsrel: f64, covar: &mut MatrixF64) -> Value {
     })
 }
 
+/// Compute the covariance matrix cov = inv (J^T J) from the QRP^T decomposition of J, where `r`
+/// holds the QR factorization and `perm` the column permutation. `r` is overwritten.
+#[doc(alias = "gsl_multifit_covar_QRPT")]
+pub fn covar_QRPT(
+    r: &mut MatrixF64,
+    perm: &mut Permutation,
+    epsrel: f64,
+    covar: &mut MatrixF64,
+) -> Value {
+    Value::from(unsafe {
+        sys::gsl_multifit_covar_QRPT(
+            r.unwrap_unique(),
+            perm.unwrap_unique(),
+            epsrel,
+            covar.unwrap_unique(),
+        )
+    })
+}
+
+/// Compute the Jacobian `J` of the function `fdf` at the point `x` using forward finite
+/// differences, given the function values `f` at `x`. The optional `wts` are the weights of a
+/// weighted fit.
+#[doc(alias = "gsl_multifit_fdfsolver_dif_df")]
+pub fn fdfsolver_dif_df(
+    x: &VectorF64,
+    wts: Option<&VectorF64>,
+    fdf: &mut MultiFitFunctionFdf,
+    f: &VectorF64,
+    J: &mut MatrixF64,
+) -> Value {
+    Value::from(unsafe {
+        sys::gsl_multifit_fdfsolver_dif_df(
+            x.unwrap_shared(),
+            wts.map(|w| w.unwrap_shared()).unwrap_or(::std::ptr::null()),
+            fdf.to_raw(),
+            f.unwrap_shared(),
+            J.unwrap_unique(),
+        )
+    })
+}
+
 #[doc(alias = "gsl_multifit_test_delta")]
 pub fn test_delta(dx: &VectorF64, x: &VectorF64, epsabs: f64, epsrel: f64) -> Value {
     Value::from(unsafe {
@@ -98,3 +98,24 @@ pub fn linear_residuals(x: &MatrixF64, y: &VectorF64, c: &VectorF64, r: &mut Vec
         ))
     }
 }
+
+/// This function uses the best-fit robust regression coefficients `c` and their estimated
+/// covariance matrix `cov` to compute the fitted function value `y` and its standard deviation
+/// `y_err` for the model y = x.c at the point `x`.
+///
+/// Returns `(Value, y, y_err)`.
+#[doc(alias = "gsl_multifit_robust_est")]
+pub fn robust_est(x: &VectorF64, c: &VectorF64, cov: &MatrixF64) -> (Value, f64, f64) {
+    let mut y = 0.;
+    let mut y_err = 0.;
+    let ret = unsafe {
+        sys::gsl_multifit_robust_est(
+            x.unwrap_shared(),
+            c.unwrap_shared(),
+            cov.unwrap_shared(),
+            &mut y,
+            &mut y_err,
+        )
+    };
+    (Value::from(ret), y, y_err)
+}
@@ -0,0 +1,52 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+//! Multimin test algorithms, See `rgsl::types::multimin` for minimizers.
+
+use crate::{Value, VectorF64};
+use ffi::FFI;
+use std::os::raw::c_void;
+
+/// This function tests the norm of the gradient `g` against the absolute tolerance `epsabs`. The
+/// gradient of a multidimensional function goes to zero at a minimum. The test returns
+/// `Value::Success` if the following condition is achieved,
+///
+/// ```text
+/// |g| < epsabs
+/// ```
+///
+/// and returns `Value::Continue` otherwise. A suitable choice of `epsabs` can be made from the
+/// desired accuracy in the function for small variations in x. The relationship between these
+/// quantities is given by `\delta{f} = g\,\delta{x}`.
+#[doc(alias = "gsl_multimin_test_gradient")]
+pub fn test_gradient(g: &VectorF64, epsabs: f64) -> Value {
+    Value::from(unsafe { sys::gsl_multimin_test_gradient(g.unwrap_shared(), epsabs) })
+}
+
+/// This function tests the minimizer specific characteristic size (if applicable to the used
+/// minimizer) against absolute tolerance `epsabs`. The test returns `Value::Success` if the size
+/// is smaller than tolerance, otherwise `Value::Continue` is returned.
+#[doc(alias = "gsl_multimin_test_size")]
+pub fn test_size(size: f64, epsabs: f64) -> Value {
+    Value::from(unsafe { sys::gsl_multimin_test_size(size, epsabs) })
+}
+
+/// This function computes the gradient `g` of `f` at `x` using forward finite differences.
+#[doc(alias = "gsl_multimin_diff")]
+pub fn diff<F: FnMut(&VectorF64) -> f64>(mut f: F, x: &VectorF64, g: &mut VectorF64) -> Value {
+    unsafe extern "C" fn inner_f<F: FnMut(&VectorF64) -> f64>(
+        x: *const sys::gsl_vector,
+        params: *mut c_void,
+    ) -> f64 {
+        let f: &mut F = &mut *(params as *mut F);
+        f(&VectorF64::soft_wrap(x as *mut _))
+    }
+
+    let function = sys::gsl_multimin_function_struct {
+        f: Some(inner_f::<F>),
+        n: x.len(),
+        params: &mut f as *mut F as *mut c_void,
+    };
+    Value::from(unsafe { sys::gsl_multimin_diff(&function, x.unwrap_shared(), g.unwrap_unique()) })
+}
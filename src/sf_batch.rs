@@ -0,0 +1,557 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+# Batch Evaluation of Special Functions
+
+This module evaluates the special functions over whole arrays at once. For every function `f` of
+the special function modules taking a single `f64` argument (the other arguments, like the order
+of a Bessel function, being fixed), it provides:
+
+ * `f(x, out)`, which stores f(x_i) in `out[i]`,
+ * `f_e(x, out, err)`, which also stores the error estimates in `err`,
+ * `f_vector` and `f_vector_e`, which do the same with [`VectorF64`].
+
+```text
+use rgsl::sf_batch::bessel;
+
+let x: Vec<f64> = (0..100).map(|i| i as f64 / 10.).collect();
+let mut j0 = vec![0.; x.len()];
+let mut err = vec![0.; x.len()];
+bessel::J0_e(&x, &mut j0, &mut err).unwrap();
+```
+
+The `_e` functions evaluate every element, even after a failure, and report the first failing
+index in a [`BatchError`]. Note that the default GSL error handler aborts the program on the
+first error: call [`set_error_handler_off`](crate::error::set_error_handler_off) to rely on the
+returned errors instead.
+
+Any other function can be evaluated in the same way with [`map`] and [`map_e`].
+!*/
+
+use crate::{types, Value, VectorF64};
+
+/// Error returned by the `_e` batch functions.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BatchError {
+    /// Index of the first element whose evaluation failed.
+    pub index: usize,
+    /// Status returned for this element, or `Value::BadLength` if the buffers don't have the
+    /// same length.
+    pub value: Value,
+}
+
+/// Stores `f(x[i])` in `out[i]` for every element of `x`.
+///
+/// Returns `Value::BadLength` if `x` and `out` don't have the same length.
+pub fn map<F: Fn(f64) -> f64>(f: F, x: &[f64], out: &mut [f64]) -> Value {
+    if x.len() != out.len() {
+        return Value::BadLength;
+    }
+    for (o, &x) in out.iter_mut().zip(x) {
+        *o = f(x);
+    }
+    Value::Success
+}
+
+/// Evaluates `f(x[i])` for every element of `x`, storing the values in `out` and the error
+/// estimates in `err`.
+///
+/// All the elements are evaluated. If some of them fail, the index and the status of the first
+/// one are returned. If the buffers don't have the same length, nothing is evaluated and the
+/// error has the index of the end of the shortest buffer.
+pub fn map_e<F: Fn(f64) -> (Value, types::Result)>(
+    f: F,
+    x: &[f64],
+    out: &mut [f64],
+    err: &mut [f64],
+) -> Result<(), BatchError> {
+    if x.len() != out.len() || x.len() != err.len() {
+        return Err(BatchError {
+            index: x.len().min(out.len()).min(err.len()),
+            value: Value::BadLength,
+        });
+    }
+    let mut first = None;
+    for (i, &xi) in x.iter().enumerate() {
+        let (ret, res) = f(xi);
+        out[i] = res.val;
+        err[i] = res.err;
+        if ret != Value::Success && first.is_none() {
+            first = Some(BatchError {
+                index: i,
+                value: ret,
+            });
+        }
+    }
+    first.map_or(Ok(()), Err)
+}
+
+/// Same as [`map`], with vectors. The stride of the vectors is taken into account, so vector
+/// views with a stride can be used.
+pub fn map_vector<F: Fn(f64) -> f64>(f: F, x: &VectorF64, out: &mut VectorF64) -> Value {
+    if x.len() != out.len() {
+        return Value::BadLength;
+    }
+    for i in 0..x.len() {
+        out.set(i, f(x.get(i)));
+    }
+    Value::Success
+}
+
+/// Same as [`map_e`], with vectors. The stride of the vectors is taken into account, so vector
+/// views with a stride can be used.
+pub fn map_vector_e<F: Fn(f64) -> (Value, types::Result)>(
+    f: F,
+    x: &VectorF64,
+    out: &mut VectorF64,
+    err: &mut VectorF64,
+) -> Result<(), BatchError> {
+    if x.len() != out.len() || x.len() != err.len() {
+        return Err(BatchError {
+            index: x.len().min(out.len()).min(err.len()),
+            value: Value::BadLength,
+        });
+    }
+    let mut first = None;
+    for i in 0..x.len() {
+        let (ret, res) = f(x.get(i));
+        out.set(i, res.val);
+        err.set(i, res.err);
+        if ret != Value::Success && first.is_none() {
+            first = Some(BatchError {
+                index: i,
+                value: ret,
+            });
+        }
+    }
+    first.map_or(Ok(()), Err)
+}
+
+macro_rules! sf_batch {
+    (
+        $(#[$attr:meta])*
+        $family:ident {
+            $($name:ident($f:path, $f_e:path)[$($p:ident: $t:ty),*][$($q:ident: $u:ty),*]),* $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        pub mod $family {
+            use super::{map, map_e, map_vector, map_vector_e, BatchError};
+            use crate::paste::paste;
+            use crate::{Value, VectorF64};
+
+            $(paste! {
+                #[doc = concat!("Evaluates [`", stringify!($name), "`](", stringify!($f),
+                    ") at each element of `x`. See [`map`].")]
+                pub fn $name($($p: $t,)* x: &[f64], $($q: $u,)* out: &mut [f64]) -> Value {
+                    map(|x| $f($($p,)* x $(, $q)*), x, out)
+                }
+
+                #[doc = concat!("Evaluates [`", stringify!($name), "_e`](", stringify!($f_e),
+                    ") at each element of `x`. See [`map_e`].")]
+                pub fn [<$name _e>](
+                    $($p: $t,)*
+                    x: &[f64],
+                    $($q: $u,)*
+                    out: &mut [f64],
+                    err: &mut [f64],
+                ) -> Result<(), BatchError> {
+                    map_e(|x| $f_e($($p,)* x $(, $q)*), x, out, err)
+                }
+
+                #[doc = concat!("Evaluates [`", stringify!($name), "`](", stringify!($f),
+                    ") at each element of `x`. See [`map_vector`].")]
+                pub fn [<$name _vector>](
+                    $($p: $t,)*
+                    x: &VectorF64,
+                    $($q: $u,)*
+                    out: &mut VectorF64,
+                ) -> Value {
+                    map_vector(|x| $f($($p,)* x $(, $q)*), x, out)
+                }
+
+                #[doc = concat!("Evaluates [`", stringify!($name), "_e`](", stringify!($f_e),
+                    ") at each element of `x`. See [`map_vector_e`].")]
+                pub fn [<$name _vector_e>](
+                    $($p: $t,)*
+                    x: &VectorF64,
+                    $($q: $u,)*
+                    out: &mut VectorF64,
+                    err: &mut VectorF64,
+                ) -> Result<(), BatchError> {
+                    map_vector_e(|x| $f_e($($p,)* x $(, $q)*), x, out, err)
+                }
+            })*
+        }
+    };
+}
+
+sf_batch! {
+    /// Batch evaluation of the [`airy`](crate::airy) functions.
+    airy {
+        Ai(crate::airy::Ai, crate::airy::Ai_e)[][mode: crate::Mode],
+        Bi(crate::airy::Bi, crate::airy::Bi_e)[][mode: crate::Mode],
+        Ai_scaled(crate::airy::Ai_scaled, crate::airy::Ai_scaled_e)[][mode: crate::Mode],
+        Bi_scaled(crate::airy::Bi_scaled, crate::airy::Bi_scaled_e)[][mode: crate::Mode],
+        Ai_deriv(crate::airy::Ai_deriv, crate::airy::Ai_deriv_e)[][mode: crate::Mode],
+        Bi_deriv(crate::airy::Bi_deriv, crate::airy::Bi_deriv_e)[][mode: crate::Mode],
+        Ai_deriv_scaled(crate::airy::Ai_deriv_scaled, crate::airy::Ai_deriv_scaled_e)[][mode: crate::Mode],
+        Bi_deriv_scaled(crate::airy::Bi_deriv_scaled, crate::airy::Bi_deriv_scaled_e)[][mode: crate::Mode],
+    }
+}
+
+sf_batch! {
+    /// Batch evaluation of the [`bessel`](crate::bessel) functions.
+    bessel {
+        I0(crate::bessel::I0, crate::bessel::I0_e)[][],
+        I1(crate::bessel::I1, crate::bessel::I1_e)[][],
+        I0_scaled(crate::bessel::I0_scaled, crate::bessel::I0_scaled_e)[][],
+        I1_scaled(crate::bessel::I1_scaled, crate::bessel::I1_scaled_e)[][],
+        i0_scaled(crate::bessel::i0_scaled, crate::bessel::i0_scaled_e)[][],
+        i1_scaled(crate::bessel::i1_scaled, crate::bessel::i1_scaled_e)[][],
+        i2_scaled(crate::bessel::i2_scaled, crate::bessel::i2_scaled_e)[][],
+        J0(crate::bessel::J0, crate::bessel::J0_e)[][],
+        J1(crate::bessel::J1, crate::bessel::J1_e)[][],
+        j0(crate::bessel::j0, crate::bessel::j0_e)[][],
+        j1(crate::bessel::j1, crate::bessel::j1_e)[][],
+        j2(crate::bessel::j2, crate::bessel::j2_e)[][],
+        K0(crate::bessel::K0, crate::bessel::K0_e)[][],
+        K1(crate::bessel::K1, crate::bessel::K1_e)[][],
+        K0_scaled(crate::bessel::K0_scaled, crate::bessel::K0_scaled_e)[][],
+        K1_scaled(crate::bessel::K1_scaled, crate::bessel::K1_scaled_e)[][],
+        k0_scaled(crate::bessel::k0_scaled, crate::bessel::k0_scaled_e)[][],
+        k1_scaled(crate::bessel::k1_scaled, crate::bessel::k1_scaled_e)[][],
+        k2_scaled(crate::bessel::k2_scaled, crate::bessel::k2_scaled_e)[][],
+        Y0(crate::bessel::Y0, crate::bessel::Y0_e)[][],
+        Y1(crate::bessel::Y1, crate::bessel::Y1_e)[][],
+        y0(crate::bessel::y0, crate::bessel::y0_e)[][],
+        y1(crate::bessel::y1, crate::bessel::y1_e)[][],
+        y2(crate::bessel::y2, crate::bessel::y2_e)[][],
+        In(crate::bessel::In, crate::bessel::In_e)[n: i32][],
+        In_scaled(crate::bessel::In_scaled, crate::bessel::In_scaled_e)[n: i32][],
+        Jn(crate::bessel::Jn, crate::bessel::Jn_e)[n: i32][],
+        Kn(crate::bessel::Kn, crate::bessel::Kn_e)[n: i32][],
+        Kn_scaled(crate::bessel::Kn_scaled, crate::bessel::Kn_scaled_e)[n: i32][],
+        Yn(crate::bessel::Yn, crate::bessel::Yn_e)[n: i32][],
+        il_scaled(crate::bessel::il_scaled, crate::bessel::il_scaled_e)[l: i32][],
+        jl(crate::bessel::jl, crate::bessel::jl_e)[l: i32][],
+        kl_scaled(crate::bessel::kl_scaled, crate::bessel::kl_scaled_e)[l: i32][],
+        yl(crate::bessel::yl, crate::bessel::yl_e)[l: i32][],
+        Inu(crate::bessel::Inu, crate::bessel::Inu_e)[nu: f64][],
+        Inu_scaled(crate::bessel::Inu_scaled, crate::bessel::Inu_scaled_e)[nu: f64][],
+        Jnu(crate::bessel::Jnu, crate::bessel::Jnu_e)[nu: f64][],
+        Knu(crate::bessel::Knu, crate::bessel::Knu_e)[nu: f64][],
+        Knu_scaled(crate::bessel::Knu_scaled, crate::bessel::Knu_scaled_e)[nu: f64][],
+        lnKnu(crate::bessel::lnKnu, crate::bessel::lnKnu_e)[nu: f64][],
+        Ynu(crate::bessel::Ynu, crate::bessel::Ynu_e)[nu: f64][],
+    }
+}
+
+sf_batch! {
+    /// Batch evaluation of the [`clausen`](crate::clausen) functions.
+    clausen {
+        clausen(crate::clausen::clausen, crate::clausen::clausen_e)[][],
+    }
+}
+
+sf_batch! {
+    /// Batch evaluation of the [`dawson`](crate::dawson) functions.
+    dawson {
+        dawson(crate::dawson::dawson, crate::dawson::dawson_e)[][],
+    }
+}
+
+sf_batch! {
+    /// Batch evaluation of the [`debye`](crate::debye) functions.
+    debye {
+        _1(crate::debye::_1, crate::debye::_1_e)[][],
+        _2(crate::debye::_2, crate::debye::_2_e)[][],
+        _3(crate::debye::_3, crate::debye::_3_e)[][],
+        _4(crate::debye::_4, crate::debye::_4_e)[][],
+        _5(crate::debye::_5, crate::debye::_5_e)[][],
+        _6(crate::debye::_6, crate::debye::_6_e)[][],
+    }
+}
+
+sf_batch! {
+    /// Batch evaluation of the [`dilogarithm`](crate::dilogarithm) functions.
+    dilogarithm {
+        dilog(crate::dilogarithm::dilog, crate::dilogarithm::dilog_e)[][],
+    }
+}
+
+sf_batch! {
+    /// Batch evaluation of the [`error`](crate::error) functions.
+    error {
+        erf(crate::error::erf, crate::error::erf_e)[][],
+        erfc(crate::error::erfc, crate::error::erfc_e)[][],
+        log_erfc(crate::error::log_erfc, crate::error::log_erfc_e)[][],
+        erf_Z(crate::error::erf_Z, crate::error::erf_Z_e)[][],
+        erf_Q(crate::error::erf_Q, crate::error::erf_Q_e)[][],
+        hazard(crate::error::hazard, crate::error::hazard_e)[][],
+    }
+}
+
+sf_batch! {
+    /// Batch evaluation of the [`exponential`](crate::exponential) functions.
+    exponential {
+        exp(crate::exponential::exp, crate::exponential::exp_e)[][],
+        expm1(crate::exponential::expm1, crate::exponential::expm1_e)[][],
+        exprel(crate::exponential::exprel, crate::exponential::exprel_e)[][],
+        exprel_2(crate::exponential::exprel_2, crate::exponential::exprel_2_e)[][],
+    }
+}
+
+sf_batch! {
+    /// Batch evaluation of the [`exponential_integrals`](crate::exponential_integrals) functions.
+    exponential_integrals {
+        E1(crate::exponential_integrals::E1, crate::exponential_integrals::E1_e)[][],
+        E2(crate::exponential_integrals::E2, crate::exponential_integrals::E2_e)[][],
+        Ei(crate::exponential_integrals::Ei, crate::exponential_integrals::Ei_e)[][],
+        Shi(crate::exponential_integrals::Shi, crate::exponential_integrals::Shi_e)[][],
+        Chi(crate::exponential_integrals::Chi, crate::exponential_integrals::Chi_e)[][],
+        _3(crate::exponential_integrals::_3, crate::exponential_integrals::_3_e)[][],
+        Si(crate::exponential_integrals::Si, crate::exponential_integrals::Si_e)[][],
+        Ci(crate::exponential_integrals::Ci, crate::exponential_integrals::Ci_e)[][],
+        atanint(crate::exponential_integrals::atanint, crate::exponential_integrals::atanint_e)[][],
+        En(crate::exponential_integrals::En, crate::exponential_integrals::En_e)[n: i32][],
+    }
+}
+
+sf_batch! {
+    /// Batch evaluation of the [`fermi_dirac`](crate::fermi_dirac) functions.
+    fermi_dirac {
+        fermi_dirac_m1(crate::fermi_dirac::complete_integrals::fermi_dirac_m1, crate::fermi_dirac::complete_integrals::fermi_dirac_m1_e)[][],
+        fermi_dirac_0(crate::fermi_dirac::complete_integrals::fermi_dirac_0, crate::fermi_dirac::complete_integrals::fermi_dirac_0_e)[][],
+        fermi_dirac_1(crate::fermi_dirac::complete_integrals::fermi_dirac_1, crate::fermi_dirac::complete_integrals::fermi_dirac_1_e)[][],
+        fermi_dirac_2(crate::fermi_dirac::complete_integrals::fermi_dirac_2, crate::fermi_dirac::complete_integrals::fermi_dirac_2_e)[][],
+        fermi_dirac_mhalf(crate::fermi_dirac::complete_integrals::fermi_dirac_mhalf, crate::fermi_dirac::complete_integrals::fermi_dirac_mhalf_e)[][],
+        fermi_dirac_half(crate::fermi_dirac::complete_integrals::fermi_dirac_half, crate::fermi_dirac::complete_integrals::fermi_dirac_half_e)[][],
+        fermi_dirac_3half(crate::fermi_dirac::complete_integrals::fermi_dirac_3half, crate::fermi_dirac::complete_integrals::fermi_dirac_3half_e)[][],
+        fermi_dirac_int(crate::fermi_dirac::complete_integrals::fermi_dirac_int, crate::fermi_dirac::complete_integrals::fermi_dirac_int_e)[j: i32][],
+    }
+}
+
+sf_batch! {
+    /// Batch evaluation of the [`gamma_beta`](crate::gamma_beta) functions.
+    gamma_beta {
+        gamma(crate::gamma_beta::gamma::gamma, crate::gamma_beta::gamma::gamma_e)[][],
+        lngamma(crate::gamma_beta::gamma::lngamma, crate::gamma_beta::gamma::lngamma_e)[][],
+        gammastar(crate::gamma_beta::gamma::gammastar, crate::gamma_beta::gamma::gammastar_e)[][],
+        gammainv(crate::gamma_beta::gamma::gammainv, crate::gamma_beta::gamma::gammainv_e)[][],
+        taylorcoeff(crate::gamma_beta::factorials::taylorcoeff, crate::gamma_beta::factorials::taylorcoeff_e)[n: i32][],
+        poch(crate::gamma_beta::pochhammer_symbol::poch, crate::gamma_beta::pochhammer_symbol::poch_e)[a: f64][],
+        lnpoch(crate::gamma_beta::pochhammer_symbol::lnpoch, crate::gamma_beta::pochhammer_symbol::lnpoch_e)[a: f64][],
+        pochrel(crate::gamma_beta::pochhammer_symbol::pochrel, crate::gamma_beta::pochhammer_symbol::pochrel_e)[a: f64][],
+        gamma_inc(crate::gamma_beta::incomplete_gamma::gamma_inc, crate::gamma_beta::incomplete_gamma::gamma_inc_e)[a: f64][],
+        gamma_inc_Q(crate::gamma_beta::incomplete_gamma::gamma_inc_Q, crate::gamma_beta::incomplete_gamma::gamma_inc_Q_e)[a: f64][],
+        gamma_inc_P(crate::gamma_beta::incomplete_gamma::gamma_inc_P, crate::gamma_beta::incomplete_gamma::gamma_inc_P_e)[a: f64][],
+        beta_inc(crate::gamma_beta::incomplete_beta::beta_inc, crate::gamma_beta::incomplete_beta::beta_inc_e)[a: f64, b: f64][],
+    }
+}
+
+sf_batch! {
+    /// Batch evaluation of the [`gegenbauer`](crate::gegenbauer) functions.
+    gegenbauer {
+        gegenpoly_n(crate::gegenbauer::gegenpoly_n, crate::gegenbauer::gegenpoly_n_e)[n: i32, lambda: f64][],
+    }
+}
+
+sf_batch! {
+    /// Batch evaluation of the [`hermite`](crate::hermite) functions.
+    #[cfg(feature = "v2_3")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
+    hermite {
+        hermite_phys(crate::hermite::hermite_phys, crate::hermite::hermite_phys_e)[n: i32][],
+        hermite_prob(crate::hermite::hermite_prob, crate::hermite::hermite_prob_e)[n: i32][],
+        hermite_func(crate::hermite::hermite_func, crate::hermite::hermite_func_e)[n: i32][],
+    }
+}
+
+sf_batch! {
+    /// Batch evaluation of the [`laguerre`](crate::laguerre) functions.
+    laguerre {
+        laguerre_n(crate::laguerre::laguerre_n, crate::laguerre::laguerre_n_e)[n: i32, a: f64][],
+    }
+}
+
+sf_batch! {
+    /// Batch evaluation of the [`lambert_w`](crate::lambert_w) functions.
+    lambert_w {
+        lambert_W0(crate::lambert_w::lambert_W0, crate::lambert_w::lambert_W0_e)[][],
+        lambert_Wm1(crate::lambert_w::lambert_Wm1, crate::lambert_w::lambert_Wm1_e)[][],
+    }
+}
+
+sf_batch! {
+    /// Batch evaluation of the [`legendre`](crate::legendre) functions.
+    legendre {
+        legendre_P1(crate::legendre::polynomials::legendre_P1, crate::legendre::polynomials::legendre_P1_e)[][],
+        legendre_P2(crate::legendre::polynomials::legendre_P2, crate::legendre::polynomials::legendre_P2_e)[][],
+        legendre_P3(crate::legendre::polynomials::legendre_P3, crate::legendre::polynomials::legendre_P3_e)[][],
+        legendre_Q0(crate::legendre::polynomials::legendre_Q0, crate::legendre::polynomials::legendre_Q0_e)[][],
+        legendre_Q1(crate::legendre::polynomials::legendre_Q1, crate::legendre::polynomials::legendre_Q1_e)[][],
+        legendre_Pl(crate::legendre::polynomials::legendre_Pl, crate::legendre::polynomials::legendre_Pl_e)[l: i32][],
+        legendre_Ql(crate::legendre::polynomials::legendre_Ql, crate::legendre::polynomials::legendre_Ql_e)[l: i32][],
+    }
+}
+
+sf_batch! {
+    /// Batch evaluation of the [`logarithm`](crate::logarithm) functions.
+    logarithm {
+        log(crate::logarithm::log, crate::logarithm::log_e)[][],
+        log_abs(crate::logarithm::log_abs, crate::logarithm::log_abs_e)[][],
+        log_1plusx(crate::logarithm::log_1plusx, crate::logarithm::log_1plusx_e)[][],
+        log_1plusx_mx(crate::logarithm::log_1plusx_mx, crate::logarithm::log_1plusx_mx_e)[][],
+    }
+}
+
+sf_batch! {
+    /// Batch evaluation of the [`psi`](crate::psi) functions.
+    psi {
+        psi(crate::psi::diagamma::psi, crate::psi::diagamma::psi_e)[][],
+        psi_1piy(crate::psi::diagamma::psi_1piy, crate::psi::diagamma::psi_1piy_e)[][],
+        psi_1(crate::psi::trigamma::psi_1, crate::psi::trigamma::psi_1_e)[][],
+        psi_n(crate::psi::polygamma::psi_n, crate::psi::polygamma::psi_n_e)[n: i32][],
+    }
+}
+
+sf_batch! {
+    /// Batch evaluation of the [`synchrotron`](crate::synchrotron) functions.
+    synchrotron {
+        synchrotron_1(crate::synchrotron::synchrotron_1, crate::synchrotron::synchrotron_1_e)[][],
+        synchrotron_2(crate::synchrotron::synchrotron_2, crate::synchrotron::synchrotron_2_e)[][],
+    }
+}
+
+sf_batch! {
+    /// Batch evaluation of the [`transport`](crate::transport) functions.
+    transport {
+        transport_2(crate::transport::transport_2, crate::transport::transport_2_e)[][],
+        transport_3(crate::transport::transport_3, crate::transport::transport_3_e)[][],
+        transport_4(crate::transport::transport_4, crate::transport::transport_4_e)[][],
+        transport_5(crate::transport::transport_5, crate::transport::transport_5_e)[][],
+    }
+}
+
+sf_batch! {
+    /// Batch evaluation of the [`zeta`](crate::zeta) functions.
+    zeta {
+        zeta(crate::zeta::riemann::zeta, crate::zeta::riemann::zeta_e)[][],
+        zetam1(crate::zeta::riemann_mins_one::zetam1, crate::zeta::riemann_mins_one::zetam1_e)[][],
+        eta(crate::zeta::eta::eta, crate::zeta::eta::eta_e)[][],
+        hzeta(crate::zeta::hurwitz::hzeta, crate::zeta::hurwitz::hzeta_e)[][q: f64],
+    }
+}
+
+#[cfg(any(test, doctest))]
+mod tests {
+    use super::*;
+    use crate::VectorF64View;
+
+    #[test]
+    fn test_batch() {
+        let x = [0., 0.5, 1., 2.];
+        let mut out = [0.; 4];
+        assert_eq!(bessel::J0(&x, &mut out), Value::Success);
+        for (&x, &o) in x.iter().zip(out.iter()) {
+            assert_eq!(o, crate::bessel::J0(x));
+        }
+        assert_eq!(bessel::Jn(2, &x, &mut out), Value::Success);
+        assert_eq!(out[3], crate::bessel::Jn(2, 2.));
+        assert_eq!(bessel::J0(&x, &mut out[1..]), Value::BadLength);
+
+        let mut err = [0.; 4];
+        assert_eq!(error::erf_e(&x, &mut out, &mut err), Ok(()));
+        assert_eq!(out[2], crate::error::erf(1.));
+        assert!(err.iter().all(|e| (0. ..1e-10).contains(e)));
+        assert_eq!(
+            error::erf_e(&x, &mut out, &mut err[..2]),
+            Err(BatchError {
+                index: 2,
+                value: Value::BadLength,
+            })
+        );
+
+        // hzeta(s, q) requires s > 1.
+        let mut v = VectorF64::from_slice(&[1.5, 2., 3., 4.]).unwrap();
+        let mut w = VectorF64::new(4).unwrap();
+        assert_eq!(zeta::hzeta_vector(&v, 1., &mut w), Value::Success);
+        assert_eq!(w.get(2), crate::zeta::hurwitz::hzeta(3., 1.));
+        v.set(0, 2.);
+        assert_eq!(map_vector(|x| x * x, &v, &mut w), Value::Success);
+        assert_eq!(w.get(0), 4.);
+    }
+
+    #[test]
+    fn test_batch_errors() {
+        let x = [1., -1., 2., -2.];
+        let mut out = [0.; 4];
+        let mut err = [0.; 4];
+        let res = gamma_beta::gammainv_e(&x, &mut out, &mut err);
+        assert_eq!(res, Ok(()));
+
+        // A failing function, which doesn't go through the GSL error handler.
+        let checked_ln = |x: f64| {
+            let val = x.ln();
+            let ret = if x > 0. {
+                Value::Success
+            } else {
+                Value::Domain
+            };
+            (ret, types::Result { val, err: 0. })
+        };
+        let res = map_e(checked_ln, &x, &mut out, &mut err);
+        assert_eq!(
+            res,
+            Err(BatchError {
+                index: 1,
+                value: Value::Domain,
+            })
+        );
+        // The elements after the failing one are still evaluated.
+        assert_eq!(out[2], 2f64.ln());
+
+        let x = VectorF64::from_slice(&x).unwrap();
+        let mut out = VectorF64::new(4).unwrap();
+        let mut err = VectorF64::new(4).unwrap();
+        let res = map_vector_e(checked_ln, &x, &mut out, &mut err);
+        assert_eq!(
+            res,
+            Err(BatchError {
+                index: 1,
+                value: Value::Domain,
+            })
+        );
+        assert_eq!(out.get(2), 2f64.ln());
+        let mut short = VectorF64::new(2).unwrap();
+        assert_eq!(
+            map_vector_e(checked_ln, &x, &mut out, &mut short),
+            Err(BatchError {
+                index: 2,
+                value: Value::BadLength,
+            })
+        );
+    }
+
+    #[test]
+    fn test_batch_vector_stride() {
+        let mut x = VectorF64::from_slice(&[1., 10., 2., 20., 3., 30.]).unwrap();
+        let mut out = VectorF64::new(6).unwrap();
+        {
+            let x_even = VectorF64View::from_vector_with_stride(&mut x, 0, 2, 3);
+            let mut out_odd = VectorF64View::from_vector_with_stride(&mut out, 1, 2, 3);
+            x_even.vector(|x| {
+                out_odd.vector_mut(|out| {
+                    assert_eq!(
+                        map_vector(|x| x * x, x.unwrap(), out.unwrap()),
+                        Value::Success
+                    );
+                })
+            });
+        }
+        assert_eq!(out.as_slice().unwrap(), &[0., 1., 0., 4., 0., 9.]);
+    }
+}
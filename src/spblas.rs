@@ -0,0 +1,107 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+# Sparse BLAS Support
+
+The Sparse Basic Linear Algebra Subprograms (BLAS) define a set of fundamental operations on
+vectors and sparse matrices which can be used to create optimized higher-level linear algebra
+functionality. GSL supports a limited number of BLAS operations for sparse matrices.
+
+[`dgemv`] accepts sparse matrices in any storage format, while [`dgemm`] requires all of its
+matrices to be in compressed column format (see
+[`SpMatrixF64::compress`](crate::types::SpMatrixF64::compress)).
+!*/
+
+use crate::{enums, SpMatrixF64, Value, VectorF64};
+use ffi::FFI;
+
+/// This function computes the matrix-vector product and sum y = \alpha op(A) x + \beta y, where
+/// op(A) = A, A^T for TransA = CblasNoTrans, CblasTrans. In-place computations are not supported,
+/// so `x` and `y` must be distinct vectors. The matrix `A` may be in any storage format.
+#[doc(alias = "gsl_spblas_dgemv")]
+pub fn dgemv(
+    transA: enums::CblasTranspose,
+    alpha: f64,
+    A: &SpMatrixF64,
+    x: &VectorF64,
+    beta: f64,
+    y: &mut VectorF64,
+) -> Value {
+    Value::from(unsafe {
+        sys::gsl_spblas_dgemv(
+            transA.into(),
+            alpha,
+            A.unwrap_shared(),
+            x.unwrap_shared(),
+            beta,
+            y.unwrap_unique(),
+        )
+    })
+}
+
+/// This function computes the sparse matrix-matrix product C = \alpha A B. The matrices must be
+/// in compressed column format. `C` is resized to hold the nonzero elements of the product.
+#[doc(alias = "gsl_spblas_dgemm")]
+pub fn dgemm(alpha: f64, A: &SpMatrixF64, B: &SpMatrixF64, C: &mut SpMatrixF64) -> Value {
+    Value::from(unsafe {
+        sys::gsl_spblas_dgemm(
+            alpha,
+            A.unwrap_shared(),
+            B.unwrap_shared(),
+            C.unwrap_unique(),
+        )
+    })
+}
+
+#[cfg(any(test, doctest))]
+mod tests {
+    use super::*;
+    use crate::{MatrixF64, SpMatrixFormat};
+
+    #[test]
+    fn test_dgemm() {
+        let a_dense = [[1., 0., 2.], [0., 3., 0.]];
+        let b_dense = [[0., 4.], [5., 0.], [0., 6.]];
+
+        let mut a = SpMatrixF64::new(2, 3).unwrap();
+        for (i, row) in a_dense.iter().enumerate() {
+            for (j, v) in row.iter().enumerate() {
+                if *v != 0. {
+                    a.set(i, j, *v);
+                }
+            }
+        }
+        let mut b = SpMatrixF64::new(3, 2).unwrap();
+        for (i, row) in b_dense.iter().enumerate() {
+            for (j, v) in row.iter().enumerate() {
+                if *v != 0. {
+                    b.set(i, j, *v);
+                }
+            }
+        }
+        let a = a.compress(SpMatrixFormat::Csc).unwrap();
+        let b = b.compress(SpMatrixFormat::Csc).unwrap();
+        let mut c = SpMatrixF64::new(2, 2)
+            .unwrap()
+            .compress(SpMatrixFormat::Csc)
+            .unwrap();
+
+        assert_eq!(dgemm(2., &a, &b, &mut c), Value::Success);
+
+        let mut dense = MatrixF64::new(2, 2).unwrap();
+        assert_eq!(c.sp2d(&mut dense), Value::Success);
+        for (i, row) in a_dense.iter().enumerate() {
+            for j in 0..2 {
+                let expected = 2.
+                    * row
+                        .iter()
+                        .zip(b_dense.iter())
+                        .map(|(a, b)| a * b[j])
+                        .sum::<f64>();
+                assert_eq!(dense.get(i, j), expected);
+            }
+        }
+    }
+}
@@ -27,6 +27,23 @@ returned, since the maximum or minimum value is undefined. For functions which r
 The median and percentile functions described in this section operate on sorted data. For convenience we use quantiles, measured on a
 scale of 0 to 1, instead of percentiles (which use a scale of 0 to 100).
 
+## Order Statistics and Robust Estimation
+
+The k-th order statistic of a sample is its k-th smallest value, which can be found without sorting with [`select`]. Robust location
+estimates such as the median, the trimmed mean and the Gastwirth estimator, and robust scale estimates such as the MAD, S_n and Q_n,
+are much less sensitive to outliers than the sample mean and standard deviation.
+
+These functions panic if a dataset of length n with stride stride does not fit in the given slice,
+if k is not smaller than n, or if a workspace is smaller than documented.
+
+## Other Element Types
+
+The functions of this module work on `f64` datasets. The same functions are available for the
+other element types supported by GSL in the submodules [`float`] (`f32`), [`int`] (`i32`),
+[`uint`] (`u32`), [`long`] (`c_long`), [`ulong`] (`c_ulong`), [`short`] (`i16`), [`ushort`]
+(`u16`), [`char`](mod@char) (`c_char`) and [`uchar`] (`c_uchar`), so integer data can be
+processed without converting it first.
+
 ## References and Further Reading
 
 The standard reference for almost any topic in statistics is the multi-volume Advanced Theory of Statistics by Kendall and Stuart.
@@ -44,6 +61,8 @@ Review of Particle Properties R.M. Barnett et al., Physical Review D54, 1 (1996)
 The Review of Particle Physics is available online at the website http://pdg.lbl.gov/.
 !*/
 
+use crate::paste::paste;
+
 /// This function returns the arithmetic mean of data, a dataset of length n with stride stride. The
 /// arithmetic mean, or sample mean, is denoted by \Hat\mu and defined as,
 ///
@@ -302,6 +321,66 @@ pub fn spearman(
     }
 }
 
+// GSL accesses data[i * stride] for i < n without any bound check.
+fn check_data(len: usize, stride: usize, n: usize) {
+    assert!(
+        n == 0 || (n - 1).checked_mul(stride).is_some_and(|last| last < len),
+        "a dataset of length {} with stride {} does not fit in {} elements",
+        n,
+        stride,
+        len
+    );
+}
+
+/// This function returns the pooled variance of two independent datasets data1 and data2 of
+/// lengths n1 and n2 with strides stride1 and stride2. The pooled variance is defined as,
+///
+/// \sigma_p^2 = ((n_1 - 1) \sigma_1^2 + (n_2 - 1) \sigma_2^2) / (n_1 + n_2 - 2)
+///
+/// where \sigma_1^2 and \sigma_2^2 are the sample variances of the two datasets.
+///
+/// [`stats::pvariance`](crate::stats::pvariance) computes the same value with the lengths of the
+/// slices as n1 and n2. The functions of this module are the complete API, with explicit lengths
+/// like in GSL.
+#[doc(alias = "gsl_stats_pvariance")]
+pub fn pvariance(
+    data1: &[f64],
+    stride1: usize,
+    n1: usize,
+    data2: &[f64],
+    stride2: usize,
+    n2: usize,
+) -> f64 {
+    check_data(data1.len(), stride1, n1);
+    check_data(data2.len(), stride2, n2);
+    unsafe { sys::gsl_stats_pvariance(data1.as_ptr(), stride1, n1, data2.as_ptr(), stride2, n2) }
+}
+
+/// This function computes the t-test statistic t for the two datasets data1 and data2 of lengths
+/// n1 and n2 with strides stride1 and stride2, using the pooled variance of the two datasets,
+///
+/// t = (\Hat\mu_1 - \Hat\mu_2) / \sqrt{\sigma_p^2 (1/n_1 + 1/n_2)}
+///
+/// Under the null hypothesis that both datasets are drawn from Gaussian distributions with the
+/// same mean and variance, t follows a Student's t-distribution with n_1 + n_2 - 2 degrees of
+/// freedom.
+///
+/// [`stats::ttest`](crate::stats::ttest) computes the same value with the lengths of the slices
+/// as n1 and n2, and [`stats::tests`](crate::stats::tests) provides the p-values.
+#[doc(alias = "gsl_stats_ttest")]
+pub fn ttest(
+    data1: &[f64],
+    stride1: usize,
+    n1: usize,
+    data2: &[f64],
+    stride2: usize,
+    n2: usize,
+) -> f64 {
+    check_data(data1.len(), stride1, n1);
+    check_data(data2.len(), stride2, n2);
+    unsafe { sys::gsl_stats_ttest(data1.as_ptr(), stride1, n1, data2.as_ptr(), stride2, n2) }
+}
+
 /// This function returns the weighted mean of the dataset data with stride stride and length n,
 /// using the set of weights w with stride wstride and length n. The weighted mean is defined as,
 ///
@@ -581,3 +660,771 @@ pub fn median_from_sorted_data(data: &[f64], stride: usize, n: usize) -> f64 {
 pub fn quantile_from_sorted_data(data: &[f64], stride: usize, n: usize, f: f64) -> f64 {
     unsafe { sys::gsl_stats_quantile_from_sorted_data(data.as_ptr(), stride, n, f) }
 }
+
+/// This function returns the median value of data, a dataset of length n with stride stride. The
+/// data does not need to be sorted: the median is found with the quickselect algorithm, which
+/// rearranges the elements of data.
+///
+/// [`stats::median`](crate::stats::median) is the same function with the length of the slice as
+/// n.
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_median")]
+pub fn median(data: &mut [f64], stride: usize, n: usize) -> f64 {
+    check_data(data.len(), stride, n);
+    unsafe { sys::gsl_stats_median(data.as_mut_ptr(), stride, n) }
+}
+
+/// This function finds the k-th smallest element of the input array data of length n and stride
+/// stride, with k counted from 0. The elements of data are rearranged with the quickselect
+/// algorithm, so that on output the element at index k is the one which would be there if data
+/// were sorted.
+///
+/// [`stats::select`](crate::stats::select) is the same function with the length of the slice as
+/// n.
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_select")]
+pub fn select(data: &mut [f64], stride: usize, n: usize, k: usize) -> f64 {
+    check_data(data.len(), stride, n);
+    assert!(k < n);
+    unsafe { sys::gsl_stats_select(data.as_mut_ptr(), stride, n, k) }
+}
+
+/// This function returns the trimmed mean of sorted_data, a dataset of length n with stride
+/// stride, discarding a fraction trim of the smallest and largest elements. The trimming factor
+/// trim must be between 0 and 0.5. With trim = 0 this is the sample mean, and as trim approaches
+/// 0.5 it approaches the median.
+///
+/// The elements of the array must be in ascending numerical order.
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_trmean_from_sorted_data")]
+pub fn trmean_from_sorted_data(trim: f64, sorted_data: &[f64], stride: usize, n: usize) -> f64 {
+    check_data(sorted_data.len(), stride, n);
+    unsafe { sys::gsl_stats_trmean_from_sorted_data(trim, sorted_data.as_ptr(), stride, n) }
+}
+
+/// This function returns the Gastwirth location estimator of sorted_data, a dataset of length n
+/// with stride stride. The Gastwirth estimator is a weighted average of the 1/3, 1/2 and 2/3
+/// quantiles,
+///
+/// \hat{\mu}_G = 0.3 Q_{1/3} + 0.4 Q_{1/2} + 0.3 Q_{2/3}
+///
+/// The elements of the array must be in ascending numerical order.
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_gastwirth_from_sorted_data")]
+pub fn gastwirth_from_sorted_data(sorted_data: &[f64], stride: usize, n: usize) -> f64 {
+    check_data(sorted_data.len(), stride, n);
+    unsafe { sys::gsl_stats_gastwirth_from_sorted_data(sorted_data.as_ptr(), stride, n) }
+}
+
+/// This function returns the median absolute deviation (MAD) of data, a dataset of length n with
+/// stride stride, without the scale factor,
+///
+/// MAD = median { |x_i - median(x)| }
+///
+/// Additional workspace of size n is required in work.
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_mad0")]
+pub fn mad0(data: &[f64], stride: usize, n: usize, work: &mut [f64]) -> f64 {
+    check_data(data.len(), stride, n);
+    assert!(work.len() >= n);
+    unsafe { sys::gsl_stats_mad0(data.as_ptr(), stride, n, work.as_mut_ptr()) }
+}
+
+/// This function returns the median absolute deviation (MAD) of data, a dataset of length n with
+/// stride stride, scaled by 1.4826 so that it is an unbiased estimate of the standard deviation
+/// for Gaussian data,
+///
+/// MAD = 1.4826 median { |x_i - median(x)| }
+///
+/// Additional workspace of size n is required in work.
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_mad")]
+pub fn mad(data: &[f64], stride: usize, n: usize, work: &mut [f64]) -> f64 {
+    check_data(data.len(), stride, n);
+    assert!(work.len() >= n);
+    unsafe { sys::gsl_stats_mad(data.as_ptr(), stride, n, work.as_mut_ptr()) }
+}
+
+/// This function returns the S_n statistic of sorted_data, a dataset of length n with stride
+/// stride, without the scale factor,
+///
+/// S_n = median_i { median_j { |x_i - x_j| } }
+///
+/// The elements of the array must be in ascending numerical order. Additional workspace of size n
+/// is required in work.
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_Sn0_from_sorted_data")]
+pub fn Sn0_from_sorted_data(sorted_data: &[f64], stride: usize, n: usize, work: &mut [f64]) -> f64 {
+    check_data(sorted_data.len(), stride, n);
+    assert!(work.len() >= n);
+    unsafe {
+        sys::gsl_stats_Sn0_from_sorted_data(sorted_data.as_ptr(), stride, n, work.as_mut_ptr())
+    }
+}
+
+/// This function returns the S_n statistic of sorted_data, a dataset of length n with stride
+/// stride, scaled by a correction factor so that it is an unbiased estimate of the standard
+/// deviation for Gaussian data. Unlike the MAD, S_n does not assume a symmetric distribution.
+///
+/// The elements of the array must be in ascending numerical order. Additional workspace of size n
+/// is required in work.
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_Sn_from_sorted_data")]
+pub fn Sn_from_sorted_data(sorted_data: &[f64], stride: usize, n: usize, work: &mut [f64]) -> f64 {
+    check_data(sorted_data.len(), stride, n);
+    assert!(work.len() >= n);
+    unsafe {
+        sys::gsl_stats_Sn_from_sorted_data(sorted_data.as_ptr(), stride, n, work.as_mut_ptr())
+    }
+}
+
+/// This function returns the Q_n statistic of sorted_data, a dataset of length n with stride
+/// stride, without the scale factor. Q_n is the first quartile of the pairwise distances
+/// |x_i - x_j| for i < j.
+///
+/// The elements of the array must be in ascending numerical order. Additional workspace of size
+/// 3n is required in work and of size 5n in work_int.
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_Qn0_from_sorted_data")]
+pub fn Qn0_from_sorted_data(
+    sorted_data: &[f64],
+    stride: usize,
+    n: usize,
+    work: &mut [f64],
+    work_int: &mut [i32],
+) -> f64 {
+    check_data(sorted_data.len(), stride, n);
+    assert!(work.len() >= 3 * n && work_int.len() >= 5 * n);
+    unsafe {
+        sys::gsl_stats_Qn0_from_sorted_data(
+            sorted_data.as_ptr(),
+            stride,
+            n,
+            work.as_mut_ptr(),
+            work_int.as_mut_ptr(),
+        )
+    }
+}
+
+/// This function returns the Q_n statistic of sorted_data, a dataset of length n with stride
+/// stride, scaled by a correction factor so that it is an unbiased estimate of the standard
+/// deviation for Gaussian data. Q_n has a higher efficiency than S_n for Gaussian data.
+///
+/// The elements of the array must be in ascending numerical order. Additional workspace of size
+/// 3n is required in work and of size 5n in work_int.
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_Qn_from_sorted_data")]
+pub fn Qn_from_sorted_data(
+    sorted_data: &[f64],
+    stride: usize,
+    n: usize,
+    work: &mut [f64],
+    work_int: &mut [i32],
+) -> f64 {
+    check_data(sorted_data.len(), stride, n);
+    assert!(work.len() >= 3 * n && work_int.len() >= 5 * n);
+    unsafe {
+        sys::gsl_stats_Qn_from_sorted_data(
+            sorted_data.as_ptr(),
+            stride,
+            n,
+            work.as_mut_ptr(),
+            work_int.as_mut_ptr(),
+        )
+    }
+}
+
+macro_rules! gsl_stats {
+    ($module:ident, $name:ident, $rust_ty:ty) => (
+paste! {
+#[doc = "Statistics for datasets of `" $rust_ty "` elements."]
+///
+/// The functions of this module behave like the `f64` functions of the parent module. The
+/// results which are not elements of the dataset are returned as `f64`.
+pub mod $module {
+    #[allow(unused_imports)]
+    use std::os::raw::{c_char, c_long, c_uchar, c_ulong};
+
+    /// This function returns the arithmetic mean of data, a dataset of length n with stride
+    /// stride.
+    #[doc(alias = $name _mean)]
+    pub fn mean(data: &[$rust_ty], stride: usize, n: usize) -> f64 {
+        unsafe { sys::[<$name _mean>](data.as_ptr(), stride, n) }
+    }
+
+    /// This function returns the estimated, or sample, variance of data.
+    #[doc(alias = $name _variance)]
+    pub fn variance(data: &[$rust_ty], stride: usize, n: usize) -> f64 {
+        unsafe { sys::[<$name _variance>](data.as_ptr(), stride, n) }
+    }
+
+    /// This function returns the sample variance of data relative to the given value of mean.
+    #[doc(alias = $name _variance_m)]
+    pub fn variance_m(data: &[$rust_ty], stride: usize, n: usize, mean: f64) -> f64 {
+        unsafe { sys::[<$name _variance_m>](data.as_ptr(), stride, n, mean) }
+    }
+
+    /// This function returns the standard deviation of data.
+    #[doc(alias = $name _sd)]
+    pub fn sd(data: &[$rust_ty], stride: usize, n: usize) -> f64 {
+        unsafe { sys::[<$name _sd>](data.as_ptr(), stride, n) }
+    }
+
+    /// This function returns the standard deviation of data relative to the given value of mean.
+    #[doc(alias = $name _sd_m)]
+    pub fn sd_m(data: &[$rust_ty], stride: usize, n: usize, mean: f64) -> f64 {
+        unsafe { sys::[<$name _sd_m>](data.as_ptr(), stride, n, mean) }
+    }
+
+    /// This function returns the total sum of squares (TSS) of data about the mean.
+    #[doc(alias = $name _tss)]
+    pub fn tss(data: &[$rust_ty], stride: usize, n: usize) -> f64 {
+        unsafe { sys::[<$name _tss>](data.as_ptr(), stride, n) }
+    }
+
+    /// This function returns the total sum of squares (TSS) of data about the given mean.
+    #[doc(alias = $name _tss_m)]
+    pub fn tss_m(data: &[$rust_ty], stride: usize, n: usize, mean: f64) -> f64 {
+        unsafe { sys::[<$name _tss_m>](data.as_ptr(), stride, n, mean) }
+    }
+
+    /// This function computes an unbiased estimate of the variance of data when the population
+    /// mean mean of the underlying distribution is known a priori.
+    #[doc(alias = $name _variance_with_fixed_mean)]
+    pub fn variance_with_fixed_mean(data: &[$rust_ty], stride: usize, n: usize, mean: f64) -> f64 {
+        unsafe { sys::[<$name _variance_with_fixed_mean>](data.as_ptr(), stride, n, mean) }
+    }
+
+    /// This function calculates the standard deviation of data for a fixed population mean mean.
+    #[doc(alias = $name _sd_with_fixed_mean)]
+    pub fn sd_with_fixed_mean(data: &[$rust_ty], stride: usize, n: usize, mean: f64) -> f64 {
+        unsafe { sys::[<$name _sd_with_fixed_mean>](data.as_ptr(), stride, n, mean) }
+    }
+
+    /// This function computes the absolute deviation from the mean of data.
+    #[doc(alias = $name _absdev)]
+    pub fn absdev(data: &[$rust_ty], stride: usize, n: usize) -> f64 {
+        unsafe { sys::[<$name _absdev>](data.as_ptr(), stride, n) }
+    }
+
+    /// This function computes the absolute deviation of data relative to the given value of mean.
+    #[doc(alias = $name _absdev_m)]
+    pub fn absdev_m(data: &[$rust_ty], stride: usize, n: usize, mean: f64) -> f64 {
+        unsafe { sys::[<$name _absdev_m>](data.as_ptr(), stride, n, mean) }
+    }
+
+    /// This function computes the skewness of data.
+    #[doc(alias = $name _skew)]
+    pub fn skew(data: &[$rust_ty], stride: usize, n: usize) -> f64 {
+        unsafe { sys::[<$name _skew>](data.as_ptr(), stride, n) }
+    }
+
+    /// This function computes the skewness of data using the given values of the mean and
+    /// standard deviation.
+    #[doc(alias = $name _skew_m_sd)]
+    pub fn skew_m_sd(data: &[$rust_ty], stride: usize, n: usize, mean: f64, sd: f64) -> f64 {
+        unsafe { sys::[<$name _skew_m_sd>](data.as_ptr(), stride, n, mean, sd) }
+    }
+
+    /// This function computes the kurtosis of data.
+    #[doc(alias = $name _kurtosis)]
+    pub fn kurtosis(data: &[$rust_ty], stride: usize, n: usize) -> f64 {
+        unsafe { sys::[<$name _kurtosis>](data.as_ptr(), stride, n) }
+    }
+
+    /// This function computes the kurtosis of data using the given values of the mean and
+    /// standard deviation.
+    #[doc(alias = $name _kurtosis_m_sd)]
+    pub fn kurtosis_m_sd(data: &[$rust_ty], stride: usize, n: usize, mean: f64, sd: f64) -> f64 {
+        unsafe { sys::[<$name _kurtosis_m_sd>](data.as_ptr(), stride, n, mean, sd) }
+    }
+
+    /// This function computes the lag-1 autocorrelation of data.
+    #[doc(alias = $name _lag1_autocorrelation)]
+    pub fn lag1_autocorrelation(data: &[$rust_ty], stride: usize, n: usize) -> f64 {
+        unsafe { sys::[<$name _lag1_autocorrelation>](data.as_ptr(), stride, n) }
+    }
+
+    /// This function computes the lag-1 autocorrelation of data using the given value of the
+    /// mean.
+    #[doc(alias = $name _lag1_autocorrelation_m)]
+    pub fn lag1_autocorrelation_m(data: &[$rust_ty], stride: usize, n: usize, mean: f64) -> f64 {
+        unsafe { sys::[<$name _lag1_autocorrelation_m>](data.as_ptr(), stride, n, mean) }
+    }
+
+    /// This function computes the covariance of the datasets data1 and data2 which must both be
+    /// of the same length n.
+    #[doc(alias = $name _covariance)]
+    pub fn covariance(
+        data1: &[$rust_ty],
+        stride1: usize,
+        data2: &[$rust_ty],
+        stride2: usize,
+        n: usize,
+    ) -> f64 {
+        unsafe { sys::[<$name _covariance>](data1.as_ptr(), stride1, data2.as_ptr(), stride2, n) }
+    }
+
+    /// This function computes the covariance of the datasets data1 and data2 using the given
+    /// values of the means, mean1 and mean2.
+    #[doc(alias = $name _covariance_m)]
+    pub fn covariance_m(
+        data1: &[$rust_ty],
+        stride1: usize,
+        data2: &[$rust_ty],
+        stride2: usize,
+        n: usize,
+        mean1: f64,
+        mean2: f64,
+    ) -> f64 {
+        unsafe {
+            sys::[<$name _covariance_m>](
+                data1.as_ptr(),
+                stride1,
+                data2.as_ptr(),
+                stride2,
+                n,
+                mean1,
+                mean2,
+            )
+        }
+    }
+
+    /// This function efficiently computes the Pearson correlation coefficient between the
+    /// datasets data1 and data2 which must both be of the same length n.
+    #[doc(alias = $name _correlation)]
+    pub fn correlation(
+        data1: &[$rust_ty],
+        stride1: usize,
+        data2: &[$rust_ty],
+        stride2: usize,
+        n: usize,
+    ) -> f64 {
+        unsafe { sys::[<$name _correlation>](data1.as_ptr(), stride1, data2.as_ptr(), stride2, n) }
+    }
+
+    /// This function computes the Spearman rank correlation coefficient between the datasets
+    /// data1 and data2 which must both be of the same length n. Additional workspace of size 2*n
+    /// is required in work.
+    #[doc(alias = $name _spearman)]
+    pub fn spearman(
+        data1: &[$rust_ty],
+        stride1: usize,
+        data2: &[$rust_ty],
+        stride2: usize,
+        n: usize,
+        work: &mut [f64],
+    ) -> f64 {
+        unsafe {
+            sys::[<$name _spearman>](
+                data1.as_ptr(),
+                stride1,
+                data2.as_ptr(),
+                stride2,
+                n,
+                work.as_mut_ptr(),
+            )
+        }
+    }
+
+    /// This function returns the pooled variance of the two independent datasets data1 and
+    /// data2 of lengths n1 and n2.
+    #[doc(alias = $name _pvariance)]
+    pub fn pvariance(
+        data1: &[$rust_ty],
+        stride1: usize,
+        n1: usize,
+        data2: &[$rust_ty],
+        stride2: usize,
+        n2: usize,
+    ) -> f64 {
+        unsafe {
+            sys::[<$name _pvariance>](data1.as_ptr(), stride1, n1, data2.as_ptr(), stride2, n2)
+        }
+    }
+
+    /// This function computes the t-test statistic t for the two datasets data1 and data2 of
+    /// lengths n1 and n2, using their pooled variance.
+    #[doc(alias = $name _ttest)]
+    pub fn ttest(
+        data1: &[$rust_ty],
+        stride1: usize,
+        n1: usize,
+        data2: &[$rust_ty],
+        stride2: usize,
+        n2: usize,
+    ) -> f64 {
+        unsafe { sys::[<$name _ttest>](data1.as_ptr(), stride1, n1, data2.as_ptr(), stride2, n2) }
+    }
+
+    /// This function returns the maximum value in data.
+    #[doc(alias = $name _max)]
+    pub fn max(data: &[$rust_ty], stride: usize, n: usize) -> $rust_ty {
+        unsafe { sys::[<$name _max>](data.as_ptr(), stride, n) }
+    }
+
+    /// This function returns the minimum value in data.
+    #[doc(alias = $name _min)]
+    pub fn min(data: &[$rust_ty], stride: usize, n: usize) -> $rust_ty {
+        unsafe { sys::[<$name _min>](data.as_ptr(), stride, n) }
+    }
+
+    /// This function finds both the minimum and maximum values in data in a single pass.
+    ///
+    /// Returns `(min, max)`.
+    #[doc(alias = $name _minmax)]
+    pub fn minmax(data: &[$rust_ty], stride: usize, n: usize) -> ($rust_ty, $rust_ty) {
+        let mut min = 0 as _;
+        let mut max = 0 as _;
+        unsafe { sys::[<$name _minmax>](&mut min, &mut max, data.as_ptr(), stride, n) };
+        (min, max)
+    }
+
+    /// This function returns the index of the maximum value in data. When there are several
+    /// equal maximum elements then the first one is chosen.
+    #[doc(alias = $name _max_index)]
+    pub fn max_index(data: &[$rust_ty], stride: usize, n: usize) -> usize {
+        unsafe { sys::[<$name _max_index>](data.as_ptr(), stride, n) }
+    }
+
+    /// This function returns the index of the minimum value in data. When there are several
+    /// equal minimum elements then the first one is chosen.
+    #[doc(alias = $name _min_index)]
+    pub fn min_index(data: &[$rust_ty], stride: usize, n: usize) -> usize {
+        unsafe { sys::[<$name _min_index>](data.as_ptr(), stride, n) }
+    }
+
+    /// This function returns the indexes of the minimum and maximum values in data in a single
+    /// pass.
+    ///
+    /// Returns `(min_index, max_index)`.
+    #[doc(alias = $name _minmax_index)]
+    pub fn minmax_index(data: &[$rust_ty], stride: usize, n: usize) -> (usize, usize) {
+        let mut min_index = 0;
+        let mut max_index = 0;
+        unsafe {
+            sys::[<$name _minmax_index>](&mut min_index, &mut max_index, data.as_ptr(), stride, n)
+        };
+        (min_index, max_index)
+    }
+
+    /// This function returns the median value of sorted_data. The elements of the array must be
+    /// in ascending numerical order.
+    #[doc(alias = $name _median_from_sorted_data)]
+    pub fn median_from_sorted_data(sorted_data: &[$rust_ty], stride: usize, n: usize) -> f64 {
+        unsafe { sys::[<$name _median_from_sorted_data>](sorted_data.as_ptr(), stride, n) }
+    }
+
+    /// This function returns a quantile value of sorted_data, determined by the fraction f
+    /// between 0 and 1. The elements of the array must be in ascending numerical order.
+    #[doc(alias = $name _quantile_from_sorted_data)]
+    pub fn quantile_from_sorted_data(
+        sorted_data: &[$rust_ty],
+        stride: usize,
+        n: usize,
+        f: f64,
+    ) -> f64 {
+        unsafe { sys::[<$name _quantile_from_sorted_data>](sorted_data.as_ptr(), stride, n, f) }
+    }
+
+    /// This function returns the median value of data. The data does not need to be sorted, its
+    /// elements are rearranged to find the median.
+    #[cfg(feature = "v2_5")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+    #[doc(alias = $name _median)]
+    pub fn median(data: &mut [$rust_ty], stride: usize, n: usize) -> f64 {
+        super::check_data(data.len(), stride, n);
+        unsafe { sys::[<$name _median>](data.as_mut_ptr(), stride, n) }
+    }
+
+    /// This function finds the k-th smallest element of data, with k counted from 0. The
+    /// elements of data are rearranged.
+    #[cfg(feature = "v2_5")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+    #[doc(alias = $name _select)]
+    pub fn select(data: &mut [$rust_ty], stride: usize, n: usize, k: usize) -> $rust_ty {
+        super::check_data(data.len(), stride, n);
+        assert!(k < n);
+        unsafe { sys::[<$name _select>](data.as_mut_ptr(), stride, n, k) }
+    }
+
+    /// This function returns the trimmed mean of sorted_data, discarding a fraction trim of the
+    /// smallest and largest elements. The elements of the array must be in ascending numerical
+    /// order.
+    #[cfg(feature = "v2_5")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+    #[doc(alias = $name _trmean_from_sorted_data)]
+    pub fn trmean_from_sorted_data(
+        trim: f64,
+        sorted_data: &[$rust_ty],
+        stride: usize,
+        n: usize,
+    ) -> f64 {
+        super::check_data(sorted_data.len(), stride, n);
+        unsafe { sys::[<$name _trmean_from_sorted_data>](trim, sorted_data.as_ptr(), stride, n) }
+    }
+
+    /// This function returns the Gastwirth location estimator of sorted_data. The elements of
+    /// the array must be in ascending numerical order.
+    #[cfg(feature = "v2_5")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+    #[doc(alias = $name _gastwirth_from_sorted_data)]
+    pub fn gastwirth_from_sorted_data(sorted_data: &[$rust_ty], stride: usize, n: usize) -> f64 {
+        super::check_data(sorted_data.len(), stride, n);
+        unsafe { sys::[<$name _gastwirth_from_sorted_data>](sorted_data.as_ptr(), stride, n) }
+    }
+
+    /// This function returns the median absolute deviation of data, without the scale factor.
+    /// Additional workspace of size n is required in work.
+    #[cfg(feature = "v2_5")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+    #[doc(alias = $name _mad0)]
+    pub fn mad0(data: &[$rust_ty], stride: usize, n: usize, work: &mut [f64]) -> f64 {
+        super::check_data(data.len(), stride, n);
+        assert!(work.len() >= n);
+        unsafe { sys::[<$name _mad0>](data.as_ptr(), stride, n, work.as_mut_ptr()) }
+    }
+
+    /// This function returns the median absolute deviation of data, scaled by 1.4826.
+    /// Additional workspace of size n is required in work.
+    #[cfg(feature = "v2_5")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+    #[doc(alias = $name _mad)]
+    pub fn mad(data: &[$rust_ty], stride: usize, n: usize, work: &mut [f64]) -> f64 {
+        super::check_data(data.len(), stride, n);
+        assert!(work.len() >= n);
+        unsafe { sys::[<$name _mad>](data.as_ptr(), stride, n, work.as_mut_ptr()) }
+    }
+
+    /// This function returns the S_n statistic of sorted_data, without the scale factor. The
+    /// elements of the array must be in ascending numerical order. Additional workspace of size
+    /// n is required in work.
+    #[cfg(feature = "v2_5")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+    #[doc(alias = $name _Sn0_from_sorted_data)]
+    pub fn Sn0_from_sorted_data(
+        sorted_data: &[$rust_ty],
+        stride: usize,
+        n: usize,
+        work: &mut [$rust_ty],
+    ) -> $rust_ty {
+        super::check_data(sorted_data.len(), stride, n);
+        assert!(work.len() >= n);
+        unsafe {
+            sys::[<$name _Sn0_from_sorted_data>](
+                sorted_data.as_ptr(),
+                stride,
+                n,
+                work.as_mut_ptr(),
+            )
+        }
+    }
+
+    /// This function returns the S_n statistic of sorted_data, scaled to be an unbiased estimate
+    /// of the standard deviation for Gaussian data. The elements of the array must be in
+    /// ascending numerical order. Additional workspace of size n is required in work.
+    #[cfg(feature = "v2_5")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+    #[doc(alias = $name _Sn_from_sorted_data)]
+    pub fn Sn_from_sorted_data(
+        sorted_data: &[$rust_ty],
+        stride: usize,
+        n: usize,
+        work: &mut [$rust_ty],
+    ) -> f64 {
+        super::check_data(sorted_data.len(), stride, n);
+        assert!(work.len() >= n);
+        unsafe {
+            sys::[<$name _Sn_from_sorted_data>](sorted_data.as_ptr(), stride, n, work.as_mut_ptr())
+        }
+    }
+
+    /// This function returns the Q_n statistic of sorted_data, without the scale factor. The
+    /// elements of the array must be in ascending numerical order. Additional workspace of size
+    /// 3n is required in work and of size 5n in work_int.
+    #[cfg(feature = "v2_5")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+    #[doc(alias = $name _Qn0_from_sorted_data)]
+    pub fn Qn0_from_sorted_data(
+        sorted_data: &[$rust_ty],
+        stride: usize,
+        n: usize,
+        work: &mut [$rust_ty],
+        work_int: &mut [i32],
+    ) -> $rust_ty {
+        super::check_data(sorted_data.len(), stride, n);
+        assert!(work.len() >= 3 * n && work_int.len() >= 5 * n);
+        unsafe {
+            sys::[<$name _Qn0_from_sorted_data>](
+                sorted_data.as_ptr(),
+                stride,
+                n,
+                work.as_mut_ptr(),
+                work_int.as_mut_ptr(),
+            )
+        }
+    }
+
+    /// This function returns the Q_n statistic of sorted_data, scaled to be an unbiased estimate
+    /// of the standard deviation for Gaussian data. The elements of the array must be in
+    /// ascending numerical order. Additional workspace of size 3n is required in work and of size
+    /// 5n in work_int.
+    #[cfg(feature = "v2_5")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+    #[doc(alias = $name _Qn_from_sorted_data)]
+    pub fn Qn_from_sorted_data(
+        sorted_data: &[$rust_ty],
+        stride: usize,
+        n: usize,
+        work: &mut [$rust_ty],
+        work_int: &mut [i32],
+    ) -> f64 {
+        super::check_data(sorted_data.len(), stride, n);
+        assert!(work.len() >= 3 * n && work_int.len() >= 5 * n);
+        unsafe {
+            sys::[<$name _Qn_from_sorted_data>](
+                sorted_data.as_ptr(),
+                stride,
+                n,
+                work.as_mut_ptr(),
+                work_int.as_mut_ptr(),
+            )
+        }
+    }
+}
+} // end of paste! block
+    ); // end of gsl_stats macro
+}
+
+gsl_stats!(float, gsl_stats_float, f32);
+gsl_stats!(int, gsl_stats_int, i32);
+gsl_stats!(uint, gsl_stats_uint, u32);
+gsl_stats!(long, gsl_stats_long, c_long);
+gsl_stats!(ulong, gsl_stats_ulong, c_ulong);
+gsl_stats!(short, gsl_stats_short, i16);
+gsl_stats!(ushort, gsl_stats_ushort, u16);
+gsl_stats!(char, gsl_stats_char, c_char);
+gsl_stats!(uchar, gsl_stats_uchar, c_uchar);
+
+#[cfg(any(test, doctest))]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "v2_5")]
+    fn test_robust() {
+        let mut data = [3., 1., 100., 4., 2.];
+        assert_eq!(median(&mut data, 1, 5), 3.);
+        let mut data = [3., 1., 100., 4., 2.];
+        assert_eq!(select(&mut data, 1, 5, 1), 2.);
+        assert_eq!(data[1], 2.);
+        // Every other element: 3, 100, 2.
+        let mut data = [3., 1., 100., 4., 2.];
+        assert_eq!(median(&mut data, 2, 3), 3.);
+
+        let data = [3., 1., 100., 4., 2.];
+        let mut work = [0.; 5];
+        assert_eq!(mad0(&data, 1, 5, &mut work), 1.);
+        assert!((mad(&data, 1, 5, &mut work) - 1.4826).abs() < 1e-12);
+
+        let sorted = [1., 2., 3., 4., 100.];
+        assert_eq!(trmean_from_sorted_data(0.2, &sorted, 1, 5), 3.);
+        assert!((gastwirth_from_sorted_data(&sorted, 1, 5) - 3.).abs() < 1e-12);
+        assert_eq!(Sn0_from_sorted_data(&sorted, 1, 5, &mut work), 2.);
+        assert!(Sn_from_sorted_data(&sorted, 1, 5, &mut work) > 2.);
+        let mut work = [0.; 15];
+        let mut work_int = [0; 25];
+        assert_eq!(
+            Qn0_from_sorted_data(&sorted, 1, 5, &mut work, &mut work_int),
+            1.
+        );
+        assert!(Qn_from_sorted_data(&sorted, 1, 5, &mut work, &mut work_int) > 1.);
+    }
+
+    #[test]
+    fn test_pvariance() {
+        let a = [1., 2., 3.];
+        let b = [2., 4., 6., 8.];
+        // (2 * 1 + 3 * 20 / 3) / 5
+        assert!((pvariance(&a, 1, 3, &b, 1, 4) - 22. / 5.).abs() < 1e-12);
+        assert!(ttest(&a, 1, 3, &b, 1, 4) < 0.);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_ttest_bad_length() {
+        ttest(&[1., 2., 3.], 1, 3, &[2., 4.], 1, 3);
+    }
+
+    #[test]
+    fn test_element_types() {
+        assert_eq!(float::mean(&[1., 2., 3., 6.], 1, 4), 3.);
+        assert_eq!(int::max(&[-4, 7, 2], 1, 3), 7);
+        assert_eq!(uint::variance(&[1, 2, 3], 1, 3), 1.);
+        assert_eq!(long::min(&[5, -3, 8], 1, 3), -3);
+        assert_eq!(ulong::mean(&[2, 4, 6], 1, 3), 4.);
+        assert_eq!(short::max_index(&[1, 9, 3], 1, 3), 1);
+        assert_eq!(ushort::mean(&[10, 20, 30, 40], 2, 2), 20.);
+        assert_eq!(char::min(&[3, 1, 2], 1, 3), 1);
+        assert_eq!(uchar::max(&[3, 250, 2], 1, 3), 250);
+    }
+
+    #[test]
+    #[cfg(feature = "v2_5")]
+    fn test_element_types_robust() {
+        let mut data = [7u16, 1, 3, 100, 4];
+        assert_eq!(ushort::median(&mut data, 1, 5), 4.);
+        let mut data = [7i32, 1, 3, 100, 4];
+        assert_eq!(int::select(&mut data, 1, 5, 0), 1);
+        let sorted = [1f32, 2., 3., 4., 100.];
+        let mut work = [0f32; 5];
+        assert_eq!(float::Sn0_from_sorted_data(&sorted, 1, 5, &mut work), 2.);
+        let mut work = [0.; 5];
+        assert_eq!(float::mad0(&sorted, 1, 5, &mut work), 1.);
+    }
+
+    #[test]
+    #[cfg(feature = "v2_5")]
+    #[should_panic]
+    fn test_element_types_short_work() {
+        let sorted = [1u32, 2, 3, 4, 100];
+        let mut work = [0u32; 15];
+        let mut work_int = [0; 24];
+        uint::Qn0_from_sorted_data(&sorted, 1, 5, &mut work, &mut work_int);
+    }
+
+    #[test]
+    #[cfg(feature = "v2_5")]
+    #[should_panic]
+    fn test_robust_short_work() {
+        let data = [3., 1., 100., 4., 2.];
+        let mut work = [0.; 4];
+        mad(&data, 1, 5, &mut work);
+    }
+
+    #[test]
+    #[cfg(feature = "v2_5")]
+    #[should_panic]
+    fn test_robust_bad_stride() {
+        let mut data = [3., 1., 100., 4., 2.];
+        median(&mut data, 2, 4);
+    }
+
+    #[test]
+    #[cfg(feature = "v2_5")]
+    #[should_panic]
+    fn test_select_out_of_range() {
+        let mut data = [3., 1., 100., 4., 2.];
+        select(&mut data, 1, 5, 5);
+    }
+}
@@ -0,0 +1,554 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+use crate::{statistics, Value, VectorF64, VectorF64View};
+use ffi::FFI;
+
+pub mod tests;
+
+#[doc(alias = "gsl_stats_wtss")]
+pub fn wtss(w: &[f64], wstride: usize, data: &[f64], stride: usize) -> f64 {
+    unsafe { sys::gsl_stats_wtss(w.as_ptr(), wstride, data.as_ptr(), stride, data.len() as _) }
+}
+
+#[doc(alias = "gsl_stats_wtss_m")]
+pub fn wtss_m(w: &[f64], wstride: usize, data: &[f64], stride: usize, wmean: f64) -> f64 {
+    unsafe {
+        sys::gsl_stats_wtss_m(
+            w.as_ptr(),
+            wstride,
+            data.as_ptr(),
+            stride,
+            data.len() as _,
+            wmean,
+        )
+    }
+}
+
+#[doc(alias = "gsl_stats_wabsdev")]
+pub fn wabsdev(w: &[f64], wstride: usize, data: &[f64], stride: usize) -> f64 {
+    unsafe { sys::gsl_stats_wabsdev(w.as_ptr(), wstride, data.as_ptr(), stride, data.len() as _) }
+}
+
+#[doc(alias = "gsl_stats_wskew")]
+pub fn wskew(w: &[f64], wstride: usize, data: &[f64], stride: usize) -> f64 {
+    unsafe { sys::gsl_stats_wskew(w.as_ptr(), wstride, data.as_ptr(), stride, data.len() as _) }
+}
+
+#[doc(alias = "gsl_stats_wkurtosis")]
+pub fn wkurtosis(w: &[f64], wstride: usize, data: &[f64], stride: usize) -> f64 {
+    unsafe { sys::gsl_stats_wkurtosis(w.as_ptr(), wstride, data.as_ptr(), stride, data.len() as _) }
+}
+
+#[doc(alias = "gsl_stats_wvariance_m")]
+pub fn wvariance_m(w: &[f64], wstride: usize, data: &[f64], stride: usize, wmean: f64) -> f64 {
+    unsafe {
+        sys::gsl_stats_wvariance_m(
+            w.as_ptr(),
+            wstride,
+            data.as_ptr(),
+            stride,
+            data.len() as _,
+            wmean,
+        )
+    }
+}
+
+#[doc(alias = "gsl_stats_wabsdev_m")]
+pub fn wabsdev_m(w: &[f64], wstride: usize, data: &[f64], stride: usize, wmean: f64) -> f64 {
+    unsafe {
+        sys::gsl_stats_wabsdev_m(
+            w.as_ptr(),
+            wstride,
+            data.as_ptr(),
+            stride,
+            data.len() as _,
+            wmean,
+        )
+    }
+}
+
+#[doc(alias = "gsl_stats_wskew_m_sd")]
+pub fn wskew_m_sd(
+    w: &[f64],
+    wstride: usize,
+    data: &[f64],
+    stride: usize,
+    wmean: f64,
+    wsd: f64,
+) -> f64 {
+    unsafe {
+        sys::gsl_stats_wskew_m_sd(
+            w.as_ptr(),
+            wstride,
+            data.as_ptr(),
+            stride,
+            data.len() as _,
+            wmean,
+            wsd,
+        )
+    }
+}
+
+#[doc(alias = "gsl_stats_wkurtosis_m_sd")]
+pub fn wkurtosis_m_sd(
+    w: &[f64],
+    wstride: usize,
+    data: &[f64],
+    stride: usize,
+    wmean: f64,
+    wsd: f64,
+) -> f64 {
+    unsafe {
+        sys::gsl_stats_wkurtosis_m_sd(
+            w.as_ptr(),
+            wstride,
+            data.as_ptr(),
+            stride,
+            data.len() as _,
+            wmean,
+            wsd,
+        )
+    }
+}
+
+#[doc(alias = "gsl_stats_pvariance")]
+pub fn pvariance(data1: &[f64], stride1: usize, data2: &[f64], stride2: usize) -> f64 {
+    unsafe {
+        sys::gsl_stats_pvariance(
+            data1.as_ptr(),
+            stride1,
+            data1.len() as _,
+            data2.as_ptr(),
+            stride2,
+            data2.len() as _,
+        )
+    }
+}
+
+#[doc(alias = "gsl_stats_ttest")]
+pub fn ttest(data1: &[f64], stride1: usize, data2: &[f64], stride2: usize) -> f64 {
+    unsafe {
+        sys::gsl_stats_ttest(
+            data1.as_ptr(),
+            stride1,
+            data1.len() as _,
+            data2.as_ptr(),
+            stride2,
+            data2.len() as _,
+        )
+    }
+}
+
+#[doc(alias = "gsl_stats_max")]
+pub fn max(data: &[f64], stride: usize) -> f64 {
+    unsafe { sys::gsl_stats_max(data.as_ptr(), stride, data.len() as _) }
+}
+
+#[doc(alias = "gsl_stats_min")]
+pub fn min(data: &[f64], stride: usize) -> f64 {
+    unsafe { sys::gsl_stats_min(data.as_ptr(), stride, data.len() as _) }
+}
+
+/// Returns `(min, max)`.
+#[doc(alias = "gsl_stats_minmax")]
+pub fn stats_minmax(data: &[f64], stride: usize) -> (f64, f64) {
+    let mut min = 0.;
+    let mut max = 0.;
+
+    unsafe { sys::gsl_stats_minmax(&mut min, &mut max, data.as_ptr(), stride, data.len() as _) }
+    (min, max)
+}
+
+#[doc(alias = "gsl_stats_max_index")]
+pub fn max_index(data: &[f64], stride: usize) -> usize {
+    unsafe { sys::gsl_stats_max_index(data.as_ptr(), stride, data.len() as _) }
+}
+
+#[doc(alias = "gsl_stats_min_index")]
+pub fn min_index(data: &[f64], stride: usize) -> usize {
+    unsafe { sys::gsl_stats_min_index(data.as_ptr(), stride, data.len() as _) }
+}
+
+/// Returns `(min, max)`.
+#[doc(alias = "gsl_stats_minmax_index")]
+pub fn stats_minmax_index(data: &[f64], stride: usize) -> (usize, usize) {
+    let mut min = 0;
+    let mut max = 0;
+
+    unsafe {
+        sys::gsl_stats_minmax_index(&mut min, &mut max, data.as_ptr(), stride, data.len() as _)
+    }
+    (min, max)
+}
+
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_select")]
+pub fn select(data: &mut [f64], stride: usize, k: usize) -> f64 {
+    unsafe { sys::gsl_stats_select(data.as_mut_ptr(), stride, data.len() as _, k) }
+}
+
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_median")]
+pub fn median(data: &mut [f64], stride: usize) -> f64 {
+    unsafe { sys::gsl_stats_median(data.as_mut_ptr(), stride, data.len() as _) }
+}
+
+/// A dataset of `n` elements read every `stride` elements from a slice.
+///
+/// Unlike the raw `(data, stride, n)` arguments of the [`statistics`]
+/// functions, a `Strided` can only be built if all its elements are inside the slice, so the
+/// [`Stats`] methods never read out of bounds.
+#[derive(Clone, Copy, Debug)]
+pub struct Strided<'a> {
+    data: &'a [f64],
+    stride: usize,
+    n: usize,
+}
+
+impl<'a> Strided<'a> {
+    /// Creates a dataset of `n` elements read every `stride` elements from `data`.
+    ///
+    /// Returns `Value::Invalid` if `stride` is 0 and `Value::BadLength` if the last element
+    /// `data[(n - 1) * stride]` is out of bounds.
+    pub fn new(data: &'a [f64], stride: usize, n: usize) -> Result<Strided<'a>, Value> {
+        if stride == 0 {
+            return Err(Value::Invalid);
+        }
+        if n > 0
+            && (n - 1)
+                .checked_mul(stride)
+                .is_none_or(|last| last >= data.len())
+        {
+            return Err(Value::BadLength);
+        }
+        Ok(Strided { data, stride, n })
+    }
+
+    /// Creates a dataset containing every `stride`-th element of `data`, starting with the first
+    /// one.
+    ///
+    /// Returns `Value::Invalid` if `stride` is 0.
+    pub fn with_stride(data: &'a [f64], stride: usize) -> Result<Strided<'a>, Value> {
+        if stride == 0 {
+            return Err(Value::Invalid);
+        }
+        Strided::new(data, stride, data.len().div_ceil(stride))
+    }
+
+    /// Returns the number of elements of the dataset.
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// Returns an iterator over the elements of the dataset.
+    pub fn iter(&self) -> impl Iterator<Item = f64> + 'a {
+        self.data.iter().step_by(self.stride).take(self.n).copied()
+    }
+
+    fn non_empty(self) -> Result<Strided<'a>, Value> {
+        if self.n == 0 {
+            Err(Value::BadLength)
+        } else {
+            Ok(self)
+        }
+    }
+
+    fn sorted(&self) -> Vec<f64> {
+        let mut sorted: Vec<f64> = self.iter().collect();
+        sorted.sort_by(f64::total_cmp);
+        sorted
+    }
+}
+
+/// Checked statistics over a dataset.
+///
+/// This trait is implemented for `[f64]`, [`VectorF64`], [`VectorF64View`] and [`Strided`]. The
+/// stride and the number of elements are taken from the dataset itself, and the methods return
+/// an error instead of calling GSL with invalid arguments:
+///
+/// * `Value::BadLength` if the dataset is empty, or if two datasets don't have the same length.
+/// * `Value::Fault` if a vector has no data.
+/// * `Value::Domain` if a quantile is not between 0 and 1.
+///
+/// ```
+/// use rgsl::stats::Stats;
+///
+/// let data = [1., 2., 4., 8.];
+/// assert_eq!(data.mean(), Ok(3.75));
+/// assert_eq!([0f64; 0][..].mean(), Err(rgsl::Value::BadLength));
+/// ```
+pub trait Stats {
+    /// Returns the elements of the dataset.
+    fn strided(&self) -> Result<Strided<'_>, Value>;
+
+    /// Returns the arithmetic mean of the dataset.
+    fn mean(&self) -> Result<f64, Value> {
+        let s = self.strided()?.non_empty()?;
+        Ok(statistics::mean(s.data, s.stride, s.n))
+    }
+
+    /// Returns the sample variance of the dataset.
+    fn variance(&self) -> Result<f64, Value> {
+        let s = self.strided()?.non_empty()?;
+        Ok(statistics::variance(s.data, s.stride, s.n))
+    }
+
+    /// Returns the sample variance of the dataset relative to the given value of `mean`.
+    fn variance_m(&self, mean: f64) -> Result<f64, Value> {
+        let s = self.strided()?.non_empty()?;
+        Ok(statistics::variance_m(s.data, s.stride, s.n, mean))
+    }
+
+    /// Returns the standard deviation of the dataset.
+    fn sd(&self) -> Result<f64, Value> {
+        let s = self.strided()?.non_empty()?;
+        Ok(statistics::sd(s.data, s.stride, s.n))
+    }
+
+    /// Returns the standard deviation of the dataset relative to the given value of `mean`.
+    fn sd_m(&self, mean: f64) -> Result<f64, Value> {
+        let s = self.strided()?.non_empty()?;
+        Ok(statistics::sd_m(s.data, s.stride, s.n, mean))
+    }
+
+    /// Returns the total sum of squares of the dataset about its mean.
+    fn tss(&self) -> Result<f64, Value> {
+        let s = self.strided()?.non_empty()?;
+        Ok(statistics::tss(s.data, s.stride, s.n))
+    }
+
+    /// Returns the absolute deviation of the dataset from its mean.
+    fn absdev(&self) -> Result<f64, Value> {
+        let s = self.strided()?.non_empty()?;
+        Ok(statistics::absdev(s.data, s.stride, s.n))
+    }
+
+    /// Returns the skewness of the dataset.
+    fn skew(&self) -> Result<f64, Value> {
+        let s = self.strided()?.non_empty()?;
+        Ok(statistics::skew(s.data, s.stride, s.n))
+    }
+
+    /// Returns the kurtosis of the dataset.
+    fn kurtosis(&self) -> Result<f64, Value> {
+        let s = self.strided()?.non_empty()?;
+        Ok(statistics::kurtosis(s.data, s.stride, s.n))
+    }
+
+    /// Returns the lag-1 autocorrelation of the dataset.
+    fn lag1_autocorrelation(&self) -> Result<f64, Value> {
+        let s = self.strided()?.non_empty()?;
+        Ok(statistics::lag1_autocorrelation(s.data, s.stride, s.n))
+    }
+
+    /// Returns the maximum value of the dataset.
+    fn max(&self) -> Result<f64, Value> {
+        let s = self.strided()?.non_empty()?;
+        Ok(statistics::max(s.data, s.stride, s.n))
+    }
+
+    /// Returns the minimum value of the dataset.
+    fn min(&self) -> Result<f64, Value> {
+        let s = self.strided()?.non_empty()?;
+        Ok(statistics::min(s.data, s.stride, s.n))
+    }
+
+    /// Returns `(min, max)`.
+    fn minmax(&self) -> Result<(f64, f64), Value> {
+        let s = self.strided()?.non_empty()?;
+        Ok(statistics::minmax(s.data, s.stride, s.n))
+    }
+
+    /// Returns the index of the maximum value of the dataset.
+    fn max_index(&self) -> Result<usize, Value> {
+        let s = self.strided()?.non_empty()?;
+        Ok(statistics::max_index(s.data, s.stride, s.n))
+    }
+
+    /// Returns the index of the minimum value of the dataset.
+    fn min_index(&self) -> Result<usize, Value> {
+        let s = self.strided()?.non_empty()?;
+        Ok(statistics::min_index(s.data, s.stride, s.n))
+    }
+
+    /// Returns `(min_index, max_index)`.
+    fn minmax_index(&self) -> Result<(usize, usize), Value> {
+        let s = self.strided()?.non_empty()?;
+        Ok(statistics::minmax_index(s.data, s.stride, s.n))
+    }
+
+    /// Returns the median of the dataset. The dataset doesn't need to be sorted, a sorted copy
+    /// is used.
+    fn median(&self) -> Result<f64, Value> {
+        let sorted = self.strided()?.non_empty()?.sorted();
+        Ok(statistics::median_from_sorted_data(
+            &sorted,
+            1,
+            sorted.len(),
+        ))
+    }
+
+    /// Returns the quantile `f` of the dataset, where `f` is between 0 and 1. The dataset
+    /// doesn't need to be sorted, a sorted copy is used.
+    fn quantile(&self, f: f64) -> Result<f64, Value> {
+        if !(0. ..=1.).contains(&f) {
+            return Err(Value::Domain);
+        }
+        let sorted = self.strided()?.non_empty()?.sorted();
+        Ok(statistics::quantile_from_sorted_data(
+            &sorted,
+            1,
+            sorted.len(),
+            f,
+        ))
+    }
+
+    /// Returns the covariance of this dataset and `other`, which must have the same length.
+    fn covariance<S: Stats + ?Sized>(&self, other: &S) -> Result<f64, Value> {
+        let (s1, s2) = same_length(self.strided()?, other.strided()?)?;
+        Ok(statistics::covariance(
+            s1.data, s1.stride, s2.data, s2.stride, s1.n,
+        ))
+    }
+
+    /// Returns the Pearson correlation coefficient of this dataset and `other`, which must have
+    /// the same length.
+    fn correlation<S: Stats + ?Sized>(&self, other: &S) -> Result<f64, Value> {
+        let (s1, s2) = same_length(self.strided()?, other.strided()?)?;
+        Ok(statistics::correlation(
+            s1.data, s1.stride, s2.data, s2.stride, s1.n,
+        ))
+    }
+
+    /// Returns the weighted mean of the dataset, using the weights `w` which must have the same
+    /// length.
+    fn wmean<S: Stats + ?Sized>(&self, w: &S) -> Result<f64, Value> {
+        let (s, w) = same_length(self.strided()?, w.strided()?)?;
+        Ok(statistics::wmean(w.data, w.stride, s.data, s.stride, s.n))
+    }
+
+    /// Returns the weighted variance of the dataset, using the weights `w` which must have the
+    /// same length.
+    fn wvariance<S: Stats + ?Sized>(&self, w: &S) -> Result<f64, Value> {
+        let (s, w) = same_length(self.strided()?, w.strided()?)?;
+        Ok(statistics::wvariance(
+            w.data, w.stride, s.data, s.stride, s.n,
+        ))
+    }
+
+    /// Returns the weighted standard deviation of the dataset, using the weights `w` which must
+    /// have the same length.
+    fn wsd<S: Stats + ?Sized>(&self, w: &S) -> Result<f64, Value> {
+        let (s, w) = same_length(self.strided()?, w.strided()?)?;
+        Ok(statistics::wsd(w.data, w.stride, s.data, s.stride, s.n))
+    }
+}
+
+fn same_length<'a, 'b>(
+    s1: Strided<'a>,
+    s2: Strided<'b>,
+) -> Result<(Strided<'a>, Strided<'b>), Value> {
+    if s1.n != s2.n {
+        Err(Value::BadLength)
+    } else {
+        Ok((s1.non_empty()?, s2))
+    }
+}
+
+impl Stats for Strided<'_> {
+    fn strided(&self) -> Result<Strided<'_>, Value> {
+        Ok(*self)
+    }
+}
+
+impl Stats for [f64] {
+    fn strided(&self) -> Result<Strided<'_>, Value> {
+        Ok(Strided {
+            data: self,
+            stride: 1,
+            n: self.len(),
+        })
+    }
+}
+
+/// Builds the dataset of a `gsl_vector`, which must stay alive for `'a`.
+unsafe fn vector_strided<'a>(v: *const sys::gsl_vector) -> Result<Strided<'a>, Value> {
+    let v = &*v;
+    if v.size == 0 {
+        return Ok(Strided {
+            data: &[],
+            stride: 1,
+            n: 0,
+        });
+    }
+    if v.data.is_null() || v.stride == 0 {
+        return Err(Value::Fault);
+    }
+    // The vector owns (or views) `(size - 1) * stride + 1` contiguous elements.
+    Ok(Strided {
+        data: ::std::slice::from_raw_parts(v.data, (v.size - 1) * v.stride + 1),
+        stride: v.stride,
+        n: v.size,
+    })
+}
+
+impl Stats for VectorF64 {
+    fn strided(&self) -> Result<Strided<'_>, Value> {
+        unsafe { vector_strided(self.unwrap_shared()) }
+    }
+}
+
+impl Stats for VectorF64View<'_> {
+    fn strided(&self) -> Result<Strided<'_>, Value> {
+        let mut ptr = ::std::ptr::null();
+        self.vector(|v| {
+            if let Some(v) = v {
+                ptr = v.unwrap_shared();
+            }
+        });
+        if ptr.is_null() {
+            Err(Value::Fault)
+        } else {
+            // `ptr` points to the `gsl_vector` stored in `self`.
+            unsafe { vector_strided(ptr) }
+        }
+    }
+}
+
+#[cfg(any(test, doctest))]
+mod test_stats {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let data = [1., 10., 2., 20., 4., 40., 8., 80.];
+        assert!((data[..].mean().unwrap() - 20.625).abs() < 1e-12);
+        assert_eq!(data[..0].mean(), Err(Value::BadLength));
+
+        let even = Strided::with_stride(&data, 2).unwrap();
+        assert_eq!(even.len(), 4);
+        assert!((even.mean().unwrap() - 3.75).abs() < 1e-12);
+        assert_eq!(even.median(), Ok(3.));
+        assert_eq!(even.quantile(1.), Ok(8.));
+        assert_eq!(even.quantile(1.5), Err(Value::Domain));
+        assert_eq!(Strided::new(&data, 2, 5).err(), Some(Value::BadLength));
+        assert_eq!(Strided::new(&data, 0, 1).err(), Some(Value::Invalid));
+        let odd = Strided::new(&data[1..], 2, 4).unwrap();
+        assert!((even.correlation(&odd).unwrap() - 1.).abs() < 1e-12);
+        assert_eq!(even.covariance(&data[..3]), Err(Value::BadLength));
+
+        let mut v = VectorF64::from_slice(&data).unwrap();
+        assert_eq!(Stats::max(&v), Ok(80.));
+        let view = VectorF64View::from_vector_with_stride(&mut v, 1, 2, 4);
+        assert_eq!(view.minmax(), Ok((10., 80.)));
+        assert_eq!(view.max_index(), Ok(3));
+    }
+}
@@ -0,0 +1,427 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+# Statistical Hypothesis Tests
+
+This module provides classical hypothesis tests on top of the [`statistics`]
+functions. The p-values are computed with the cumulative distribution functions of the
+[`randist`](crate::randist) module.
+
+Each test returns the value of its statistic together with its degrees of freedom and the
+p-value, the probability of observing a statistic at least as extreme under the null
+hypothesis. The datasets can be given as anything implementing [`Stats`], like
+slices or vectors:
+
+```text
+use rgsl::stats::tests::{ttest_welch, Alternative};
+
+let a = [5.1, 4.9, 5.6, 5.8, 6.0];
+let b = [6.2, 6.8, 5.9, 7.1, 6.6, 6.9];
+let res = ttest_welch(&a[..], &b[..], Alternative::TwoSided).unwrap();
+if res.p_value < 0.05 {
+    println!("the means differ (t = {}, dof = {})", res.statistic, res.dof);
+}
+```
+
+All the functions return `Value::BadLength` if a dataset is too small for the test.
+!*/
+
+use super::{Stats, Strided};
+use crate::randist::chi_squared::chisq_Q;
+use crate::randist::f_distribution::{fdist_P, fdist_Q};
+use crate::randist::t_distribution::{tdist_P, tdist_Q};
+use crate::{statistics, MatrixF64, Value};
+
+/// The alternative hypothesis of a test.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+pub enum Alternative {
+    /// The statistic differs from its expected value under the null hypothesis.
+    TwoSided,
+    /// The statistic is smaller than its expected value under the null hypothesis.
+    Less,
+    /// The statistic is greater than its expected value under the null hypothesis.
+    Greater,
+}
+
+impl Alternative {
+    /// Computes the p-value from the lower and upper tail probabilities of the statistic.
+    fn p_value(self, lower: f64, upper: f64) -> f64 {
+        match self {
+            Alternative::TwoSided => (2. * lower.min(upper)).min(1.),
+            Alternative::Less => lower,
+            Alternative::Greater => upper,
+        }
+    }
+}
+
+/// Result of a t-test or of a chi-square test.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TestResult {
+    /// Value of the test statistic.
+    pub statistic: f64,
+    /// Degrees of freedom of the distribution of the statistic.
+    pub dof: f64,
+    /// Probability of observing a statistic at least as extreme under the null hypothesis, for
+    /// the requested alternative.
+    pub p_value: f64,
+}
+
+/// Result of an F-test.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FTestResult {
+    /// Ratio of the sample variances.
+    pub statistic: f64,
+    /// Degrees of freedom of the numerator.
+    pub dof1: f64,
+    /// Degrees of freedom of the denominator.
+    pub dof2: f64,
+    /// Probability of observing a variance ratio at least as extreme under the null hypothesis,
+    /// for the requested alternative.
+    pub p_value: f64,
+}
+
+/// Result of a Kolmogorov-Smirnov test.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct KsTestResult {
+    /// Largest distance D between the empirical and the reference distribution functions.
+    pub statistic: f64,
+    /// Sample size. The distribution of D only depends on it.
+    pub n: usize,
+    /// Probability of observing a distance at least as large as D under the null hypothesis.
+    pub p_value: f64,
+}
+
+fn t_result(t: f64, dof: f64, alternative: Alternative) -> TestResult {
+    TestResult {
+        statistic: t,
+        dof,
+        p_value: alternative.p_value(tdist_P(t, dof), tdist_Q(t, dof)),
+    }
+}
+
+fn at_least(s: Strided<'_>, n: usize) -> Result<Strided<'_>, Value> {
+    if s.len() < n {
+        Err(Value::BadLength)
+    } else {
+        Ok(s)
+    }
+}
+
+/// One-sample Student's t-test of the null hypothesis that the mean of `x` is `mu`.
+///
+/// The statistic t = (\Hat\mu - mu) / (\Hat\sigma / \sqrt{n}) follows a t-distribution with
+/// n - 1 degrees of freedom.
+pub fn ttest_one_sample<S: Stats + ?Sized>(
+    x: &S,
+    mu: f64,
+    alternative: Alternative,
+) -> Result<TestResult, Value> {
+    let x = at_least(x.strided()?, 2)?;
+    let n = x.len() as f64;
+    let t = (x.mean()? - mu) / (x.sd()? / n.sqrt());
+    Ok(t_result(t, n - 1., alternative))
+}
+
+/// Two-sample Student's t-test of the null hypothesis that `x` and `y` have the same mean,
+/// assuming that they have the same variance.
+///
+/// The statistic is computed by [`statistics::ttest`] from the pooled variance, and follows a
+/// t-distribution with n_x + n_y - 2 degrees of freedom.
+pub fn ttest_student<S: Stats + ?Sized, T: Stats + ?Sized>(
+    x: &S,
+    y: &T,
+    alternative: Alternative,
+) -> Result<TestResult, Value> {
+    let x = at_least(x.strided()?, 2)?;
+    let y = at_least(y.strided()?, 2)?;
+    let t = statistics::ttest(x.data, x.stride, x.n, y.data, y.stride, y.n);
+    Ok(t_result(t, (x.n + y.n - 2) as f64, alternative))
+}
+
+/// Welch's t-test of the null hypothesis that `x` and `y` have the same mean, without assuming
+/// that they have the same variance.
+///
+/// The statistic t = (\Hat\mu_x - \Hat\mu_y) / \sqrt{s_x^2 / n_x + s_y^2 / n_y} approximately
+/// follows a t-distribution whose degrees of freedom are given by the Welch-Satterthwaite
+/// equation.
+pub fn ttest_welch<S: Stats + ?Sized, T: Stats + ?Sized>(
+    x: &S,
+    y: &T,
+    alternative: Alternative,
+) -> Result<TestResult, Value> {
+    let x = at_least(x.strided()?, 2)?;
+    let y = at_least(y.strided()?, 2)?;
+    let (nx, ny) = (x.len() as f64, y.len() as f64);
+    let vx = x.variance()? / nx;
+    let vy = y.variance()? / ny;
+    let t = (x.mean()? - y.mean()?) / (vx + vy).sqrt();
+    let dof = (vx + vy).powi(2) / (vx * vx / (nx - 1.) + vy * vy / (ny - 1.));
+    Ok(t_result(t, dof, alternative))
+}
+
+/// Paired t-test of the null hypothesis that the mean of the differences x_i - y_i is zero.
+/// `x` and `y` must have the same length.
+pub fn ttest_paired<S: Stats + ?Sized, T: Stats + ?Sized>(
+    x: &S,
+    y: &T,
+    alternative: Alternative,
+) -> Result<TestResult, Value> {
+    let (x, y) = (x.strided()?, y.strided()?);
+    if x.len() != y.len() {
+        return Err(Value::BadLength);
+    }
+    let diff: Vec<f64> = x.iter().zip(y.iter()).map(|(a, b)| a - b).collect();
+    ttest_one_sample(&diff[..], 0., alternative)
+}
+
+/// Pearson's chi-square goodness-of-fit test of the null hypothesis that the counts `observed`
+/// follow the frequencies `expected`. Both must have the same length k and the same total.
+///
+/// The statistic \chi^2 = \sum (O_i - E_i)^2 / E_i follows a chi-square distribution with
+/// k - 1 - `ddof` degrees of freedom, where `ddof` is the number of parameters of the expected
+/// distribution which were estimated from the data.
+///
+/// Returns `Value::Domain` if an expected frequency is not positive and `Value::Invalid` if the
+/// totals of `observed` and `expected` differ by more than a relative tolerance of 1e-8, in which
+/// case `expected` must be rescaled by the caller.
+pub fn chisq_goodness_of_fit<S: Stats + ?Sized, T: Stats + ?Sized>(
+    observed: &S,
+    expected: &T,
+    ddof: usize,
+) -> Result<TestResult, Value> {
+    let (observed, expected) = (observed.strided()?, expected.strided()?);
+    if observed.len() != expected.len() || observed.len() < ddof + 2 {
+        return Err(Value::BadLength);
+    }
+    let (total_o, total_e) = (observed.iter().sum::<f64>(), expected.iter().sum::<f64>());
+    if (total_o - total_e).abs() > 1e-8 * total_o.abs().max(total_e.abs()) {
+        return Err(Value::Invalid);
+    }
+    let mut chisq = 0.;
+    for (o, e) in observed.iter().zip(expected.iter()) {
+        if e <= 0. {
+            return Err(Value::Domain);
+        }
+        chisq += (o - e) * (o - e) / e;
+    }
+    let dof = (observed.len() - 1 - ddof) as f64;
+    Ok(TestResult {
+        statistic: chisq,
+        dof,
+        p_value: chisq_Q(chisq, dof),
+    })
+}
+
+/// Pearson's chi-square test of independence of the two categorical variables of the
+/// contingency table `table`, whose element (i, j) is the count of observations in row
+/// category i and column category j.
+///
+/// The expected counts are E_ij = R_i C_j / N, where R_i and C_j are the row and column totals
+/// and N the total count. The statistic follows a chi-square distribution with (r - 1)(c - 1)
+/// degrees of freedom.
+///
+/// Returns `Value::Domain` if a row or a column total is not positive.
+pub fn chisq_independence(table: &MatrixF64) -> Result<TestResult, Value> {
+    let (r, c) = (table.size1(), table.size2());
+    if r < 2 || c < 2 {
+        return Err(Value::BadLength);
+    }
+    let rows: Vec<f64> = (0..r)
+        .map(|i| (0..c).map(|j| table.get(i, j)).sum())
+        .collect();
+    let cols: Vec<f64> = (0..c)
+        .map(|j| (0..r).map(|i| table.get(i, j)).sum())
+        .collect();
+    if rows.iter().chain(cols.iter()).any(|&t| t <= 0.) {
+        return Err(Value::Domain);
+    }
+    let total: f64 = rows.iter().sum();
+
+    let mut chisq = 0.;
+    for (i, row) in rows.iter().enumerate() {
+        for (j, col) in cols.iter().enumerate() {
+            let e = row * col / total;
+            let d = table.get(i, j) - e;
+            chisq += d * d / e;
+        }
+    }
+    let dof = ((r - 1) * (c - 1)) as f64;
+    Ok(TestResult {
+        statistic: chisq,
+        dof,
+        p_value: chisq_Q(chisq, dof),
+    })
+}
+
+/// F-test of the null hypothesis that `x` and `y` have the same variance.
+///
+/// The statistic F = s_x^2 / s_y^2 follows an F-distribution with n_x - 1 and n_y - 1 degrees
+/// of freedom. The test assumes that both samples are drawn from Gaussian distributions.
+pub fn ftest<S: Stats + ?Sized, T: Stats + ?Sized>(
+    x: &S,
+    y: &T,
+    alternative: Alternative,
+) -> Result<FTestResult, Value> {
+    let x = at_least(x.strided()?, 2)?;
+    let y = at_least(y.strided()?, 2)?;
+    let f = x.variance()? / y.variance()?;
+    let (dof1, dof2) = ((x.len() - 1) as f64, (y.len() - 1) as f64);
+    Ok(FTestResult {
+        statistic: f,
+        dof1,
+        dof2,
+        p_value: alternative.p_value(fdist_P(f, dof1, dof2), fdist_Q(f, dof1, dof2)),
+    })
+}
+
+/// One-sample Kolmogorov-Smirnov test of the null hypothesis that `x` is drawn from the
+/// continuous distribution whose cumulative distribution function is `cdf`, for example
+/// `|x| rgsl::randist::gaussian::ugaussian_P(x)`.
+///
+/// The statistic is D = \sup_x |F_n(x) - F(x)|, where F_n is the empirical distribution function
+/// of `x`. The two-sided p-value is computed with the asymptotic Kolmogorov distribution, using
+/// the effective sample size correction of Stephens, which is accurate for n >= 5 or so.
+pub fn ks_test<S: Stats + ?Sized, F: Fn(f64) -> f64>(x: &S, cdf: F) -> Result<KsTestResult, Value> {
+    let x = at_least(x.strided()?, 1)?;
+    let sorted = x.sorted();
+    let n = sorted.len() as f64;
+
+    let mut d: f64 = 0.;
+    for (i, &v) in sorted.iter().enumerate() {
+        let f = cdf(v);
+        d = d.max(f - i as f64 / n).max((i + 1) as f64 / n - f);
+    }
+
+    let sqrt_n = n.sqrt();
+    let lambda = (sqrt_n + 0.12 + 0.11 / sqrt_n) * d;
+    Ok(KsTestResult {
+        statistic: d,
+        n: sorted.len(),
+        p_value: kolmogorov_Q(lambda),
+    })
+}
+
+/// Complementary cumulative distribution function of the Kolmogorov distribution,
+/// Q(\lambda) = 2 \sum_{k=1}^\infty (-1)^{k-1} \exp(-2 k^2 \lambda^2).
+fn kolmogorov_Q(lambda: f64) -> f64 {
+    if lambda < 0.2 {
+        // The series converges too slowly here, but Q is 1 to double precision.
+        return 1.;
+    }
+    let mut sum = 0.;
+    let mut sign = 1.;
+    for k in 1..=100 {
+        let k = k as f64;
+        let term = (-2. * k * k * lambda * lambda).exp();
+        sum += sign * term;
+        if term < 1e-16 * sum {
+            break;
+        }
+        sign = -sign;
+    }
+    (2. * sum).clamp(0., 1.)
+}
+
+#[cfg(any(test, doctest))]
+mod test_hypothesis {
+    use super::*;
+
+    // Reference values computed with mpmath.
+    const P_ONE_SAMPLE: f64 = 0.230199641080499;
+    const T_STUDENT: f64 = -2.215646837627989;
+    const P_STUDENT: f64 = 0.053945920509407;
+    const T_WELCH: f64 = -2.376354103144018;
+    const DOF_WELCH: f64 = 6.972255729793634;
+    const P_WELCH: f64 = 0.049284338206731;
+    const P_CHISQ_GOF: f64 = 0.002500407145823;
+    const P_CHISQ_INDEP: f64 = 0.372998483613487;
+    const P_FTEST: f64 = 0.120036400883601;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-6, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn test_ttests() {
+        let x = [1., 2., 3., 4., 5.];
+        let res = ttest_one_sample(&x[..], 2., Alternative::TwoSided).unwrap();
+        assert_close(res.statistic, 2f64.sqrt());
+        assert_eq!(res.dof, 4.);
+        assert_close(res.p_value, P_ONE_SAMPLE);
+        let less = ttest_one_sample(&x[..], 2., Alternative::Less).unwrap();
+        let greater = ttest_one_sample(&x[..], 2., Alternative::Greater).unwrap();
+        assert_close(less.p_value + greater.p_value, 1.);
+        assert_close(greater.p_value, P_ONE_SAMPLE / 2.);
+
+        let y = [2., 4., 6., 8., 10., 12.];
+        let student = ttest_student(&x[..], &y[..], Alternative::TwoSided).unwrap();
+        assert_eq!(student.dof, 9.);
+        assert_close(student.statistic, T_STUDENT);
+        assert_close(student.p_value, P_STUDENT);
+        let welch = ttest_welch(&x[..], &y[..], Alternative::TwoSided).unwrap();
+        assert_close(welch.statistic, T_WELCH);
+        assert_close(welch.dof, DOF_WELCH);
+        assert_close(welch.p_value, P_WELCH);
+
+        let paired = ttest_paired(&x[..], &y[..5], Alternative::TwoSided).unwrap();
+        let diff = [-1., -2., -3., -4., -5.];
+        assert_eq!(
+            paired,
+            ttest_one_sample(&diff[..], 0., Alternative::TwoSided).unwrap()
+        );
+        assert_eq!(
+            ttest_paired(&x[..], &y[..], Alternative::TwoSided),
+            Err(Value::BadLength)
+        );
+        assert_eq!(
+            ttest_one_sample(&x[..1], 0., Alternative::TwoSided),
+            Err(Value::BadLength)
+        );
+    }
+
+    #[test]
+    fn test_chisq() {
+        let observed = [18., 22., 20., 40.];
+        let expected = [25., 25., 25., 25.];
+        let res = chisq_goodness_of_fit(&observed[..], &expected[..], 0).unwrap();
+        assert_close(res.statistic, 14.32);
+        assert_eq!(res.dof, 3.);
+        assert_close(res.p_value, P_CHISQ_GOF);
+        let halved: Vec<f64> = expected.iter().map(|e| e / 2.).collect();
+        assert_eq!(
+            chisq_goodness_of_fit(&observed[..], &halved[..], 0).unwrap_err(),
+            Value::Invalid
+        );
+
+        let mut table = MatrixF64::new(2, 2).unwrap();
+        table.set(0, 0, 10.);
+        table.set(0, 1, 20.);
+        table.set(1, 0, 30.);
+        table.set(1, 1, 40.);
+        let res = chisq_independence(&table).unwrap();
+        assert_close(res.statistic, 100. / 126.);
+        assert_eq!(res.dof, 1.);
+        assert_close(res.p_value, P_CHISQ_INDEP);
+    }
+
+    #[test]
+    fn test_ftest_and_ks() {
+        let x = [1., 2., 3., 4., 5.];
+        let y = [2., 4., 6., 8., 10., 12.];
+        let res = ftest(&x[..], &y[..], Alternative::TwoSided).unwrap();
+        assert_close(res.statistic, 2.5 / 14.);
+        assert_eq!((res.dof1, res.dof2), (4., 5.));
+        assert_close(res.p_value, P_FTEST);
+
+        let uniform = [0.1, 0.3, 0.5, 0.7, 0.9];
+        let res = ks_test(&uniform[..], |x| x.clamp(0., 1.)).unwrap();
+        assert_close(res.statistic, 0.1);
+        assert_eq!(res.n, 5);
+        assert!(res.p_value > 0.99);
+        let res = ks_test(&uniform[..], |x| (x * 4.).clamp(0., 1.)).unwrap();
+        assert_close(res.statistic, 0.8);
+        assert!(res.p_value < 0.01);
+    }
+}
@@ -4,9 +4,12 @@
 
 // TODO : port to Rust type : http://doc.rust-lang.org/num/complex/struct.Complex.html
 
+use crate::trigonometric::Trigonometric;
+use crate::{types, Value};
 use std::default::Default;
 use std::fmt;
 use std::fmt::{Debug, Formatter};
+use std::mem::MaybeUninit;
 
 #[doc(hidden)]
 #[allow(clippy::upper_case_acronyms)]
@@ -445,6 +448,99 @@ impl ComplexF64 {
     pub fn imaginary(&self) -> f64 {
         self.dat[1]
     }
+
+    /// This function computes \log(\Gamma(z)) for z not a negative integer or zero, using the
+    /// complex Lanczos method. It returns lnr = \log|\Gamma(z)| and arg = \arg(\Gamma(z)) in
+    /// (-\pi,\pi], with their error estimates.
+    ///
+    /// The phase is not well-determined when |z| is very large, in which case `Value::Loss` is
+    /// returned. See [`lngamma_complex_e`](crate::gamma_beta::gamma::lngamma_complex_e).
+    pub fn lngamma(&self) -> (Value, types::Result, types::Result) {
+        ::gamma_beta::gamma::lngamma_complex_e(self.dat[0], self.dat[1])
+    }
+
+    /// This function computes the complex logarithm of z. It returns lnr and theta such that
+    /// \exp(lnr + i \theta) = z, where theta lies in the range [-\pi,\pi], with their error
+    /// estimates. See [`log`](Self::log) for the version without error estimates.
+    pub fn log_e(&self) -> (Value, types::Result, types::Result) {
+        ::logarithm::complex_log_e(self.dat[0], self.dat[1])
+    }
+
+    /// This function computes the complex sine of z, returning its real and imaginary parts with
+    /// their error estimates. See [`sin`](Self::sin) for the version without error estimates.
+    pub fn sin_e(&self) -> (Value, types::Result, types::Result) {
+        self.dat[0].complex_sin_e(self.dat[1])
+    }
+
+    /// This function computes the complex cosine of z, returning its real and imaginary parts
+    /// with their error estimates. See [`cos`](Self::cos) for the version without error
+    /// estimates.
+    pub fn cos_e(&self) -> (Value, types::Result, types::Result) {
+        self.dat[0].complex_cos_e(self.dat[1])
+    }
+
+    /// This function computes the logarithm of the complex sine of z, \log(\sin(z)), returning
+    /// its real and imaginary parts with their error estimates.
+    pub fn logsin(&self) -> (Value, types::Result, types::Result) {
+        self.dat[0].complex_logsin_e(self.dat[1])
+    }
+
+    /// This function computes the full complex-valued dilogarithm of z, returning its real and
+    /// imaginary parts with their error estimates.
+    #[doc(alias = "gsl_sf_complex_dilog_xy_e")]
+    pub fn dilog(&self) -> (Value, types::Result, types::Result) {
+        let mut result_re = MaybeUninit::<sys::gsl_sf_result>::uninit();
+        let mut result_im = MaybeUninit::<sys::gsl_sf_result>::uninit();
+        let ret = unsafe {
+            ::sys::gsl_sf_complex_dilog_xy_e(
+                self.dat[0],
+                self.dat[1],
+                result_re.as_mut_ptr(),
+                result_im.as_mut_ptr(),
+            )
+        };
+
+        (
+            Value::from(ret),
+            unsafe { result_re.assume_init() }.into(),
+            unsafe { result_im.assume_init() }.into(),
+        )
+    }
+
+    /// This function computes the digamma function \psi(z) = \Gamma'(z)/\Gamma(z) for z not a
+    /// negative integer or zero, returning its real and imaginary parts with their error
+    /// estimates.
+    #[doc(alias = "gsl_sf_complex_psi_e")]
+    pub fn psi(&self) -> (Value, types::Result, types::Result) {
+        let mut result_re = MaybeUninit::<sys::gsl_sf_result>::uninit();
+        let mut result_im = MaybeUninit::<sys::gsl_sf_result>::uninit();
+        let ret = unsafe {
+            ::sys::gsl_sf_complex_psi_e(
+                self.dat[0],
+                self.dat[1],
+                result_re.as_mut_ptr(),
+                result_im.as_mut_ptr(),
+            )
+        };
+
+        (
+            Value::from(ret),
+            unsafe { result_re.assume_init() }.into(),
+            unsafe { result_im.assume_init() }.into(),
+        )
+    }
+
+    /// This function converts the polar coordinates (r, theta) to rectilinear coordinates
+    /// (x, y), with their error estimates: x = r \cos(\theta), y = r \sin(\theta).
+    pub fn polar_to_rect(r: f64, theta: f64) -> (Value, types::Result, types::Result) {
+        r.polar_to_rect(theta)
+    }
+
+    /// This function converts z to polar coordinates (r, theta), with their error estimates,
+    /// such that z = r \exp(i \theta), where theta lies in the range [-\pi,\pi].
+    pub fn rect_to_polar(&self) -> (Value, types::Result, types::Result) {
+        self.dat[0].rect_to_polar(self.dat[1])
+    }
 }
 
 impl Debug for ComplexF64 {
@@ -1522,6 +1618,28 @@ fn complex_f64() {
     );
 }
 
+#[test]
+fn complex_f64_special_functions() {
+    let v = ComplexF64::rect(0.5, 1.5);
+    let fmt = |(ret, re, im): (Value, types::Result, types::Result)| {
+        assert_eq!(ret, Value::Success);
+        assert!(re.err < 1e-10 && im.err < 1e-10);
+        format!("{:.4} {:.4}", re.val, im.val)
+    };
+    assert_eq!(fmt(v.lngamma()), "-1.4373 -0.8632".to_owned());
+    assert_eq!(fmt(v.log_e()), "0.4581 1.2490".to_owned());
+    assert_eq!(fmt(v.sin_e()), "1.1278 1.8686".to_owned());
+    assert_eq!(fmt(v.cos_e()), "2.0644 -1.0208".to_owned());
+    assert_eq!(fmt(v.logsin()), "0.7805 1.0278".to_owned());
+    assert_eq!(fmt(v.dilog()), "-0.0625 1.5031".to_owned());
+    assert_eq!(fmt(v.psi()), "0.3850 1.5705".to_owned());
+    assert_eq!(fmt(v.rect_to_polar()), "1.5811 1.2490".to_owned());
+    assert_eq!(
+        fmt(ComplexF64::polar_to_rect(2., 0.5)),
+        "1.7552 0.9589".to_owned()
+    );
+}
+
 #[test]
 fn complex_f32() {
     let v = ComplexF32::rect(10., 10.);
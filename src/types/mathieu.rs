@@ -227,4 +227,130 @@ impl MathieuWorkspace {
             )
         })
     }
+
+    /// Returns the maximum order of the Mathieu functions which can be computed with this
+    /// workspace, as given to [`new`](Self::new).
+    pub fn size(&self) -> usize {
+        unsafe { (*self.unwrap_shared()).size }
+    }
+
+    /// Checks that `[order_min, order_max]` is a valid range of orders for this workspace and
+    /// returns the number of orders in it.
+    fn orders(&self, order_min: i32, order_max: i32) -> Result<usize, Value> {
+        if order_min < 0 || order_max < order_min || order_max as usize > self.size() {
+            Err(Value::Invalid)
+        } else {
+            Ok((order_max - order_min) as usize + 1)
+        }
+    }
+
+    /// Computes the characteristic values a_n(q) for n from `order_min` to `order_max` inclusive.
+    ///
+    /// Returns `Value::Invalid` if the range is empty or exceeds the size of the workspace.
+    pub fn a_array(&mut self, order_min: i32, order_max: i32, q: f64) -> Result<Vec<f64>, Value> {
+        let mut result = vec![0.; self.orders(order_min, order_max)?];
+        match self.mathieu_a_array(order_min, order_max, q, &mut result) {
+            Value::Success => Ok(result),
+            e => Err(e),
+        }
+    }
+
+    /// Computes the characteristic values b_n(q) for n from `order_min` to `order_max` inclusive.
+    ///
+    /// Returns `Value::Invalid` if the range is empty or exceeds the size of the workspace.
+    pub fn b_array(&mut self, order_min: i32, order_max: i32, q: f64) -> Result<Vec<f64>, Value> {
+        let mut result = vec![0.; self.orders(order_min, order_max)?];
+        match self.mathieu_b_array(order_min, order_max, q, &mut result) {
+            Value::Success => Ok(result),
+            e => Err(e),
+        }
+    }
+
+    /// Computes the angular Mathieu functions ce_n(q,x) for n from `nmin` to `nmax` inclusive.
+    ///
+    /// Returns `Value::Invalid` if the range is empty or exceeds the size of the workspace.
+    pub fn ce_array(&mut self, nmin: i32, nmax: i32, q: f64, x: f64) -> Result<Vec<f64>, Value> {
+        let mut result = vec![0.; self.orders(nmin, nmax)?];
+        match self.mathieu_ce_array(nmin, nmax, q, x, &mut result) {
+            Value::Success => Ok(result),
+            e => Err(e),
+        }
+    }
+
+    /// Computes the angular Mathieu functions se_n(q,x) for n from `nmin` to `nmax` inclusive.
+    ///
+    /// Returns `Value::Invalid` if the range is empty or exceeds the size of the workspace.
+    pub fn se_array(&mut self, nmin: i32, nmax: i32, q: f64, x: f64) -> Result<Vec<f64>, Value> {
+        let mut result = vec![0.; self.orders(nmin, nmax)?];
+        match self.mathieu_se_array(nmin, nmax, q, x, &mut result) {
+            Value::Success => Ok(result),
+            e => Err(e),
+        }
+    }
+
+    /// Computes the radial Mathieu functions Mc^{(j)}_n(q,x) of kind `j` for n from `nmin` to
+    /// `nmax` inclusive.
+    ///
+    /// Returns `Value::Invalid` if the range is empty or exceeds the size of the workspace.
+    pub fn Mc_array(
+        &mut self,
+        j: i32,
+        nmin: i32,
+        nmax: i32,
+        q: f64,
+        x: f64,
+    ) -> Result<Vec<f64>, Value> {
+        let mut result = vec![0.; self.orders(nmin, nmax)?];
+        match self.mathieu_Mc_array(j, nmin, nmax, q, x, &mut result) {
+            Value::Success => Ok(result),
+            e => Err(e),
+        }
+    }
+
+    /// Computes the radial Mathieu functions Ms^{(j)}_n(q,x) of kind `j` for n from `nmin` to
+    /// `nmax` inclusive.
+    ///
+    /// Returns `Value::Invalid` if the range is empty or exceeds the size of the workspace.
+    pub fn Ms_array(
+        &mut self,
+        j: i32,
+        nmin: i32,
+        nmax: i32,
+        q: f64,
+        x: f64,
+    ) -> Result<Vec<f64>, Value> {
+        let mut result = vec![0.; self.orders(nmin, nmax)?];
+        match self.mathieu_Ms_array(j, nmin, nmax, q, x, &mut result) {
+            Value::Success => Ok(result),
+            e => Err(e),
+        }
+    }
+}
+
+#[cfg(any(test, doctest))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arrays() {
+        let mut w = MathieuWorkspace::new(10, 20.).unwrap();
+        assert_eq!(w.size(), 10);
+
+        let a = w.a_array(0, 5, 1.).unwrap();
+        assert_eq!(a.len(), 6);
+        for (n, &a_n) in a.iter().enumerate() {
+            let (ret, single) = MathieuWorkspace::mathieu_a(n as i32, 1.);
+            assert_eq!(ret, Value::Success);
+            assert!((a_n - single.val).abs() < 1e-8);
+        }
+
+        let ce = w.ce_array(0, 3, 1., 0.5).unwrap();
+        assert_eq!(ce.len(), 4);
+        let (_, ce_2) = MathieuWorkspace::mathieu_ce(2, 1., 0.5);
+        assert!((ce[2] - ce_2.val).abs() < 1e-8);
+
+        assert_eq!(w.b_array(0, 11, 1.), Err(Value::Invalid));
+        assert_eq!(w.se_array(3, 2, 1., 0.5), Err(Value::Invalid));
+        assert_eq!(w.Mc_array(1, -1, 2, 1., 0.5), Err(Value::Invalid));
+    }
 }
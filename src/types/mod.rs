@@ -38,13 +38,34 @@ pub use self::minimizer::{Minimizer, MinimizerType};
 pub use self::monte_carlo::{
     MiserMonteCarlo, MiserParams, PlainMonteCarlo, VegasMonteCarlo, VegasParams,
 };
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+pub use self::movstat::{MovstatFunction, MovstatWorkspace};
 pub use self::multifit_linear::MultifitLinearWorkspace;
+pub use self::multifit_nlinear::{
+    MultiFitNlinearFdf, MultiFitNlinearParameters, MultiFitNlinearScale, MultiFitNlinearSolver,
+    MultiFitNlinearTrs, MultiFitNlinearType, MultiFitNlinearWorkspace,
+};
+pub use self::multifit_robust::{MultifitRobustStats, MultifitRobustWorkspace};
 pub use self::multifit_solver::{
-    MultiFitFdfSolver, MultiFitFdfSolverType, MultiFitFunction, MultiFitFunctionFdf,
+    FdfRidgeSolver, MultiFitFdfSolver, MultiFitFdfSolverType, MultiFitFunction, MultiFitFunctionFdf,
 };
 #[cfg(feature = "v2_1")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
 pub use self::multilarge_linear::{MultilargeLinearType, MultilargeLinearWorkspace};
+#[cfg(feature = "v2_1")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
+pub use self::multilarge_nlinear::{
+    MultilargeNlinearFdf, MultilargeNlinearParameters, MultilargeNlinearScale,
+    MultilargeNlinearSolver, MultilargeNlinearTrs, MultilargeNlinearType,
+    MultilargeNlinearWorkspace,
+};
+pub use self::multimin::{
+    MultiMinFMinimizer, MultiMinFMinimizerType, MultiMinFdfMinimizer, MultiMinFdfMinimizerType,
+};
+pub use self::multiroot::{
+    MultiRootFSolver, MultiRootFSolverType, MultiRootFdfSolver, MultiRootFdfSolverType,
+};
 pub use self::multiset::MultiSet;
 pub use self::n_tuples::{ReadNTuples, WriteNTuples};
 pub use self::ordinary_differential_equations::{
@@ -57,9 +78,17 @@ pub use self::ran_discrete::RanDiscrete;
 pub use self::result::{Result, ResultE10};
 pub use self::rng::{Rng, RngType};
 pub use self::roots::{RootFSolver, RootFSolverType, RootFdfSolver, RootFdfSolverType};
-pub use self::rstat::{RStatQuantileWorkspace, RStatWorkspace};
+pub use self::rstat::{
+    RStatQuantileState, RStatQuantileWorkspace, RStatQuantiles, RStatState, RStatWorkspace,
+};
 pub use self::series_acceleration::{LevinUTruncWorkspace, LevinUWorkspace};
 pub use self::siman::{SimAnnealing, SimAnnealingParams};
+#[cfg(feature = "v2_6")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
+pub use self::splinalg::{IterSolver, IterSolverType};
+#[cfg(feature = "v2_6")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
+pub use self::spmatrix::{SpMatrixF32, SpMatrixF64, SpMatrixI32, SpMatrixIter, SpMatrixU32};
 pub use self::vector::{
     VectorF32, VectorF32View, VectorF64, VectorF64View, VectorI32, VectorI32View, VectorU32,
     VectorU32View,
@@ -87,11 +116,20 @@ pub mod matrix;
 pub mod matrix_complex;
 pub mod minimizer;
 pub mod monte_carlo;
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+pub mod movstat;
 pub mod multifit_linear;
+pub mod multifit_nlinear;
+pub mod multifit_robust;
 pub mod multifit_solver;
 #[cfg(feature = "v2_1")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
 pub mod multilarge_linear;
+#[cfg(feature = "v2_1")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
+pub mod multilarge_nlinear;
+pub mod multimin;
 pub mod multiroot;
 pub mod multiset;
 pub mod n_tuples;
@@ -106,6 +144,12 @@ pub mod roots;
 pub mod rstat;
 pub mod series_acceleration;
 pub mod siman;
+#[cfg(feature = "v2_6")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
+pub mod splinalg;
+#[cfg(feature = "v2_6")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
+pub mod spmatrix;
 pub mod vector;
 pub mod vector_complex;
 pub mod wavelet_transforms;
@@ -0,0 +1,386 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+# Moving Window Statistics
+
+This chapter describes routines for computing moving window statistics (also called rolling
+statistics and running statistics), using a window around a sample which is used to calculate
+various local statistical properties of an input data stream. The window is then slid forward by
+one sample to process the next data point and so on.
+
+## Moving Windows
+
+There are a number of ways to define a window around a sample x_i. The simplest is a symmetric
+window of K = 2H + 1 samples centered on x_i:
+
+```text
+W_i^H = {x_{i-H}, ..., x_i, ..., x_{i+H}}
+```
+
+If K is even, it is rounded up to the next odd number. A more general asymmetric window contains
+H samples before x_i and J samples after:
+
+```text
+W_i^{H,J} = {x_{i-H}, ..., x_i, ..., x_{i+J}}
+```
+
+When processing samples near the ends of the input signal, there will not be enough samples to
+fill the window. The way the window is filled there is selected with
+[`MovstatEnd`].
+
+## User-defined Moving Statistics
+
+Any statistic can be computed over the moving windows by implementing [`MovstatFunction`],
+which is implemented by every `FnMut(&mut [f64]) -> f64` closure, and passing it to
+[`MovstatWorkspace::apply`]:
+
+```text
+let mut w = MovstatWorkspace::new(5).unwrap();
+w.apply(MovstatEnd::Truncate, |window: &mut [f64]| window.iter().sum::<f64>().abs(), &x, &mut y);
+```
+!*/
+
+use crate::{MovstatEnd, Value, VectorF64};
+use ffi::FFI;
+use std::os::raw::c_void;
+
+/// A statistic computed over each window by [`MovstatWorkspace::apply`].
+pub trait MovstatFunction {
+    /// Computes the statistic of the samples in `window`. The samples may be reordered in place,
+    /// for example to sort them.
+    fn compute(&mut self, window: &mut [f64]) -> f64;
+}
+
+impl<F: FnMut(&mut [f64]) -> f64> MovstatFunction for F {
+    fn compute(&mut self, window: &mut [f64]) -> f64 {
+        self(window)
+    }
+}
+
+ffi_wrapper!(
+    MovstatWorkspace,
+    *mut sys::gsl_movstat_workspace,
+    gsl_movstat_free
+);
+
+impl MovstatWorkspace {
+    /// This function allocates a workspace for computing symmetric moving window statistics. The
+    /// size of the window is specified by `K`. If `K` is even, it is rounded up to the next odd
+    /// number to maintain a symmetric window.
+    #[doc(alias = "gsl_movstat_alloc")]
+    pub fn new(K: usize) -> Option<Self> {
+        let s = unsafe { sys::gsl_movstat_alloc(K) };
+        if s.is_null() {
+            None
+        } else {
+            Some(Self::wrap(s))
+        }
+    }
+
+    /// This function allocates a workspace for computing moving window statistics using an
+    /// asymmetric window with `H` samples before the current sample and `J` samples after it.
+    #[doc(alias = "gsl_movstat_alloc2")]
+    pub fn new_asymmetric(H: usize, J: usize) -> Option<Self> {
+        let s = unsafe { sys::gsl_movstat_alloc2(H, J) };
+        if s.is_null() {
+            None
+        } else {
+            Some(Self::wrap(s))
+        }
+    }
+
+    /// This function computes the moving window mean of the input vector `x`, storing the output
+    /// in `y`. The parameter `endtype` specifies how windows near the ends of the input should be
+    /// handled. It is allowed to have `x` = `y` for an in-place moving mean.
+    #[doc(alias = "gsl_movstat_mean")]
+    pub fn mean(&mut self, endtype: MovstatEnd, x: &VectorF64, y: &mut VectorF64) -> Value {
+        Value::from(unsafe {
+            sys::gsl_movstat_mean(
+                endtype.into(),
+                x.unwrap_shared(),
+                y.unwrap_unique(),
+                self.unwrap_unique(),
+            )
+        })
+    }
+
+    /// This function computes the moving window variance of the input vector `x`, storing the
+    /// output in `y`. If the window contains a single sample, the variance is set to 0.
+    #[doc(alias = "gsl_movstat_variance")]
+    pub fn variance(&mut self, endtype: MovstatEnd, x: &VectorF64, y: &mut VectorF64) -> Value {
+        Value::from(unsafe {
+            sys::gsl_movstat_variance(
+                endtype.into(),
+                x.unwrap_shared(),
+                y.unwrap_unique(),
+                self.unwrap_unique(),
+            )
+        })
+    }
+
+    /// This function computes the moving window standard deviation of the input vector `x`,
+    /// storing the output in `y`.
+    #[doc(alias = "gsl_movstat_sd")]
+    pub fn sd(&mut self, endtype: MovstatEnd, x: &VectorF64, y: &mut VectorF64) -> Value {
+        Value::from(unsafe {
+            sys::gsl_movstat_sd(
+                endtype.into(),
+                x.unwrap_shared(),
+                y.unwrap_unique(),
+                self.unwrap_unique(),
+            )
+        })
+    }
+
+    /// This function computes the moving minimum of the input vector `x`, storing the result in
+    /// `y`.
+    #[doc(alias = "gsl_movstat_min")]
+    pub fn min(&mut self, endtype: MovstatEnd, x: &VectorF64, y: &mut VectorF64) -> Value {
+        Value::from(unsafe {
+            sys::gsl_movstat_min(
+                endtype.into(),
+                x.unwrap_shared(),
+                y.unwrap_unique(),
+                self.unwrap_unique(),
+            )
+        })
+    }
+
+    /// This function computes the moving maximum of the input vector `x`, storing the result in
+    /// `y`.
+    #[doc(alias = "gsl_movstat_max")]
+    pub fn max(&mut self, endtype: MovstatEnd, x: &VectorF64, y: &mut VectorF64) -> Value {
+        Value::from(unsafe {
+            sys::gsl_movstat_max(
+                endtype.into(),
+                x.unwrap_shared(),
+                y.unwrap_unique(),
+                self.unwrap_unique(),
+            )
+        })
+    }
+
+    /// This function computes the moving minimum and maximum of the input vector `x`, storing the
+    /// window minimums in `y_min` and the window maximums in `y_max`.
+    #[doc(alias = "gsl_movstat_minmax")]
+    pub fn minmax(
+        &mut self,
+        endtype: MovstatEnd,
+        x: &VectorF64,
+        y_min: &mut VectorF64,
+        y_max: &mut VectorF64,
+    ) -> Value {
+        Value::from(unsafe {
+            sys::gsl_movstat_minmax(
+                endtype.into(),
+                x.unwrap_shared(),
+                y_min.unwrap_unique(),
+                y_max.unwrap_unique(),
+                self.unwrap_unique(),
+            )
+        })
+    }
+
+    /// This function computes the moving median of the input vector `x`, storing the output in
+    /// `y`.
+    #[doc(alias = "gsl_movstat_median")]
+    pub fn median(&mut self, endtype: MovstatEnd, x: &VectorF64, y: &mut VectorF64) -> Value {
+        Value::from(unsafe {
+            sys::gsl_movstat_median(
+                endtype.into(),
+                x.unwrap_shared(),
+                y.unwrap_unique(),
+                self.unwrap_unique(),
+            )
+        })
+    }
+
+    /// This function computes the moving MAD of the input vector `x` and stores the result in
+    /// `xmad`. The medians of each window are stored in `xmedian` on output. This variant does
+    /// not include the scale factor of 1.4826.
+    #[doc(alias = "gsl_movstat_mad0")]
+    pub fn mad0(
+        &mut self,
+        endtype: MovstatEnd,
+        x: &VectorF64,
+        xmedian: &mut VectorF64,
+        xmad: &mut VectorF64,
+    ) -> Value {
+        Value::from(unsafe {
+            sys::gsl_movstat_mad0(
+                endtype.into(),
+                x.unwrap_shared(),
+                xmedian.unwrap_unique(),
+                xmad.unwrap_unique(),
+                self.unwrap_unique(),
+            )
+        })
+    }
+
+    /// This function computes the moving MAD of the input vector `x` and stores the result in
+    /// `xmad`. The medians of each window are stored in `xmedian` on output. The MAD is scaled
+    /// by 1.4826 so that it is an unbiased estimate of the standard deviation for Gaussian data.
+    #[doc(alias = "gsl_movstat_mad")]
+    pub fn mad(
+        &mut self,
+        endtype: MovstatEnd,
+        x: &VectorF64,
+        xmedian: &mut VectorF64,
+        xmad: &mut VectorF64,
+    ) -> Value {
+        Value::from(unsafe {
+            sys::gsl_movstat_mad(
+                endtype.into(),
+                x.unwrap_shared(),
+                xmedian.unwrap_unique(),
+                xmad.unwrap_unique(),
+                self.unwrap_unique(),
+            )
+        })
+    }
+
+    /// This function computes the moving q-quantile range of the input vector `x` and stores the
+    /// result in `xqqr`. The quantile parameter `q` must be between 0 and 0.5. The input
+    /// `q = 0.25` corresponds to the interquartile range.
+    #[doc(alias = "gsl_movstat_qqr")]
+    pub fn qqr(
+        &mut self,
+        endtype: MovstatEnd,
+        x: &VectorF64,
+        q: f64,
+        xqqr: &mut VectorF64,
+    ) -> Value {
+        Value::from(unsafe {
+            sys::gsl_movstat_qqr(
+                endtype.into(),
+                x.unwrap_shared(),
+                q,
+                xqqr.unwrap_unique(),
+                self.unwrap_unique(),
+            )
+        })
+    }
+
+    /// This function computes the moving S_n of the input vector `x` and stores the output in
+    /// `xscale`. The S_n statistic is a robust estimate of the standard deviation, scaled to be
+    /// consistent for Gaussian data.
+    #[doc(alias = "gsl_movstat_Sn")]
+    pub fn Sn(&mut self, endtype: MovstatEnd, x: &VectorF64, xscale: &mut VectorF64) -> Value {
+        Value::from(unsafe {
+            sys::gsl_movstat_Sn(
+                endtype.into(),
+                x.unwrap_shared(),
+                xscale.unwrap_unique(),
+                self.unwrap_unique(),
+            )
+        })
+    }
+
+    /// This function computes the moving Q_n of the input vector `x` and stores the output in
+    /// `xscale`. The Q_n statistic is a robust estimate of the standard deviation, scaled to be
+    /// consistent for Gaussian data.
+    #[doc(alias = "gsl_movstat_Qn")]
+    pub fn Qn(&mut self, endtype: MovstatEnd, x: &VectorF64, xscale: &mut VectorF64) -> Value {
+        Value::from(unsafe {
+            sys::gsl_movstat_Qn(
+                endtype.into(),
+                x.unwrap_shared(),
+                xscale.unwrap_unique(),
+                self.unwrap_unique(),
+            )
+        })
+    }
+
+    /// This function computes the moving window sum of the input vector `x`, storing the output
+    /// in `y`.
+    #[doc(alias = "gsl_movstat_sum")]
+    pub fn sum(&mut self, endtype: MovstatEnd, x: &VectorF64, y: &mut VectorF64) -> Value {
+        Value::from(unsafe {
+            sys::gsl_movstat_sum(
+                endtype.into(),
+                x.unwrap_shared(),
+                y.unwrap_unique(),
+                self.unwrap_unique(),
+            )
+        })
+    }
+
+    /// This function applies the user-defined moving window statistic `f` to the input vector
+    /// `x`, storing the output in `y`. `f` is called once for each window with the samples of
+    /// that window.
+    #[doc(alias = "gsl_movstat_apply")]
+    pub fn apply<F: MovstatFunction>(
+        &mut self,
+        endtype: MovstatEnd,
+        mut f: F,
+        x: &VectorF64,
+        y: &mut VectorF64,
+    ) -> Value {
+        unsafe extern "C" fn inner_f<F: MovstatFunction>(
+            n: usize,
+            x: *mut f64,
+            params: *mut c_void,
+        ) -> f64 {
+            let f: &mut F = &mut *(params as *mut F);
+            if n == 0 {
+                f.compute(&mut [])
+            } else {
+                f.compute(::std::slice::from_raw_parts_mut(x, n))
+            }
+        }
+
+        let function = sys::gsl_movstat_function {
+            function: Some(inner_f::<F>),
+            params: &mut f as *mut F as *mut c_void,
+        };
+        Value::from(unsafe {
+            sys::gsl_movstat_apply(
+                endtype.into(),
+                &function,
+                x.unwrap_shared(),
+                y.unwrap_unique(),
+                self.unwrap_unique(),
+            )
+        })
+    }
+}
+
+#[cfg(any(test, doctest))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_movstat() {
+        let x = VectorF64::from_slice(&[1., 2., 3., 4., 5., 9.]).unwrap();
+        let mut y = VectorF64::new(6).unwrap();
+        let mut w = MovstatWorkspace::new(3).unwrap();
+
+        assert_eq!(w.mean(MovstatEnd::Truncate, &x, &mut y), Value::Success);
+        assert_eq!(y.as_slice().unwrap(), &[1.5, 2., 3., 4., 6., 7.]);
+        assert_eq!(w.sum(MovstatEnd::PadZero, &x, &mut y), Value::Success);
+        assert_eq!(y.as_slice().unwrap(), &[3., 6., 9., 12., 18., 14.]);
+        assert_eq!(w.max(MovstatEnd::PadValue, &x, &mut y), Value::Success);
+        assert_eq!(y.as_slice().unwrap(), &[2., 3., 4., 5., 9., 9.]);
+
+        let mut calls = 0;
+        let mut z = VectorF64::new(6).unwrap();
+        let ret = w.apply(
+            MovstatEnd::PadValue,
+            |window: &mut [f64]| {
+                calls += 1;
+                window.iter().cloned().fold(f64::MIN, f64::max)
+            },
+            &x,
+            &mut z,
+        );
+        assert_eq!(ret, Value::Success);
+        assert_eq!(calls, 6);
+        assert_eq!(y.as_slice(), z.as_slice());
+
+        let mut w = MovstatWorkspace::new_asymmetric(2, 0).unwrap();
+        assert_eq!(w.min(MovstatEnd::Truncate, &x, &mut y), Value::Success);
+        assert_eq!(y.as_slice().unwrap(), &[1., 1., 1., 2., 3., 4.]);
+    }
+}
@@ -0,0 +1,733 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+# Nonlinear Least-Squares Fitting
+
+This chapter describes functions for multidimensional nonlinear least-squares fitting. There are
+generally two classes of algorithms for solving nonlinear least squares problems, which fall under
+line search methods and trust region methods. GSL currently implements only trust region methods
+and provides the user with full access to intermediate steps of the iteration. The user also has
+the ability to tune a number of parameters which affect low-level aspects of the algorithm which
+can help to accelerate convergence for the specific problem at hand.
+
+## Overview
+
+The problem of multidimensional nonlinear least-squares fitting requires the minimization of the
+squared residuals of n functions, f_i, in p parameters, x_i,
+
+```text
+\Phi(x) = (1/2) || f(x) ||^2
+        = (1/2) \sum_{i=1}^{n} f_i(x_1, ..., x_p)^2
+```
+
+In trust region methods, the objective (or cost) function \Phi(x) is approximated by a model
+function m_k(\delta) in the vicinity of some point x_k. The model function is often simply a
+second order Taylor series expansion around the point x_k. The trust region is the region within
+which the model is considered a good approximation of the objective function. At each iteration,
+the solver computes a step \delta which minimizes the model within the trust region, and then
+decides whether to accept the step and how to update the trust region radius.
+
+## Weighted Nonlinear Least-Squares
+
+Weighted nonlinear least-squares fitting minimizes the function
+
+```text
+\Phi(x) = (1/2) f^T W f
+```
+
+where W = diag(w_1,w_2,...,w_n) is the weighting matrix. The weights w_i are commonly defined as
+w_i = 1/\sigma_i^2, where \sigma_i is the error in the i-th measurement. Use
+[`MultiFitNlinearWorkspace::winit`] to initialize a weighted fit.
+
+## Providing the Function to be Minimized
+
+The function to be minimized is provided through [`MultiFitNlinearFdf`]. Only the residual function
+`f` is mandatory: when no Jacobian `df` is provided it is approximated with finite differences,
+and the same happens for the second directional derivative `fvv` used by the geodesic
+acceleration method.
+!*/
+
+use crate::{MatrixF64, MultiFitNlinearFdType, Value, VectorF64};
+use ffi::FFI;
+use std::os::raw::{c_int, c_void};
+
+ffi_wrapper!(
+    MultiFitNlinearType,
+    *const sys::gsl_multifit_nlinear_type,
+    "Type of the nonlinear least squares solver."
+);
+
+impl MultiFitNlinearType {
+    /// This specifies a trust region method. It is currently the only implemented nonlinear least
+    /// squares method.
+    #[doc(alias = "gsl_multifit_nlinear_trust")]
+    pub fn trust() -> MultiFitNlinearType {
+        ffi_wrap!(gsl_multifit_nlinear_trust)
+    }
+}
+
+ffi_wrapper!(
+    MultiFitNlinearTrs,
+    *const sys::gsl_multifit_nlinear_trs,
+    "Method used to solve the trust region subproblem."
+);
+
+impl MultiFitNlinearTrs {
+    /// Levenberg-Marquardt algorithm.
+    #[doc(alias = "gsl_multifit_nlinear_trs_lm")]
+    pub fn lm() -> MultiFitNlinearTrs {
+        ffi_wrap!(gsl_multifit_nlinear_trs_lm)
+    }
+
+    /// Levenberg-Marquardt algorithm with geodesic acceleration.
+    #[doc(alias = "gsl_multifit_nlinear_trs_lmaccel")]
+    pub fn lmaccel() -> MultiFitNlinearTrs {
+        ffi_wrap!(gsl_multifit_nlinear_trs_lmaccel)
+    }
+
+    /// Dogleg algorithm.
+    #[doc(alias = "gsl_multifit_nlinear_trs_dogleg")]
+    pub fn dogleg() -> MultiFitNlinearTrs {
+        ffi_wrap!(gsl_multifit_nlinear_trs_dogleg)
+    }
+
+    /// Double dogleg algorithm.
+    #[doc(alias = "gsl_multifit_nlinear_trs_ddogleg")]
+    pub fn ddogleg() -> MultiFitNlinearTrs {
+        ffi_wrap!(gsl_multifit_nlinear_trs_ddogleg)
+    }
+
+    /// 2D subspace algorithm.
+    #[doc(alias = "gsl_multifit_nlinear_trs_subspace2D")]
+    pub fn subspace2D() -> MultiFitNlinearTrs {
+        ffi_wrap!(gsl_multifit_nlinear_trs_subspace2D)
+    }
+}
+
+ffi_wrapper!(
+    MultiFitNlinearScale,
+    *const sys::gsl_multifit_nlinear_scale,
+    "Scaling strategy used to build the diagonal scaling matrix D."
+);
+
+impl MultiFitNlinearScale {
+    /// This damping strategy was originally suggested by Levenberg, and corresponds to D^T D = I.
+    /// This method has also been called “Tikhonov regularization”; however, this name is not
+    /// appropriate for nonlinear least squares, since it is not regularizing the problem. This
+    /// strategy is not scale invariant.
+    #[doc(alias = "gsl_multifit_nlinear_scale_levenberg")]
+    pub fn levenberg() -> MultiFitNlinearScale {
+        ffi_wrap!(gsl_multifit_nlinear_scale_levenberg)
+    }
+
+    /// This damping strategy was suggested by Marquardt, and corresponds to D^T D = diag(J^T J).
+    /// This method is scale-invariant, but it is generally considered inferior to both the
+    /// Levenberg and Moré strategies, though may work well on certain classes of problems.
+    #[doc(alias = "gsl_multifit_nlinear_scale_marquardt")]
+    pub fn marquardt() -> MultiFitNlinearScale {
+        ffi_wrap!(gsl_multifit_nlinear_scale_marquardt)
+    }
+
+    /// This damping strategy was suggested by Moré, and corresponds to D^T D = max(diag(J^T J)),
+    /// in other words the maximum elements of diag(J^T J) encountered thus far in the iteration.
+    /// This choice of D makes the problem scale-invariant. This is the default strategy.
+    #[doc(alias = "gsl_multifit_nlinear_scale_more")]
+    pub fn more() -> MultiFitNlinearScale {
+        ffi_wrap!(gsl_multifit_nlinear_scale_more)
+    }
+}
+
+ffi_wrapper!(
+    MultiFitNlinearSolver,
+    *const sys::gsl_multifit_nlinear_solver,
+    "Linear solver used for the trust region subproblem."
+);
+
+impl MultiFitNlinearSolver {
+    /// This method solves the system using a Cholesky decomposition of the normal equations. It
+    /// is the fastest method but the least accurate for ill-conditioned Jacobians.
+    #[doc(alias = "gsl_multifit_nlinear_solver_cholesky")]
+    pub fn cholesky() -> MultiFitNlinearSolver {
+        ffi_wrap!(gsl_multifit_nlinear_solver_cholesky)
+    }
+
+    /// This method solves the system using a modified Cholesky decomposition of the normal
+    /// equations, which remains usable when J^T J is not positive definite.
+    #[doc(alias = "gsl_multifit_nlinear_solver_mcholesky")]
+    pub fn mcholesky() -> MultiFitNlinearSolver {
+        ffi_wrap!(gsl_multifit_nlinear_solver_mcholesky)
+    }
+
+    /// This method solves the system using a rank revealing QR decomposition of the Jacobian J.
+    /// This will generally be the fastest of the accurate methods and is the default.
+    #[doc(alias = "gsl_multifit_nlinear_solver_qr")]
+    pub fn qr() -> MultiFitNlinearSolver {
+        ffi_wrap!(gsl_multifit_nlinear_solver_qr)
+    }
+
+    /// This method solves the system using a singular value decomposition of J. It is the
+    /// slowest but most accurate method, best suited for severely ill-conditioned Jacobians.
+    #[doc(alias = "gsl_multifit_nlinear_solver_svd")]
+    pub fn svd() -> MultiFitNlinearSolver {
+        ffi_wrap!(gsl_multifit_nlinear_solver_svd)
+    }
+}
+
+/// Tunable parameters of the nonlinear least squares solver.
+#[derive(Clone, Copy)]
+pub struct MultiFitNlinearParameters {
+    inner: sys::gsl_multifit_nlinear_parameters,
+}
+
+impl Default for MultiFitNlinearParameters {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MultiFitNlinearParameters {
+    /// Returns the default parameters: Levenberg-Marquardt with Moré scaling and a QR solver.
+    #[doc(alias = "gsl_multifit_nlinear_default_parameters")]
+    pub fn new() -> MultiFitNlinearParameters {
+        MultiFitNlinearParameters {
+            inner: unsafe { sys::gsl_multifit_nlinear_default_parameters() },
+        }
+    }
+
+    /// Sets the trust region subproblem method.
+    pub fn set_trs(&mut self, trs: MultiFitNlinearTrs) -> &mut MultiFitNlinearParameters {
+        self.inner.trs = trs.unwrap_shared();
+        self
+    }
+
+    /// Sets the scaling method.
+    pub fn set_scale(&mut self, scale: MultiFitNlinearScale) -> &mut MultiFitNlinearParameters {
+        self.inner.scale = scale.unwrap_shared();
+        self
+    }
+
+    /// Sets the linear solver method.
+    pub fn set_solver(&mut self, solver: MultiFitNlinearSolver) -> &mut MultiFitNlinearParameters {
+        self.inner.solver = solver.unwrap_shared();
+        self
+    }
+
+    /// Returns the finite difference method used when no Jacobian is provided.
+    pub fn fdtype(&self) -> MultiFitNlinearFdType {
+        MultiFitNlinearFdType::from(self.inner.fdtype)
+    }
+
+    /// Sets the finite difference method used when no Jacobian is provided.
+    pub fn set_fdtype(&mut self, fdtype: MultiFitNlinearFdType) -> &mut MultiFitNlinearParameters {
+        self.inner.fdtype = fdtype.into();
+        self
+    }
+
+    /// Returns the factor by which the trust region radius is increased when a step is
+    /// accepted.
+    pub fn factor_up(&self) -> f64 {
+        self.inner.factor_up
+    }
+
+    /// When a step is accepted, the trust region radius will be increased by this factor. The
+    /// default value is 3.
+    pub fn set_factor_up(&mut self, factor_up: f64) -> &mut MultiFitNlinearParameters {
+        self.inner.factor_up = factor_up;
+        self
+    }
+
+    /// Returns the factor by which the trust region radius is decreased when a step is
+    /// rejected.
+    pub fn factor_down(&self) -> f64 {
+        self.inner.factor_down
+    }
+
+    /// When a step is rejected, the trust region radius will be decreased by this factor. The
+    /// default value is 2.
+    pub fn set_factor_down(&mut self, factor_down: f64) -> &mut MultiFitNlinearParameters {
+        self.inner.factor_down = factor_down;
+        self
+    }
+
+    /// Returns the maximum allowed ratio |a|/|v| of the acceleration term to the velocity term.
+    pub fn avmax(&self) -> f64 {
+        self.inner.avmax
+    }
+
+    /// When using geodesic acceleration to solve a nonlinear least squares problem, an important
+    /// parameter to monitor is the ratio of the acceleration term to the velocity term, |a|/|v|.
+    /// Steps with a ratio above this value are rejected. The default value is 0.75.
+    pub fn set_avmax(&mut self, avmax: f64) -> &mut MultiFitNlinearParameters {
+        self.inner.avmax = avmax;
+        self
+    }
+
+    /// Returns the step size used to approximate the Jacobian matrix with finite differences.
+    pub fn h_df(&self) -> f64 {
+        self.inner.h_df
+    }
+
+    /// Sets the step size for approximating the Jacobian matrix with finite differences. The
+    /// default value is \sqrt{\epsilon}.
+    pub fn set_h_df(&mut self, h_df: f64) -> &mut MultiFitNlinearParameters {
+        self.inner.h_df = h_df;
+        self
+    }
+
+    /// Returns the step size used to approximate the second directional derivative `fvv`.
+    pub fn h_fvv(&self) -> f64 {
+        self.inner.h_fvv
+    }
+
+    /// Sets the step size used to approximate the second directional derivative `fvv` when
+    /// using geodesic acceleration without providing it. The default value is 0.02.
+    pub fn set_h_fvv(&mut self, h_fvv: f64) -> &mut MultiFitNlinearParameters {
+        self.inner.h_fvv = h_fvv;
+        self
+    }
+}
+
+/// Definition of the nonlinear least squares problem: n residual functions in p parameters, with
+/// an optional Jacobian and optional second directional derivative.
+pub struct MultiFitNlinearFdf<'a> {
+    f: Box<dyn FnMut(&VectorF64, &mut VectorF64) -> Value + 'a>,
+    df: Option<Box<dyn FnMut(&VectorF64, &mut MatrixF64) -> Value + 'a>>,
+    fvv: Option<Box<dyn FnMut(&VectorF64, &VectorF64, &mut VectorF64) -> Value + 'a>>,
+    intern: sys::gsl_multifit_nlinear_fdf,
+}
+
+impl<'a> MultiFitNlinearFdf<'a> {
+    /// `n` is the number of residuals, `p` the number of parameters. `f` must store the n
+    /// residuals f_i(x) in its second argument.
+    #[doc(alias = "gsl_multifit_nlinear_fdf")]
+    pub fn new<F: FnMut(&VectorF64, &mut VectorF64) -> Value + 'a>(
+        n: usize,
+        p: usize,
+        f: F,
+    ) -> MultiFitNlinearFdf<'a> {
+        MultiFitNlinearFdf {
+            f: Box::new(f),
+            df: None,
+            fvv: None,
+            intern: sys::gsl_multifit_nlinear_fdf {
+                f: Some(nlinear_f),
+                df: None,
+                fvv: None,
+                n,
+                p,
+                params: ::std::ptr::null_mut(),
+                nevalf: 0,
+                nevaldf: 0,
+                nevalfvv: 0,
+            },
+        }
+    }
+
+    /// Sets the function computing the n-by-p Jacobian matrix J_{ij} = d f_i / d x_j. If not
+    /// set, the Jacobian is approximated with finite differences.
+    pub fn df<DF: FnMut(&VectorF64, &mut MatrixF64) -> Value + 'a>(
+        mut self,
+        df: DF,
+    ) -> MultiFitNlinearFdf<'a> {
+        self.df = Some(Box::new(df));
+        self.intern.df = Some(nlinear_df);
+        self
+    }
+
+    /// Sets the function computing the second directional derivative
+    /// fvv_i = \sum_{\alpha\beta} v_\alpha v_\beta \partial_\alpha \partial_\beta f_i(x) of the
+    /// residuals, used only by the geodesic acceleration method. If not set, it is approximated
+    /// with finite differences.
+    pub fn fvv<FVV: FnMut(&VectorF64, &VectorF64, &mut VectorF64) -> Value + 'a>(
+        mut self,
+        fvv: FVV,
+    ) -> MultiFitNlinearFdf<'a> {
+        self.fvv = Some(Box::new(fvv));
+        self.intern.fvv = Some(nlinear_fvv);
+        self
+    }
+
+    /// Returns the number of residuals.
+    pub fn n(&self) -> usize {
+        self.intern.n
+    }
+
+    /// Returns the number of parameters.
+    pub fn p(&self) -> usize {
+        self.intern.p
+    }
+
+    /// Returns the number of function evaluations performed so far.
+    pub fn nevalf(&self) -> usize {
+        self.intern.nevalf
+    }
+
+    /// Returns the number of Jacobian evaluations performed so far.
+    pub fn nevaldf(&self) -> usize {
+        self.intern.nevaldf
+    }
+
+    /// Returns the number of `fvv` evaluations performed so far.
+    pub fn nevalfvv(&self) -> usize {
+        self.intern.nevalfvv
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    fn to_raw(&mut self) -> *mut sys::gsl_multifit_nlinear_fdf {
+        self.intern.params = self as *mut MultiFitNlinearFdf as *mut c_void;
+        &mut self.intern
+    }
+}
+
+unsafe extern "C" fn nlinear_f(
+    x: *const sys::gsl_vector,
+    params: *mut c_void,
+    f: *mut sys::gsl_vector,
+) -> c_int {
+    let fdf = &mut *(params as *mut MultiFitNlinearFdf);
+    (fdf.f)(
+        &VectorF64::soft_wrap(x as *mut _),
+        &mut VectorF64::soft_wrap(f),
+    )
+    .into()
+}
+
+unsafe extern "C" fn nlinear_df(
+    x: *const sys::gsl_vector,
+    params: *mut c_void,
+    df: *mut sys::gsl_matrix,
+) -> c_int {
+    let fdf = &mut *(params as *mut MultiFitNlinearFdf);
+    match fdf.df {
+        Some(ref mut df_f) => df_f(
+            &VectorF64::soft_wrap(x as *mut _),
+            &mut MatrixF64::soft_wrap(df),
+        ),
+        None => Value::BadFunction,
+    }
+    .into()
+}
+
+unsafe extern "C" fn nlinear_fvv(
+    x: *const sys::gsl_vector,
+    v: *const sys::gsl_vector,
+    params: *mut c_void,
+    fvv: *mut sys::gsl_vector,
+) -> c_int {
+    let fdf = &mut *(params as *mut MultiFitNlinearFdf);
+    match fdf.fvv {
+        Some(ref mut fvv_f) => fvv_f(
+            &VectorF64::soft_wrap(x as *mut _),
+            &VectorF64::soft_wrap(v as *mut _),
+            &mut VectorF64::soft_wrap(fvv),
+        ),
+        None => Value::BadFunction,
+    }
+    .into()
+}
+
+ffi_wrapper!(
+    MultiFitNlinearWorkspace<'a>,
+    *mut sys::gsl_multifit_nlinear_workspace,
+    gsl_multifit_nlinear_free
+    ;fdf: Option<Box<MultiFitNlinearFdf<'a>>> => None;,
+    "This is a workspace for nonlinear least squares fitting using trust region methods."
+);
+
+impl<'a> MultiFitNlinearWorkspace<'a> {
+    /// This function returns a pointer to a newly allocated instance of a derivative solver of
+    /// type `T` for `n` observations and `p` parameters. The `params` input specifies a tunable
+    /// set of parameters which will affect important details in each iteration of the trust
+    /// region subproblem algorithm. It is recommended to start with the suggested default
+    /// parameters (see [`MultiFitNlinearParameters::new`]) and then tune the parameters once the
+    /// code is working correctly.
+    ///
+    /// If there is insufficient memory to create the solver then the function returns a null
+    /// pointer and the error handler is invoked with an error code of `Value::NoMemory`.
+    #[doc(alias = "gsl_multifit_nlinear_alloc")]
+    pub fn new(
+        t: &MultiFitNlinearType,
+        params: &MultiFitNlinearParameters,
+        n: usize,
+        p: usize,
+    ) -> Option<MultiFitNlinearWorkspace<'a>> {
+        let ptr =
+            unsafe { sys::gsl_multifit_nlinear_alloc(t.unwrap_shared(), &params.inner, n, p) };
+
+        if ptr.is_null() {
+            None
+        } else {
+            Some(MultiFitNlinearWorkspace::wrap(ptr))
+        }
+    }
+
+    /// This function initializes, or reinitializes, an existing workspace `w` to use the system
+    /// `fdf` and the initial guess `x`.
+    #[doc(alias = "gsl_multifit_nlinear_init")]
+    pub fn init(&mut self, x: &VectorF64, fdf: MultiFitNlinearFdf<'a>) -> Value {
+        let mut fdf = Box::new(fdf);
+        let raw = fdf.to_raw();
+        self.fdf = Some(fdf);
+
+        Value::from(unsafe {
+            sys::gsl_multifit_nlinear_init(x.unwrap_shared(), raw, self.unwrap_unique())
+        })
+    }
+
+    /// This function is the same as [`init`](Self::init) but it also sets the weighting
+    /// matrix to W = diag(w_1, ..., w_n) using the vector `wts`.
+    #[doc(alias = "gsl_multifit_nlinear_winit")]
+    pub fn winit(&mut self, x: &VectorF64, wts: &VectorF64, fdf: MultiFitNlinearFdf<'a>) -> Value {
+        let mut fdf = Box::new(fdf);
+        let raw = fdf.to_raw();
+        self.fdf = Some(fdf);
+
+        Value::from(unsafe {
+            sys::gsl_multifit_nlinear_winit(
+                x.unwrap_shared(),
+                wts.unwrap_shared(),
+                raw,
+                self.unwrap_unique(),
+            )
+        })
+    }
+
+    /// Returns the system given to [`init`](Self::init) or [`winit`](Self::winit), which can be
+    /// used to query the number of function evaluations.
+    pub fn fdf(&self) -> Option<&MultiFitNlinearFdf<'a>> {
+        self.fdf.as_deref()
+    }
+
+    /// This function performs a single iteration of the solver `w`. If the iteration encounters
+    /// an unexpected problem then an error code will be returned. The solver workspace maintains
+    /// a current estimate of the best-fit parameters at all times.
+    #[doc(alias = "gsl_multifit_nlinear_iterate")]
+    pub fn iterate(&mut self) -> Value {
+        Value::from(unsafe { sys::gsl_multifit_nlinear_iterate(self.unwrap_unique()) })
+    }
+
+    /// This function returns the current ratio |a|/|v| of the acceleration correction term to
+    /// the velocity step term. The acceleration term is computed only by the
+    /// [`lmaccel`](MultiFitNlinearTrs::lmaccel) method, so this ratio will be zero for other
+    /// methods.
+    #[doc(alias = "gsl_multifit_nlinear_avratio")]
+    pub fn avratio(&self) -> f64 {
+        unsafe { sys::gsl_multifit_nlinear_avratio(self.unwrap_shared()) }
+    }
+
+    /// This function iterates the nonlinear least squares solver `w` for a maximum of `maxiter`
+    /// iterations. After each iteration, the system is tested for convergence with the error
+    /// tolerances `xtol`, `gtol` and `ftol` (see [`test`](Self::test)).
+    ///
+    /// Returns `(Value, info)`. Upon successful convergence, the function returns
+    /// `Value::Success` and sets `info` to the reason for convergence (1 for a small step size, 2
+    /// for a small gradient). If the function has not converged after `maxiter` iterations,
+    /// `Value::MaxIteration` is returned.
+    #[doc(alias = "gsl_multifit_nlinear_driver")]
+    pub fn driver(&mut self, maxiter: usize, xtol: f64, gtol: f64, ftol: f64) -> (Value, i32) {
+        let mut info = 0;
+        let ret = unsafe {
+            sys::gsl_multifit_nlinear_driver(
+                maxiter,
+                xtol,
+                gtol,
+                ftol,
+                None,
+                ::std::ptr::null_mut(),
+                &mut info,
+                self.unwrap_unique(),
+            )
+        };
+        (Value::from(ret), info)
+    }
+
+    /// This function is the same as [`driver`](Self::driver) but it also calls `callback` after
+    /// each iteration with the current iteration number, the current position x and the current
+    /// residual vector f(x), so that the user may save or print relevant quantities.
+    // checker:ignore
+    #[doc(alias = "gsl_multifit_nlinear_driver")]
+    pub fn driver_with_callback<F: FnMut(usize, &VectorF64, &VectorF64)>(
+        &mut self,
+        maxiter: usize,
+        xtol: f64,
+        gtol: f64,
+        ftol: f64,
+        callback: F,
+    ) -> (Value, i32) {
+        unsafe extern "C" fn inner_callback<F: FnMut(usize, &VectorF64, &VectorF64)>(
+            iter: usize,
+            params: *mut c_void,
+            w: *const sys::gsl_multifit_nlinear_workspace,
+        ) {
+            let f = &mut *(params as *mut F);
+            let x = VectorF64::soft_wrap(sys::gsl_multifit_nlinear_position(w));
+            let r = VectorF64::soft_wrap(sys::gsl_multifit_nlinear_residual(w));
+            f(iter, &x, &r);
+        }
+
+        let mut callback = callback;
+        let mut info = 0;
+        let ret = unsafe {
+            sys::gsl_multifit_nlinear_driver(
+                maxiter,
+                xtol,
+                gtol,
+                ftol,
+                Some(inner_callback::<F>),
+                &mut callback as *mut F as *mut c_void,
+                &mut info,
+                self.unwrap_unique(),
+            )
+        };
+        (Value::from(ret), info)
+    }
+
+    /// This function returns the Jacobian matrix of the current iteration of the solver `w`.
+    #[doc(alias = "gsl_multifit_nlinear_jac")]
+    pub fn jac(&self) -> MatrixF64 {
+        MatrixF64::soft_wrap(unsafe { sys::gsl_multifit_nlinear_jac(self.unwrap_shared()) })
+    }
+
+    /// Returns the solver type name.
+    #[doc(alias = "gsl_multifit_nlinear_name")]
+    pub fn name(&self) -> String {
+        unsafe {
+            let tmp = sys::gsl_multifit_nlinear_name(self.unwrap_shared());
+
+            String::from_utf8_lossy(::std::ffi::CStr::from_ptr(tmp).to_bytes()).to_string()
+        }
+    }
+
+    /// Returns the name of the trust region subproblem method.
+    #[doc(alias = "gsl_multifit_nlinear_trs_name")]
+    pub fn trs_name(&self) -> String {
+        unsafe {
+            let tmp = sys::gsl_multifit_nlinear_trs_name(self.unwrap_shared());
+
+            String::from_utf8_lossy(::std::ffi::CStr::from_ptr(tmp).to_bytes()).to_string()
+        }
+    }
+
+    /// This function returns the current position x (i.e. best-fit parameters) of the solver
+    /// `w`.
+    #[doc(alias = "gsl_multifit_nlinear_position")]
+    pub fn position(&self) -> VectorF64 {
+        VectorF64::soft_wrap(unsafe { sys::gsl_multifit_nlinear_position(self.unwrap_shared()) })
+    }
+
+    /// This function returns the current residual vector f(x) of the solver `w`. For weighted
+    /// systems, the residual vector includes the weighting factor \sqrt{W}.
+    #[doc(alias = "gsl_multifit_nlinear_residual")]
+    pub fn residual(&self) -> VectorF64 {
+        VectorF64::soft_wrap(unsafe { sys::gsl_multifit_nlinear_residual(self.unwrap_shared()) })
+    }
+
+    /// This function returns the number of iterations performed by the solver `w`. The
+    /// iteration counter is updated on each call to [`iterate`](Self::iterate), and reset to 0
+    /// in [`init`](Self::init).
+    #[doc(alias = "gsl_multifit_nlinear_niter")]
+    pub fn niter(&self) -> usize {
+        unsafe { sys::gsl_multifit_nlinear_niter(self.unwrap_shared()) }
+    }
+
+    /// This function estimates the reciprocal condition number of the Jacobian matrix at the
+    /// current position x. The reciprocal condition number estimate is defined as
+    /// 1 / (||J||_2 ||J^{-1}||_2).
+    ///
+    /// Returns `(Value, rcond)`.
+    #[doc(alias = "gsl_multifit_nlinear_rcond")]
+    pub fn rcond(&self) -> (Value, f64) {
+        let mut rcond = 0.;
+        let ret = unsafe { sys::gsl_multifit_nlinear_rcond(&mut rcond, self.unwrap_shared()) };
+        (Value::from(ret), rcond)
+    }
+
+    /// This function tests for convergence of the nonlinear least squares solver `w` at the
+    /// current iteration:
+    ///
+    /// * testing for a small step size relative to the current parameter vector:
+    ///   `|\delta_i| <= xtol (|x_i| + xtol)`
+    /// * testing for a small gradient: `||g||_\inf <= gtol`
+    /// * testing for a small change in the residual vector: `||f(x+\delta) - f(x)|| <= ftol
+    ///   max(||f(x)||, 1)`
+    ///
+    /// Returns `(Value, info)`. If the test succeeds, `Value::Success` is returned and `info` is
+    /// set to the test which succeeded (1 for xtol, 2 for gtol). Otherwise `Value::Continue` is
+    /// returned.
+    #[doc(alias = "gsl_multifit_nlinear_test")]
+    pub fn test(&self, xtol: f64, gtol: f64, ftol: f64) -> (Value, i32) {
+        let mut info = 0;
+        let ret = unsafe {
+            sys::gsl_multifit_nlinear_test(xtol, gtol, ftol, &mut info, self.unwrap_shared())
+        };
+        (Value::from(ret), info)
+    }
+
+    /// This function computes the covariance matrix of best-fit parameters using the Jacobian
+    /// matrix of the current iteration, storing it in `covar`. The parameter `epsrel` is used to
+    /// remove linear-dependent columns when the Jacobian is rank deficient.
+    ///
+    /// The covariance matrix is given by `C = (J^T J)^{-1}`, so for an unweighted fit it must be
+    /// multiplied by the variance of the residuals to obtain the parameter covariance.
+    #[doc(alias = "gsl_multifit_nlinear_covar")]
+    pub fn covar(&self, epsrel: f64, covar: &mut MatrixF64) -> Value {
+        let jac = self.jac();
+        Value::from(unsafe {
+            sys::gsl_multifit_nlinear_covar(jac.unwrap_shared(), epsrel, covar.unwrap_unique())
+        })
+    }
+}
+
+#[cfg(any(test, doctest))]
+mod tests {
+    use super::*;
+
+    // Fit y = A * exp(-lambda * t) + b on exact data.
+    #[test]
+    fn test_multifit_nlinear() {
+        let n = 40;
+        let ts: Vec<f64> = (0..n).map(|i| i as f64 * 3.0 / (n - 1) as f64).collect();
+        let ys: Vec<f64> = ts.iter().map(|t| 5.0 * (-1.5 * t).exp() + 1.0).collect();
+
+        let fdf = MultiFitNlinearFdf::new(n, 3, |x, f| {
+            for (i, (t, y)) in ts.iter().zip(ys.iter()).enumerate() {
+                f.set(i, x.get(0) * (-x.get(1) * t).exp() + x.get(2) - y);
+            }
+            Value::Success
+        })
+        .df(|x, J| {
+            for (i, t) in ts.iter().enumerate() {
+                let e = (-x.get(1) * t).exp();
+                J.set(i, 0, e);
+                J.set(i, 1, -t * x.get(0) * e);
+                J.set(i, 2, 1.0);
+            }
+            Value::Success
+        });
+
+        let params = MultiFitNlinearParameters::new();
+        let mut w =
+            MultiFitNlinearWorkspace::new(&MultiFitNlinearType::trust(), &params, n, 3).unwrap();
+        let x = VectorF64::from_slice(&[1.0, 1.0, 0.0]).unwrap();
+        w.init(&x, fdf);
+
+        let mut iterations = 0;
+        let (status, _info) = w.driver_with_callback(100, 1e-8, 1e-8, 1e-8, |_iter, x, f| {
+            assert_eq!(x.len(), 3);
+            assert_eq!(f.len(), n);
+            iterations += 1;
+        });
+        assert_eq!(status, Value::Success);
+        assert!(iterations > 0);
+
+        let x = w.position();
+        assert!((x.get(0) - 5.0).abs() < 1e-6);
+        assert!((x.get(1) - 1.5).abs() < 1e-6);
+        assert!((x.get(2) - 1.0).abs() < 1e-6);
+
+        let mut covar = MatrixF64::new(3, 3).unwrap();
+        assert_eq!(w.covar(0.0, &mut covar), Value::Success);
+    }
+}
@@ -0,0 +1,228 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+# Robust Linear Regression
+
+Ordinary least squares (OLS) models are often heavily influenced by the presence of outliers.
+Outliers are data points which do not follow the general trend of the other observations,
+although there is strictly no precise definition of an outlier. Robust linear regression refers
+to regression algorithms which are robust to outliers. The most common type of robust regression
+is M-estimation, which minimizes
+
+```text
+\chi^2 = \sum_i \rho((y_i - \sum_j X_{ij} c_j) / \sigma_i)
+```
+
+where \rho is a function which grows more slowly than the square for large residuals. This is
+solved with iteratively reweighted least squares, where each iteration assigns the weights
+w_i = \psi(r_i) / r_i computed from the current residuals. The weighting function is selected
+with [`MultifitRobustType`](crate::MultifitRobustType).
+!*/
+
+use crate::{MatrixF64, MultifitRobustType, Value, VectorF64};
+use ffi::FFI;
+
+/// Statistics computed by the last call to [`MultifitRobustWorkspace::robust`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct MultifitRobustStats {
+    /// Standard deviation of the residuals as computed from ordinary least squares.
+    pub sigma_ols: f64,
+    /// Estimated scale factor \sigma computed with the median absolute deviation of the
+    /// residuals.
+    pub sigma_mad: f64,
+    /// Robust estimate of the standard deviation of the residuals.
+    pub sigma_rob: f64,
+    /// Final estimate of the standard deviation, computed from the above three.
+    pub sigma: f64,
+    /// Coefficient of determination R^2 = 1 - SSE / SST.
+    pub rsq: f64,
+    /// Adjusted coefficient of determination R^2 = 1 - (1 - R^2) (n - 1) / (n - p).
+    pub adj_rsq: f64,
+    /// Root mean squared error of the final residuals.
+    pub rmse: f64,
+    /// Residual sum of squares, taking into account the robust covariance matrix.
+    pub sse: f64,
+    /// Number of degrees of freedom n - p.
+    pub dof: usize,
+    /// Number of iterations performed by the algorithm.
+    pub numit: usize,
+    /// Final weights used.
+    pub weights: Vec<f64>,
+    /// Final residuals y - X c.
+    pub residuals: Vec<f64>,
+}
+
+ffi_wrapper!(
+    MultifitRobustWorkspace,
+    *mut sys::gsl_multifit_robust_workspace,
+    gsl_multifit_robust_free
+);
+
+impl MultifitRobustWorkspace {
+    /// This function allocates a workspace for fitting a model to `n` observations using `p`
+    /// parameters. The type `t` specifies the weighting function.
+    #[doc(alias = "gsl_multifit_robust_alloc")]
+    pub fn new(t: MultifitRobustType, n: usize, p: usize) -> Option<Self> {
+        let s = unsafe { sys::gsl_multifit_robust_alloc(t.into(), n, p) };
+        if s.is_null() {
+            None
+        } else {
+            Some(Self::wrap(s))
+        }
+    }
+
+    /// This function sets the tuning constant t used to adjust the residuals at each iteration
+    /// to `tune`. Decreasing the tuning constant increases the downweight assigned to large
+    /// residuals, while increasing the tuning constant decreases the downweight assigned to
+    /// large residuals.
+    #[doc(alias = "gsl_multifit_robust_tune")]
+    pub fn tune(&mut self, tune: f64) -> Value {
+        Value::from(unsafe { sys::gsl_multifit_robust_tune(tune, self.unwrap_unique()) })
+    }
+
+    /// This function sets the maximum number of iterations in the iteratively reweighted least
+    /// squares algorithm to `maxiter`. By default, this value is set to 100.
+    #[doc(alias = "gsl_multifit_robust_maxiter")]
+    pub fn maxiter(&mut self, maxiter: usize) -> Value {
+        Value::from(unsafe { sys::gsl_multifit_robust_maxiter(maxiter, self.unwrap_unique()) })
+    }
+
+    /// This function returns the name of the robust type `T` specified to
+    /// [`new`](Self::new).
+    #[doc(alias = "gsl_multifit_robust_name")]
+    pub fn name(&self) -> String {
+        unsafe {
+            let tmp = sys::gsl_multifit_robust_name(self.unwrap_shared());
+
+            String::from_utf8_lossy(::std::ffi::CStr::from_ptr(tmp).to_bytes()).to_string()
+        }
+    }
+
+    /// This function computes the vector of weights `wts` corresponding to the residual vector
+    /// `r`, using the weighting function and tuning constant of the workspace.
+    #[doc(alias = "gsl_multifit_robust_weights")]
+    pub fn weights(&mut self, r: &VectorF64, wts: &mut VectorF64) -> Value {
+        Value::from(unsafe {
+            sys::gsl_multifit_robust_weights(
+                r.unwrap_shared(),
+                wts.unwrap_unique(),
+                self.unwrap_unique(),
+            )
+        })
+    }
+
+    /// This function computes the best-fit parameters `c` of the model y = X c for the
+    /// observations `y` and the matrix of predictor variables `x`, attempting to reduce the
+    /// influence of outliers using the algorithm outlined above. The p-by-p variance-covariance
+    /// matrix of the model parameters `cov` is estimated as \sigma^2 (X^T X)^{-1}.
+    ///
+    /// If the algorithm does not converge within the maximum number of iterations,
+    /// `Value::MaxIteration` is returned, but `c` and `cov` still hold the last estimates.
+    #[doc(alias = "gsl_multifit_robust")]
+    pub fn robust(
+        &mut self,
+        x: &MatrixF64,
+        y: &VectorF64,
+        c: &mut VectorF64,
+        cov: &mut MatrixF64,
+    ) -> Value {
+        Value::from(unsafe {
+            sys::gsl_multifit_robust(
+                x.unwrap_shared(),
+                y.unwrap_shared(),
+                c.unwrap_unique(),
+                cov.unwrap_unique(),
+                self.unwrap_unique(),
+            )
+        })
+    }
+
+    /// This function computes the vector of studentized residuals
+    /// r_i = (y_i - (X c)_i) / (\sigma \sqrt{1 - h_i}) for the observations `y`, coefficients
+    /// `c` and matrix of predictor variables `x`, where h_i are the statistical leverages of the
+    /// last fit.
+    #[doc(alias = "gsl_multifit_robust_residuals")]
+    pub fn residuals(
+        &mut self,
+        x: &MatrixF64,
+        y: &VectorF64,
+        c: &VectorF64,
+        r: &mut VectorF64,
+    ) -> Value {
+        Value::from(unsafe {
+            sys::gsl_multifit_robust_residuals(
+                x.unwrap_shared(),
+                y.unwrap_shared(),
+                c.unwrap_shared(),
+                r.unwrap_unique(),
+                self.unwrap_unique(),
+            )
+        })
+    }
+
+    /// This function returns the statistics of the last fit performed with
+    /// [`robust`](Self::robust).
+    #[doc(alias = "gsl_multifit_robust_statistics")]
+    pub fn statistics(&self) -> MultifitRobustStats {
+        let stats = unsafe { sys::gsl_multifit_robust_statistics(self.unwrap_shared()) };
+        let to_vec = |v: *mut sys::gsl_vector| {
+            if v.is_null() {
+                Vec::new()
+            } else {
+                let v = VectorF64::soft_wrap(v);
+                (0..v.len()).map(|i| v.get(i)).collect()
+            }
+        };
+
+        MultifitRobustStats {
+            sigma_ols: stats.sigma_ols,
+            sigma_mad: stats.sigma_mad,
+            sigma_rob: stats.sigma_rob,
+            sigma: stats.sigma,
+            rsq: stats.Rsq,
+            adj_rsq: stats.adj_Rsq,
+            rmse: stats.rmse,
+            sse: stats.sse,
+            dof: stats.dof,
+            numit: stats.numit,
+            weights: to_vec(stats.weights),
+            residuals: to_vec(stats.r),
+        }
+    }
+}
+
+#[cfg(any(test, doctest))]
+mod tests {
+    use super::*;
+
+    // Fit a line through points lying on y = 2 + 3x, with a single large outlier.
+    #[test]
+    fn test_multifit_robust() {
+        let n = 20;
+        let mut x = MatrixF64::new(n, 2).unwrap();
+        let mut y = VectorF64::new(n).unwrap();
+        for i in 0..n {
+            let xi = i as f64;
+            x.set(i, 0, 1.);
+            x.set(i, 1, xi);
+            y.set(i, 2. + 3. * xi);
+        }
+        y.set(10, 1000.);
+
+        let mut w = MultifitRobustWorkspace::new(MultifitRobustType::Bisquare, n, 2).unwrap();
+        let mut c = VectorF64::new(2).unwrap();
+        let mut cov = MatrixF64::new(2, 2).unwrap();
+        assert_eq!(w.robust(&x, &y, &mut c, &mut cov), Value::Success);
+        assert!((c.get(0) - 2.).abs() < 1e-6);
+        assert!((c.get(1) - 3.).abs() < 1e-6);
+
+        let stats = w.statistics();
+        assert_eq!(stats.dof, n - 2);
+        assert_eq!(stats.weights.len(), n);
+        assert_eq!(stats.residuals.len(), n);
+        assert!(stats.weights[10] < 1e-6);
+        assert_eq!(w.name(), "bisquare");
+    }
+}
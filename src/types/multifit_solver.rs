@@ -282,7 +282,7 @@ impl MultiFitFSolver {
 
     #[doc(alias = "gsl_multifit_fsolver_position")]
     pub fn position(&self) -> VectorF64 {
-        unsafe { ffi::FFI::wrap(sys::gsl_multifit_fsolver_position(self.unwrap_shared())) }
+        unsafe { ffi::FFI::soft_wrap(sys::gsl_multifit_fsolver_position(self.unwrap_shared())) }
     }
 }
 
@@ -315,6 +315,25 @@ impl MultiFitFdfSolver {
         })
     }
 
+    /// This function is the same as [`set`](Self::set) but it also sets the weighting matrix
+    /// W = diag(wts), turning the problem into a weighted least squares fit.
+    #[doc(alias = "gsl_multifit_fdfsolver_wset")]
+    pub fn wset(
+        &mut self,
+        f: &mut MultiFitFunctionFdf,
+        x: &::VectorF64,
+        wts: &::VectorF64,
+    ) -> ::Value {
+        ::Value::from(unsafe {
+            sys::gsl_multifit_fdfsolver_wset(
+                self.unwrap_unique(),
+                f.to_raw(),
+                x.unwrap_shared(),
+                wts.unwrap_shared(),
+            )
+        })
+    }
+
     pub fn x(&self) -> ::VectorF64 {
         unsafe { ffi::FFI::soft_wrap((*self.unwrap_shared()).x) }
     }
@@ -355,7 +374,46 @@ impl MultiFitFdfSolver {
     /// This function returns the current position (i.e. best-fit parameters) s->x of the solver s.
     #[doc(alias = "gsl_multifit_fdfsolver_position")]
     pub fn position(&self) -> ::VectorF64 {
-        unsafe { ffi::FFI::wrap(sys::gsl_multifit_fdfsolver_position(self.unwrap_shared())) }
+        unsafe { ffi::FFI::soft_wrap(sys::gsl_multifit_fdfsolver_position(self.unwrap_shared())) }
+    }
+
+    /// This function returns the current residual vector f(x) of the solver s. For weighted
+    /// systems, the residual vector includes the weighting factor \sqrt{W}.
+    #[doc(alias = "gsl_multifit_fdfsolver_residual")]
+    pub fn residual(&self) -> ::VectorF64 {
+        unsafe { ffi::FFI::soft_wrap(sys::gsl_multifit_fdfsolver_residual(self.unwrap_shared())) }
+    }
+
+    /// This function returns the number of iterations performed by the solver s. The iteration
+    /// counter is updated on each call to [`iterate`](Self::iterate), and reset to 0 in
+    /// [`set`](Self::set).
+    #[doc(alias = "gsl_multifit_fdfsolver_niter")]
+    pub fn niter(&self) -> usize {
+        unsafe { sys::gsl_multifit_fdfsolver_niter(self.unwrap_shared()) }
+    }
+
+    /// This function stores the Jacobian matrix of the current iteration of the solver s into
+    /// the output `J`.
+    #[doc(alias = "gsl_multifit_fdfsolver_jac")]
+    pub fn jac(&mut self, J: &mut ::MatrixF64) -> ::Value {
+        ::Value::from(unsafe {
+            sys::gsl_multifit_fdfsolver_jac(self.unwrap_unique(), J.unwrap_unique())
+        })
+    }
+
+    /// This function tests for convergence of the solver s with the tolerances `xtol` (small
+    /// step), `gtol` (small gradient) and `ftol` (small change in the residual).
+    ///
+    /// Returns `(Value, info)`. If the test succeeds, `Value::Success` is returned and `info` is
+    /// set to the test which succeeded (1 for xtol, 2 for gtol). Otherwise `Value::Continue` is
+    /// returned.
+    #[doc(alias = "gsl_multifit_fdfsolver_test")]
+    pub fn test(&self, xtol: f64, gtol: f64, ftol: f64) -> (::Value, i32) {
+        let mut info = 0;
+        let ret = unsafe {
+            sys::gsl_multifit_fdfsolver_test(self.unwrap_shared(), xtol, gtol, ftol, &mut info)
+        };
+        (::Value::from(ret), info)
     }
 
     /// These functions iterate the solver s for a maximum of maxiter iterations. After each
@@ -409,6 +467,196 @@ impl MultiFitFdfSolverType {
     }
 }
 
+ffi_wrapper!(
+    FdfRidgeSolver<'a>,
+    *mut sys::gsl_multifit_fdfridge,
+    gsl_multifit_fdfridge_free
+    ;_fdf: ::std::marker::PhantomData<&'a mut MultiFitFunctionFdf> => ::std::marker::PhantomData;,
+    "Solver for nonlinear least squares problems with Tikhonov (ridge) regularization, which
+minimizes ||f(x)||^2 + ||L x||^2 where L is either lambda I, a diagonal matrix or a general
+matrix."
+);
+
+impl<'a> FdfRidgeSolver<'a> {
+    /// This function returns a pointer to a newly allocated instance of a ridge solver of type T
+    /// for n observations and p parameters.
+    #[doc(alias = "gsl_multifit_fdfridge_alloc")]
+    pub fn new(t: &MultiFitFdfSolverType, n: usize, p: usize) -> Option<FdfRidgeSolver<'a>> {
+        let s = unsafe { sys::gsl_multifit_fdfridge_alloc(t.unwrap_shared(), n, p) };
+        if s.is_null() {
+            None
+        } else {
+            Some(FdfRidgeSolver::wrap(s))
+        }
+    }
+
+    /// This function initializes the solver to use the function `f`, the initial guess `x` and
+    /// the regularization matrix L = lambda I.
+    #[doc(alias = "gsl_multifit_fdfridge_set")]
+    pub fn set(&mut self, f: &'a mut MultiFitFunctionFdf, x: &::VectorF64, lambda: f64) -> ::Value {
+        ::Value::from(unsafe {
+            sys::gsl_multifit_fdfridge_set(
+                self.unwrap_unique(),
+                f.to_raw(),
+                x.unwrap_shared(),
+                lambda,
+            )
+        })
+    }
+
+    /// Same as [`set`](Self::set) but also sets the weighting matrix W = diag(wts).
+    #[doc(alias = "gsl_multifit_fdfridge_wset")]
+    pub fn wset(
+        &mut self,
+        f: &'a mut MultiFitFunctionFdf,
+        x: &::VectorF64,
+        lambda: f64,
+        wts: &::VectorF64,
+    ) -> ::Value {
+        ::Value::from(unsafe {
+            sys::gsl_multifit_fdfridge_wset(
+                self.unwrap_unique(),
+                f.to_raw(),
+                x.unwrap_shared(),
+                lambda,
+                wts.unwrap_shared(),
+            )
+        })
+    }
+
+    /// This function initializes the solver to use the function `f`, the initial guess `x` and
+    /// the diagonal regularization matrix L = diag(lambda).
+    #[doc(alias = "gsl_multifit_fdfridge_set2")]
+    pub fn set2(
+        &mut self,
+        f: &'a mut MultiFitFunctionFdf,
+        x: &::VectorF64,
+        lambda: &'a ::VectorF64,
+    ) -> ::Value {
+        ::Value::from(unsafe {
+            sys::gsl_multifit_fdfridge_set2(
+                self.unwrap_unique(),
+                f.to_raw(),
+                x.unwrap_shared(),
+                lambda.unwrap_shared(),
+            )
+        })
+    }
+
+    /// Same as [`set2`](Self::set2) but also sets the weighting matrix W = diag(wts).
+    #[doc(alias = "gsl_multifit_fdfridge_wset2")]
+    pub fn wset2(
+        &mut self,
+        f: &'a mut MultiFitFunctionFdf,
+        x: &::VectorF64,
+        lambda: &'a ::VectorF64,
+        wts: &::VectorF64,
+    ) -> ::Value {
+        ::Value::from(unsafe {
+            sys::gsl_multifit_fdfridge_wset2(
+                self.unwrap_unique(),
+                f.to_raw(),
+                x.unwrap_shared(),
+                lambda.unwrap_shared(),
+                wts.unwrap_shared(),
+            )
+        })
+    }
+
+    /// This function initializes the solver to use the function `f`, the initial guess `x` and
+    /// the general regularization matrix `L`, which has p columns and any number of rows.
+    #[doc(alias = "gsl_multifit_fdfridge_set3")]
+    pub fn set3(
+        &mut self,
+        f: &'a mut MultiFitFunctionFdf,
+        x: &::VectorF64,
+        L: &'a ::MatrixF64,
+    ) -> ::Value {
+        ::Value::from(unsafe {
+            sys::gsl_multifit_fdfridge_set3(
+                self.unwrap_unique(),
+                f.to_raw(),
+                x.unwrap_shared(),
+                L.unwrap_shared(),
+            )
+        })
+    }
+
+    /// Same as [`set3`](Self::set3) but also sets the weighting matrix W = diag(wts).
+    #[doc(alias = "gsl_multifit_fdfridge_wset3")]
+    pub fn wset3(
+        &mut self,
+        f: &'a mut MultiFitFunctionFdf,
+        x: &::VectorF64,
+        L: &'a ::MatrixF64,
+        wts: &::VectorF64,
+    ) -> ::Value {
+        ::Value::from(unsafe {
+            sys::gsl_multifit_fdfridge_wset3(
+                self.unwrap_unique(),
+                f.to_raw(),
+                x.unwrap_shared(),
+                L.unwrap_shared(),
+                wts.unwrap_shared(),
+            )
+        })
+    }
+
+    #[doc(alias = "gsl_multifit_fdfridge_name")]
+    pub fn name(&self) -> String {
+        unsafe {
+            let tmp = sys::gsl_multifit_fdfridge_name(self.unwrap_shared());
+
+            String::from_utf8_lossy(::std::ffi::CStr::from_ptr(tmp).to_bytes()).to_string()
+        }
+    }
+
+    /// This function performs a single iteration of the solver.
+    #[doc(alias = "gsl_multifit_fdfridge_iterate")]
+    pub fn iterate(&mut self) -> ::Value {
+        ::Value::from(unsafe { sys::gsl_multifit_fdfridge_iterate(self.unwrap_unique()) })
+    }
+
+    /// This function returns the current position (i.e. best-fit parameters) of the solver.
+    #[doc(alias = "gsl_multifit_fdfridge_position")]
+    pub fn position(&self) -> ::VectorF64 {
+        unsafe { ffi::FFI::soft_wrap(sys::gsl_multifit_fdfridge_position(self.unwrap_shared())) }
+    }
+
+    /// This function returns the current residual vector f(x) of the solver, without the
+    /// regularization terms.
+    #[doc(alias = "gsl_multifit_fdfridge_residual")]
+    pub fn residual(&self) -> ::VectorF64 {
+        unsafe { ffi::FFI::soft_wrap(sys::gsl_multifit_fdfridge_residual(self.unwrap_shared())) }
+    }
+
+    /// This function returns the number of iterations performed by the solver.
+    #[doc(alias = "gsl_multifit_fdfridge_niter")]
+    pub fn niter(&self) -> usize {
+        unsafe { sys::gsl_multifit_fdfridge_niter(self.unwrap_shared()) }
+    }
+
+    /// This function iterates the solver for a maximum of `maxiter` iterations, testing for
+    /// convergence after each iteration with the tolerances `xtol`, `gtol` and `ftol`.
+    ///
+    /// Returns `(Value, info)`, where `info` is the reason for convergence.
+    #[doc(alias = "gsl_multifit_fdfridge_driver")]
+    pub fn driver(&mut self, maxiter: usize, xtol: f64, gtol: f64, ftol: f64) -> (::Value, i32) {
+        let mut info = 0;
+        let ret = unsafe {
+            sys::gsl_multifit_fdfridge_driver(
+                self.unwrap_unique(),
+                maxiter,
+                xtol,
+                gtol,
+                ftol,
+                &mut info,
+            )
+        };
+        (::Value::from(ret), info)
+    }
+}
+
 pub struct MultiFitFunctionFdf {
     pub f: Option<Box<dyn Fn(::VectorF64, ::VectorF64) -> ::Value>>,
     pub df: Option<Box<dyn Fn(::VectorF64, ::MatrixF64) -> ::Value>>,
@@ -441,7 +689,7 @@ impl MultiFitFunctionFdf {
     }
 
     #[allow(clippy::wrong_self_convention)]
-    fn to_raw(&mut self) -> *mut sys::gsl_multifit_function_fdf {
+    pub(crate) fn to_raw(&mut self) -> *mut sys::gsl_multifit_function_fdf {
         self.intern.n = self.n;
         self.intern.p = self.p;
         self.intern.params = self as *mut MultiFitFunctionFdf as *mut c_void;
@@ -501,3 +749,99 @@ unsafe extern "C" fn fdf(
         ::Value::Success.into()
     }
 }
+
+#[cfg(any(test, doctest))]
+mod tests {
+    use super::*;
+    use MatrixF64;
+
+    // Residuals of y = A * exp(-lambda * t) + b against exact data with A = 5, lambda = 1.5 and
+    // b = 1.
+    fn exponential(n: usize) -> MultiFitFunctionFdf {
+        let ts: Vec<f64> = (0..n).map(|i| i as f64 * 3.0 / (n - 1) as f64).collect();
+        let ys: Vec<f64> = ts.iter().map(|t| 5.0 * (-1.5 * t).exp() + 1.0).collect();
+
+        let mut fdf = MultiFitFunctionFdf::new(n, 3, 0, 0);
+        let t1 = ts.clone();
+        fdf.f = Some(Box::new(move |x, mut f| {
+            for (i, (t, y)) in t1.iter().zip(ys.iter()).enumerate() {
+                f.set(i, x.get(0) * (-x.get(1) * t).exp() + x.get(2) - y);
+            }
+            ::Value::Success
+        }));
+        fdf.df = Some(Box::new(move |x, mut J| {
+            for (i, t) in ts.iter().enumerate() {
+                let e = (-x.get(1) * t).exp();
+                J.set(i, 0, e);
+                J.set(i, 1, -t * x.get(0) * e);
+                J.set(i, 2, 1.0);
+            }
+            ::Value::Success
+        }));
+        fdf
+    }
+
+    #[test]
+    fn test_fdfsolver_wset() {
+        let n = 40;
+        let mut fdf = exponential(n);
+        let x = VectorF64::from_slice(&[1.0, 1.0, 0.0]).unwrap();
+        let mut wts = VectorF64::new(n).unwrap();
+        wts.set_all(4.0);
+
+        let mut s = MultiFitFdfSolver::new(&MultiFitFdfSolverType::lmsder(), n, 3).unwrap();
+        assert_eq!(s.wset(&mut fdf, &x, &wts), ::Value::Success);
+        let mut status = ::Value::Continue;
+        while status == ::Value::Continue && s.niter() < 100 {
+            assert_eq!(s.iterate(), ::Value::Success);
+            status = s.test(1e-8, 1e-8, 0.).0;
+        }
+        assert_eq!(status, ::Value::Success);
+        assert_eq!(s.residual().len(), n);
+
+        let x = s.position();
+        assert!((x.get(0) - 5.0).abs() < 1e-4);
+        assert!((x.get(1) - 1.5).abs() < 1e-4);
+        assert!((x.get(2) - 1.0).abs() < 1e-4);
+
+        let mut J = MatrixF64::new(n, 3).unwrap();
+        assert_eq!(s.jac(&mut J), ::Value::Success);
+        let mut covar = MatrixF64::new(3, 3).unwrap();
+        assert_eq!(::multifit::covar(&J, 0., &mut covar), ::Value::Success);
+
+        // The finite difference Jacobian of the unweighted system matches the analytic one.
+        let mut f = VectorF64::new(n).unwrap();
+        let mut dif_J = MatrixF64::new(n, 3).unwrap();
+        (fdf.f.as_ref().unwrap())(x.clone().unwrap(), FFI::soft_wrap(f.unwrap_unique()));
+        assert_eq!(
+            ::multifit::fdfsolver_dif_df(&x, None, &mut fdf, &f, &mut dif_J),
+            ::Value::Success
+        );
+        (fdf.df.as_ref().unwrap())(x.clone().unwrap(), FFI::soft_wrap(J.unwrap_unique()));
+        for i in 0..n {
+            for j in 0..3 {
+                assert!((dif_J.get(i, j) - J.get(i, j)).abs() < 1e-6);
+            }
+        }
+    }
+
+    // Fit the same model with a negligible ridge penalty.
+    #[test]
+    fn test_fdfridge_solver() {
+        let n = 40;
+        let mut fdf = exponential(n);
+
+        let x = VectorF64::from_slice(&[1.0, 1.0, 0.0]).unwrap();
+        let mut s = FdfRidgeSolver::new(&MultiFitFdfSolverType::lmsder(), n, 3).unwrap();
+        assert_eq!(s.set(&mut fdf, &x, 1e-10), ::Value::Success);
+        let (status, _info) = s.driver(100, 1e-8, 1e-8, 1e-8);
+        assert_eq!(status, ::Value::Success);
+        assert!(s.niter() > 0);
+        assert_eq!(s.residual().len(), n);
+
+        let x = s.position();
+        assert!((x.get(0) - 5.0).abs() < 1e-4);
+        assert!((x.get(1) - 1.5).abs() < 1e-4);
+        assert!((x.get(2) - 1.0).abs() < 1e-4);
+    }
+}
@@ -0,0 +1,777 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+# Large-scale Nonlinear Least-Squares Fitting
+
+For very large problems, the dense n-by-p Jacobian used by
+[`MultiFitNlinearWorkspace`](crate::MultiFitNlinearWorkspace) may not fit in memory. The large
+scale solver never stores J itself: instead the user provides a function computing the products
+J u and J^T u for a given vector u, and optionally the normal equations matrix J^T J. The trust
+region subproblem can then be solved either from J^T J (Cholesky based solvers) or without it,
+using the Steihaug-Toint conjugate gradient method ([`MultilargeNlinearTrs::cgst`] together with
+[`MultilargeNlinearSolver::none`]).
+
+The workspace otherwise mirrors the dense solver: it is initialized with [`MultilargeNlinearFdf`],
+iterated with [`MultilargeNlinearWorkspace::iterate`] or [`MultilargeNlinearWorkspace::driver`],
+and the covariance matrix of the best-fit parameters is available through
+[`MultilargeNlinearWorkspace::covar`].
+!*/
+
+use crate::{CblasTranspose, MatrixF64, MultilargeNlinearFdType, Value, VectorF64};
+use ffi::FFI;
+use std::os::raw::{c_int, c_void};
+
+ffi_wrapper!(
+    MultilargeNlinearType,
+    *const sys::gsl_multilarge_nlinear_type,
+    "Type of the large-scale nonlinear least squares solver."
+);
+
+impl MultilargeNlinearType {
+    /// This specifies a trust region method. It is currently the only implemented nonlinear least
+    /// squares method.
+    #[doc(alias = "gsl_multilarge_nlinear_trust")]
+    pub fn trust() -> MultilargeNlinearType {
+        ffi_wrap!(gsl_multilarge_nlinear_trust)
+    }
+}
+
+ffi_wrapper!(
+    MultilargeNlinearTrs,
+    *const sys::gsl_multilarge_nlinear_trs,
+    "Method used to solve the trust region subproblem."
+);
+
+impl MultilargeNlinearTrs {
+    /// Levenberg-Marquardt algorithm.
+    #[doc(alias = "gsl_multilarge_nlinear_trs_lm")]
+    pub fn lm() -> MultilargeNlinearTrs {
+        ffi_wrap!(gsl_multilarge_nlinear_trs_lm)
+    }
+
+    /// Levenberg-Marquardt algorithm with geodesic acceleration.
+    #[doc(alias = "gsl_multilarge_nlinear_trs_lmaccel")]
+    pub fn lmaccel() -> MultilargeNlinearTrs {
+        ffi_wrap!(gsl_multilarge_nlinear_trs_lmaccel)
+    }
+
+    /// Dogleg algorithm.
+    #[doc(alias = "gsl_multilarge_nlinear_trs_dogleg")]
+    pub fn dogleg() -> MultilargeNlinearTrs {
+        ffi_wrap!(gsl_multilarge_nlinear_trs_dogleg)
+    }
+
+    /// Double dogleg algorithm.
+    #[doc(alias = "gsl_multilarge_nlinear_trs_ddogleg")]
+    pub fn ddogleg() -> MultilargeNlinearTrs {
+        ffi_wrap!(gsl_multilarge_nlinear_trs_ddogleg)
+    }
+
+    /// 2D subspace algorithm.
+    #[doc(alias = "gsl_multilarge_nlinear_trs_subspace2D")]
+    pub fn subspace2D() -> MultilargeNlinearTrs {
+        ffi_wrap!(gsl_multilarge_nlinear_trs_subspace2D)
+    }
+
+    /// Steihaug-Toint conjugate gradient algorithm. This method only needs the products J u and
+    /// J^T u and never forms J^T J, which makes it suitable for problems where even the p-by-p
+    /// normal equations matrix is too large.
+    #[doc(alias = "gsl_multilarge_nlinear_trs_cgst")]
+    pub fn cgst() -> MultilargeNlinearTrs {
+        ffi_wrap!(gsl_multilarge_nlinear_trs_cgst)
+    }
+}
+
+ffi_wrapper!(
+    MultilargeNlinearScale,
+    *const sys::gsl_multilarge_nlinear_scale,
+    "Scaling strategy used to build the diagonal scaling matrix D."
+);
+
+impl MultilargeNlinearScale {
+    /// Levenberg damping strategy, D^T D = I. This strategy is not scale invariant.
+    #[doc(alias = "gsl_multilarge_nlinear_scale_levenberg")]
+    pub fn levenberg() -> MultilargeNlinearScale {
+        ffi_wrap!(gsl_multilarge_nlinear_scale_levenberg)
+    }
+
+    /// Marquardt damping strategy, D^T D = diag(J^T J).
+    #[doc(alias = "gsl_multilarge_nlinear_scale_marquardt")]
+    pub fn marquardt() -> MultilargeNlinearScale {
+        ffi_wrap!(gsl_multilarge_nlinear_scale_marquardt)
+    }
+
+    /// Moré damping strategy, D^T D = max(diag(J^T J)) over the iterations so far. This is the
+    /// default strategy.
+    #[doc(alias = "gsl_multilarge_nlinear_scale_more")]
+    pub fn more() -> MultilargeNlinearScale {
+        ffi_wrap!(gsl_multilarge_nlinear_scale_more)
+    }
+}
+
+ffi_wrapper!(
+    MultilargeNlinearSolver,
+    *const sys::gsl_multilarge_nlinear_solver,
+    "Linear solver used for the trust region subproblem."
+);
+
+impl MultilargeNlinearSolver {
+    /// Solves the subproblem using a Cholesky decomposition of J^T J. This is the default.
+    #[doc(alias = "gsl_multilarge_nlinear_solver_cholesky")]
+    pub fn cholesky() -> MultilargeNlinearSolver {
+        ffi_wrap!(gsl_multilarge_nlinear_solver_cholesky)
+    }
+
+    /// Solves the subproblem using a modified Cholesky decomposition of J^T J, which remains
+    /// usable when J^T J is not positive definite.
+    #[doc(alias = "gsl_multilarge_nlinear_solver_mcholesky")]
+    pub fn mcholesky() -> MultilargeNlinearSolver {
+        ffi_wrap!(gsl_multilarge_nlinear_solver_mcholesky)
+    }
+
+    /// No linear solver. This must be used with [`MultilargeNlinearTrs::cgst`], in which case
+    /// J^T J is never requested from the user.
+    #[doc(alias = "gsl_multilarge_nlinear_solver_none")]
+    pub fn none() -> MultilargeNlinearSolver {
+        ffi_wrap!(gsl_multilarge_nlinear_solver_none)
+    }
+}
+
+/// Tunable parameters of the large-scale nonlinear least squares solver.
+#[derive(Clone, Copy)]
+pub struct MultilargeNlinearParameters {
+    inner: sys::gsl_multilarge_nlinear_parameters,
+}
+
+impl Default for MultilargeNlinearParameters {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MultilargeNlinearParameters {
+    /// Returns the default parameters: Levenberg-Marquardt with Moré scaling and a Cholesky
+    /// solver.
+    #[doc(alias = "gsl_multilarge_nlinear_default_parameters")]
+    pub fn new() -> MultilargeNlinearParameters {
+        MultilargeNlinearParameters {
+            inner: unsafe { sys::gsl_multilarge_nlinear_default_parameters() },
+        }
+    }
+
+    /// Sets the trust region subproblem method.
+    pub fn set_trs(&mut self, trs: MultilargeNlinearTrs) -> &mut MultilargeNlinearParameters {
+        self.inner.trs = trs.unwrap_shared();
+        self
+    }
+
+    /// Sets the scaling method.
+    pub fn set_scale(&mut self, scale: MultilargeNlinearScale) -> &mut MultilargeNlinearParameters {
+        self.inner.scale = scale.unwrap_shared();
+        self
+    }
+
+    /// Sets the linear solver method.
+    pub fn set_solver(
+        &mut self,
+        solver: MultilargeNlinearSolver,
+    ) -> &mut MultilargeNlinearParameters {
+        self.inner.solver = solver.unwrap_shared();
+        self
+    }
+
+    /// Returns the finite difference method used when no Jacobian products are provided.
+    pub fn fdtype(&self) -> MultilargeNlinearFdType {
+        MultilargeNlinearFdType::from(self.inner.fdtype)
+    }
+
+    /// Sets the finite difference method used when no Jacobian products are provided.
+    pub fn set_fdtype(
+        &mut self,
+        fdtype: MultilargeNlinearFdType,
+    ) -> &mut MultilargeNlinearParameters {
+        self.inner.fdtype = fdtype.into();
+        self
+    }
+
+    /// Returns the factor by which the trust region radius is increased when a step is
+    /// accepted.
+    pub fn factor_up(&self) -> f64 {
+        self.inner.factor_up
+    }
+
+    /// When a step is accepted, the trust region radius will be increased by this factor. The
+    /// default value is 3.
+    pub fn set_factor_up(&mut self, factor_up: f64) -> &mut MultilargeNlinearParameters {
+        self.inner.factor_up = factor_up;
+        self
+    }
+
+    /// Returns the factor by which the trust region radius is decreased when a step is
+    /// rejected.
+    pub fn factor_down(&self) -> f64 {
+        self.inner.factor_down
+    }
+
+    /// When a step is rejected, the trust region radius will be decreased by this factor. The
+    /// default value is 2.
+    pub fn set_factor_down(&mut self, factor_down: f64) -> &mut MultilargeNlinearParameters {
+        self.inner.factor_down = factor_down;
+        self
+    }
+
+    /// Returns the maximum allowed ratio |a|/|v| of the acceleration term to the velocity term.
+    pub fn avmax(&self) -> f64 {
+        self.inner.avmax
+    }
+
+    /// Maximum allowed ratio |a|/|v| of the acceleration term to the velocity term when using
+    /// geodesic acceleration. The default value is 0.75.
+    pub fn set_avmax(&mut self, avmax: f64) -> &mut MultilargeNlinearParameters {
+        self.inner.avmax = avmax;
+        self
+    }
+
+    /// Returns the step size used to approximate the Jacobian products with finite differences.
+    pub fn h_df(&self) -> f64 {
+        self.inner.h_df
+    }
+
+    /// Sets the step size for approximating the Jacobian products with finite differences.
+    pub fn set_h_df(&mut self, h_df: f64) -> &mut MultilargeNlinearParameters {
+        self.inner.h_df = h_df;
+        self
+    }
+
+    /// Returns the step size used to approximate the second directional derivative `fvv`.
+    pub fn h_fvv(&self) -> f64 {
+        self.inner.h_fvv
+    }
+
+    /// Sets the step size used to approximate the second directional derivative `fvv` when
+    /// using geodesic acceleration without providing it.
+    pub fn set_h_fvv(&mut self, h_fvv: f64) -> &mut MultilargeNlinearParameters {
+        self.inner.h_fvv = h_fvv;
+        self
+    }
+
+    /// Returns the maximum number of iterations used by the [`cgst`](MultilargeNlinearTrs::cgst)
+    /// method.
+    pub fn max_iter(&self) -> usize {
+        self.inner.max_iter
+    }
+
+    /// Sets the maximum number of iterations used by the [`cgst`](MultilargeNlinearTrs::cgst)
+    /// method to solve the trust region subproblem.
+    pub fn set_max_iter(&mut self, max_iter: usize) -> &mut MultilargeNlinearParameters {
+        self.inner.max_iter = max_iter;
+        self
+    }
+
+    /// Returns the tolerance used by the [`cgst`](MultilargeNlinearTrs::cgst) method.
+    pub fn tol(&self) -> f64 {
+        self.inner.tol
+    }
+
+    /// Sets the tolerance used by the [`cgst`](MultilargeNlinearTrs::cgst) method to solve the
+    /// trust region subproblem.
+    pub fn set_tol(&mut self, tol: f64) -> &mut MultilargeNlinearParameters {
+        self.inner.tol = tol;
+        self
+    }
+}
+
+/// Definition of the large-scale nonlinear least squares problem: n residual functions in p
+/// parameters, with optional Jacobian products and optional second directional derivative.
+pub struct MultilargeNlinearFdf<'a> {
+    f: Box<dyn FnMut(&VectorF64, &mut VectorF64) -> Value + 'a>,
+    #[allow(clippy::type_complexity)]
+    df: Option<
+        Box<
+            dyn FnMut(
+                    CblasTranspose,
+                    &VectorF64,
+                    &VectorF64,
+                    &mut VectorF64,
+                    Option<&mut MatrixF64>,
+                ) -> Value
+                + 'a,
+        >,
+    >,
+    fvv: Option<Box<dyn FnMut(&VectorF64, &VectorF64, &mut VectorF64) -> Value + 'a>>,
+    intern: sys::gsl_multilarge_nlinear_fdf,
+}
+
+impl<'a> MultilargeNlinearFdf<'a> {
+    /// `n` is the number of residuals, `p` the number of parameters. `f` must store the n
+    /// residuals f_i(x) in its second argument.
+    #[doc(alias = "gsl_multilarge_nlinear_fdf")]
+    pub fn new<F: FnMut(&VectorF64, &mut VectorF64) -> Value + 'a>(
+        n: usize,
+        p: usize,
+        f: F,
+    ) -> MultilargeNlinearFdf<'a> {
+        MultilargeNlinearFdf {
+            f: Box::new(f),
+            df: None,
+            fvv: None,
+            intern: sys::gsl_multilarge_nlinear_fdf {
+                f: Some(large_f),
+                df: None,
+                fvv: None,
+                n,
+                p,
+                params: ::std::ptr::null_mut(),
+                nevalf: 0,
+                nevaldfu: 0,
+                nevaldf2: 0,
+                nevalfvv: 0,
+            },
+        }
+    }
+
+    /// Sets the function computing the Jacobian products. It is called as
+    /// `df(trans, x, u, v, JTJ)`:
+    ///
+    /// * if `trans` is `CblasTranspose::NoTranspose`, it must store J u in `v` (`u` has length p
+    ///   and `v` length n);
+    /// * if `trans` is `CblasTranspose::Transpose`, it must store J^T u in `v` (`u` has length n
+    ///   and `v` length p).
+    ///
+    /// When `JTJ` is `Some`, the p-by-p matrix J^T J must also be stored in it. It is only
+    /// requested by the solvers which factorize the normal equations.
+    ///
+    /// If not set, the products are approximated with finite differences.
+    pub fn df<
+        DF: FnMut(
+                CblasTranspose,
+                &VectorF64,
+                &VectorF64,
+                &mut VectorF64,
+                Option<&mut MatrixF64>,
+            ) -> Value
+            + 'a,
+    >(
+        mut self,
+        df: DF,
+    ) -> MultilargeNlinearFdf<'a> {
+        self.df = Some(Box::new(df));
+        self.intern.df = Some(large_df);
+        self
+    }
+
+    /// Sets the function computing the second directional derivative of the residuals, used only
+    /// by the geodesic acceleration method. If not set, it is approximated with finite
+    /// differences.
+    pub fn fvv<FVV: FnMut(&VectorF64, &VectorF64, &mut VectorF64) -> Value + 'a>(
+        mut self,
+        fvv: FVV,
+    ) -> MultilargeNlinearFdf<'a> {
+        self.fvv = Some(Box::new(fvv));
+        self.intern.fvv = Some(large_fvv);
+        self
+    }
+
+    /// Returns the number of residuals.
+    pub fn n(&self) -> usize {
+        self.intern.n
+    }
+
+    /// Returns the number of parameters.
+    pub fn p(&self) -> usize {
+        self.intern.p
+    }
+
+    /// Returns the number of function evaluations performed so far.
+    pub fn nevalf(&self) -> usize {
+        self.intern.nevalf
+    }
+
+    /// Returns the number of J u and J^T u evaluations performed so far.
+    pub fn nevaldfu(&self) -> usize {
+        self.intern.nevaldfu
+    }
+
+    /// Returns the number of J^T J evaluations performed so far.
+    pub fn nevaldf2(&self) -> usize {
+        self.intern.nevaldf2
+    }
+
+    /// Returns the number of `fvv` evaluations performed so far.
+    pub fn nevalfvv(&self) -> usize {
+        self.intern.nevalfvv
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    fn to_raw(&mut self) -> *mut sys::gsl_multilarge_nlinear_fdf {
+        self.intern.params = self as *mut MultilargeNlinearFdf as *mut c_void;
+        &mut self.intern
+    }
+}
+
+unsafe extern "C" fn large_f(
+    x: *const sys::gsl_vector,
+    params: *mut c_void,
+    f: *mut sys::gsl_vector,
+) -> c_int {
+    let fdf = &mut *(params as *mut MultilargeNlinearFdf);
+    (fdf.f)(
+        &VectorF64::soft_wrap(x as *mut _),
+        &mut VectorF64::soft_wrap(f),
+    )
+    .into()
+}
+
+#[allow(non_snake_case)]
+unsafe extern "C" fn large_df(
+    TransJ: sys::CBLAS_TRANSPOSE_t,
+    x: *const sys::gsl_vector,
+    u: *const sys::gsl_vector,
+    params: *mut c_void,
+    v: *mut sys::gsl_vector,
+    JTJ: *mut sys::gsl_matrix,
+) -> c_int {
+    let fdf = &mut *(params as *mut MultilargeNlinearFdf);
+    let mut jtj = if JTJ.is_null() {
+        None
+    } else {
+        Some(MatrixF64::soft_wrap(JTJ))
+    };
+    match fdf.df {
+        Some(ref mut df_f) => df_f(
+            CblasTranspose::from(TransJ),
+            &VectorF64::soft_wrap(x as *mut _),
+            &VectorF64::soft_wrap(u as *mut _),
+            &mut VectorF64::soft_wrap(v),
+            jtj.as_mut(),
+        ),
+        None => Value::BadFunction,
+    }
+    .into()
+}
+
+unsafe extern "C" fn large_fvv(
+    x: *const sys::gsl_vector,
+    v: *const sys::gsl_vector,
+    params: *mut c_void,
+    fvv: *mut sys::gsl_vector,
+) -> c_int {
+    let fdf = &mut *(params as *mut MultilargeNlinearFdf);
+    match fdf.fvv {
+        Some(ref mut fvv_f) => fvv_f(
+            &VectorF64::soft_wrap(x as *mut _),
+            &VectorF64::soft_wrap(v as *mut _),
+            &mut VectorF64::soft_wrap(fvv),
+        ),
+        None => Value::BadFunction,
+    }
+    .into()
+}
+
+ffi_wrapper!(
+    MultilargeNlinearWorkspace<'a>,
+    *mut sys::gsl_multilarge_nlinear_workspace,
+    gsl_multilarge_nlinear_free
+    ;fdf: Option<Box<MultilargeNlinearFdf<'a>>> => None;,
+    "This is a workspace for large-scale nonlinear least squares fitting using trust region methods."
+);
+
+impl<'a> MultilargeNlinearWorkspace<'a> {
+    /// This function returns a pointer to a newly allocated instance of a solver of type `T` for
+    /// `n` observations and `p` parameters. The `params` input specifies a tunable set of
+    /// parameters which will affect important details in each iteration of the trust region
+    /// subproblem algorithm.
+    #[doc(alias = "gsl_multilarge_nlinear_alloc")]
+    pub fn new(
+        t: &MultilargeNlinearType,
+        params: &MultilargeNlinearParameters,
+        n: usize,
+        p: usize,
+    ) -> Option<MultilargeNlinearWorkspace<'a>> {
+        let ptr =
+            unsafe { sys::gsl_multilarge_nlinear_alloc(t.unwrap_shared(), &params.inner, n, p) };
+
+        if ptr.is_null() {
+            None
+        } else {
+            Some(MultilargeNlinearWorkspace::wrap(ptr))
+        }
+    }
+
+    /// This function initializes, or reinitializes, an existing workspace `w` to use the system
+    /// `fdf` and the initial guess `x`.
+    #[doc(alias = "gsl_multilarge_nlinear_init")]
+    pub fn init(&mut self, x: &VectorF64, fdf: MultilargeNlinearFdf<'a>) -> Value {
+        let mut fdf = Box::new(fdf);
+        let raw = fdf.to_raw();
+        self.fdf = Some(fdf);
+
+        Value::from(unsafe {
+            sys::gsl_multilarge_nlinear_init(x.unwrap_shared(), raw, self.unwrap_unique())
+        })
+    }
+
+    /// This function is the same as [`init`](Self::init) but it also sets the weighting
+    /// matrix to W = diag(w_1, ..., w_n) using the vector `wts`.
+    #[doc(alias = "gsl_multilarge_nlinear_winit")]
+    pub fn winit(
+        &mut self,
+        x: &VectorF64,
+        wts: &VectorF64,
+        fdf: MultilargeNlinearFdf<'a>,
+    ) -> Value {
+        let mut fdf = Box::new(fdf);
+        let raw = fdf.to_raw();
+        self.fdf = Some(fdf);
+
+        Value::from(unsafe {
+            sys::gsl_multilarge_nlinear_winit(
+                x.unwrap_shared(),
+                wts.unwrap_shared(),
+                raw,
+                self.unwrap_unique(),
+            )
+        })
+    }
+
+    /// Returns the system given to [`init`](Self::init) or [`winit`](Self::winit), which can be
+    /// used to query the number of function evaluations.
+    pub fn fdf(&self) -> Option<&MultilargeNlinearFdf<'a>> {
+        self.fdf.as_deref()
+    }
+
+    /// This function performs a single iteration of the solver `w`.
+    #[doc(alias = "gsl_multilarge_nlinear_iterate")]
+    pub fn iterate(&mut self) -> Value {
+        Value::from(unsafe { sys::gsl_multilarge_nlinear_iterate(self.unwrap_unique()) })
+    }
+
+    /// This function returns the current ratio |a|/|v| of the acceleration correction term to
+    /// the velocity step term.
+    #[doc(alias = "gsl_multilarge_nlinear_avratio")]
+    pub fn avratio(&self) -> f64 {
+        unsafe { sys::gsl_multilarge_nlinear_avratio(self.unwrap_shared()) }
+    }
+
+    /// This function iterates the solver `w` for a maximum of `maxiter` iterations, testing for
+    /// convergence after each iteration with the tolerances `xtol`, `gtol` and `ftol` (see
+    /// [`test`](Self::test)).
+    ///
+    /// Returns `(Value, info)`, where `info` is the reason for convergence.
+    #[doc(alias = "gsl_multilarge_nlinear_driver")]
+    pub fn driver(&mut self, maxiter: usize, xtol: f64, gtol: f64, ftol: f64) -> (Value, i32) {
+        let mut info = 0;
+        let ret = unsafe {
+            sys::gsl_multilarge_nlinear_driver(
+                maxiter,
+                xtol,
+                gtol,
+                ftol,
+                None,
+                ::std::ptr::null_mut(),
+                &mut info,
+                self.unwrap_unique(),
+            )
+        };
+        (Value::from(ret), info)
+    }
+
+    /// This function is the same as [`driver`](Self::driver) but it also calls `callback` after
+    /// each iteration with the current iteration number, the current position x and the current
+    /// residual vector f(x).
+    // checker:ignore
+    #[doc(alias = "gsl_multilarge_nlinear_driver")]
+    pub fn driver_with_callback<F: FnMut(usize, &VectorF64, &VectorF64)>(
+        &mut self,
+        maxiter: usize,
+        xtol: f64,
+        gtol: f64,
+        ftol: f64,
+        callback: F,
+    ) -> (Value, i32) {
+        unsafe extern "C" fn inner_callback<F: FnMut(usize, &VectorF64, &VectorF64)>(
+            iter: usize,
+            params: *mut c_void,
+            w: *const sys::gsl_multilarge_nlinear_workspace,
+        ) {
+            let f = &mut *(params as *mut F);
+            let x = VectorF64::soft_wrap(sys::gsl_multilarge_nlinear_position(w));
+            let r = VectorF64::soft_wrap(sys::gsl_multilarge_nlinear_residual(w));
+            f(iter, &x, &r);
+        }
+
+        let mut callback = callback;
+        let mut info = 0;
+        let ret = unsafe {
+            sys::gsl_multilarge_nlinear_driver(
+                maxiter,
+                xtol,
+                gtol,
+                ftol,
+                Some(inner_callback::<F>),
+                &mut callback as *mut F as *mut c_void,
+                &mut info,
+                self.unwrap_unique(),
+            )
+        };
+        (Value::from(ret), info)
+    }
+
+    /// Returns the solver type name.
+    #[doc(alias = "gsl_multilarge_nlinear_name")]
+    pub fn name(&self) -> String {
+        unsafe {
+            let tmp = sys::gsl_multilarge_nlinear_name(self.unwrap_shared());
+
+            String::from_utf8_lossy(::std::ffi::CStr::from_ptr(tmp).to_bytes()).to_string()
+        }
+    }
+
+    /// Returns the name of the trust region subproblem method.
+    #[doc(alias = "gsl_multilarge_nlinear_trs_name")]
+    pub fn trs_name(&self) -> String {
+        unsafe {
+            let tmp = sys::gsl_multilarge_nlinear_trs_name(self.unwrap_shared());
+
+            String::from_utf8_lossy(::std::ffi::CStr::from_ptr(tmp).to_bytes()).to_string()
+        }
+    }
+
+    /// This function returns the current position x (i.e. best-fit parameters) of the solver
+    /// `w`.
+    #[doc(alias = "gsl_multilarge_nlinear_position")]
+    pub fn position(&self) -> VectorF64 {
+        VectorF64::soft_wrap(unsafe { sys::gsl_multilarge_nlinear_position(self.unwrap_shared()) })
+    }
+
+    /// This function returns the current residual vector f(x) of the solver `w`.
+    #[doc(alias = "gsl_multilarge_nlinear_residual")]
+    pub fn residual(&self) -> VectorF64 {
+        VectorF64::soft_wrap(unsafe { sys::gsl_multilarge_nlinear_residual(self.unwrap_shared()) })
+    }
+
+    /// This function returns the last step taken by the solver `w`.
+    #[doc(alias = "gsl_multilarge_nlinear_step")]
+    pub fn step(&self) -> VectorF64 {
+        VectorF64::soft_wrap(unsafe { sys::gsl_multilarge_nlinear_step(self.unwrap_shared()) })
+    }
+
+    /// This function returns the number of iterations performed by the solver `w`.
+    #[doc(alias = "gsl_multilarge_nlinear_niter")]
+    pub fn niter(&self) -> usize {
+        unsafe { sys::gsl_multilarge_nlinear_niter(self.unwrap_shared()) }
+    }
+
+    /// This function estimates the reciprocal condition number of the Jacobian matrix at the
+    /// current position x. It requires a solver which forms J^T J.
+    ///
+    /// Returns `(Value, rcond)`.
+    #[doc(alias = "gsl_multilarge_nlinear_rcond")]
+    pub fn rcond(&self) -> (Value, f64) {
+        let mut rcond = 0.;
+        let ret = unsafe { sys::gsl_multilarge_nlinear_rcond(&mut rcond, self.unwrap_shared()) };
+        (Value::from(ret), rcond)
+    }
+
+    /// This function tests for convergence of the solver `w` at the current iteration, with the
+    /// same criteria as [`MultiFitNlinearWorkspace::test`](crate::MultiFitNlinearWorkspace::test).
+    ///
+    /// Returns `(Value, info)`.
+    #[doc(alias = "gsl_multilarge_nlinear_test")]
+    pub fn test(&self, xtol: f64, gtol: f64, ftol: f64) -> (Value, i32) {
+        let mut info = 0;
+        let ret = unsafe {
+            sys::gsl_multilarge_nlinear_test(xtol, gtol, ftol, &mut info, self.unwrap_shared())
+        };
+        (Value::from(ret), info)
+    }
+
+    /// This function computes the covariance matrix `C = (J^T J)^{-1}` of the best-fit
+    /// parameters at the current position, storing it in `covar`. It requires a solver which
+    /// forms J^T J.
+    #[doc(alias = "gsl_multilarge_nlinear_covar")]
+    pub fn covar(&mut self, covar: &mut MatrixF64) -> Value {
+        Value::from(unsafe {
+            sys::gsl_multilarge_nlinear_covar(covar.unwrap_unique(), self.unwrap_unique())
+        })
+    }
+}
+
+#[cfg(any(test, doctest))]
+mod tests {
+    use super::*;
+
+    // Fit y = A * exp(-lambda * t) + b on exact data, providing only the Jacobian products.
+    #[test]
+    fn test_multilarge_nlinear() {
+        let n = 40;
+        let ts: Vec<f64> = (0..n).map(|i| i as f64 * 3.0 / (n - 1) as f64).collect();
+        let ys: Vec<f64> = ts.iter().map(|t| 5.0 * (-1.5 * t).exp() + 1.0).collect();
+
+        let jacobian = |x: &VectorF64| {
+            let mut j = MatrixF64::new(n, 3).unwrap();
+            for (i, t) in ts.iter().enumerate() {
+                let e = (-x.get(1) * t).exp();
+                j.set(i, 0, e);
+                j.set(i, 1, -t * x.get(0) * e);
+                j.set(i, 2, 1.0);
+            }
+            j
+        };
+
+        let fdf = MultilargeNlinearFdf::new(n, 3, |x, f| {
+            for (i, (t, y)) in ts.iter().zip(ys.iter()).enumerate() {
+                f.set(i, x.get(0) * (-x.get(1) * t).exp() + x.get(2) - y);
+            }
+            Value::Success
+        })
+        .df(|trans, x, u, v, jtj| {
+            let j = jacobian(x);
+            let (rows, cols) = match trans {
+                CblasTranspose::NoTranspose => (n, 3),
+                _ => (3, n),
+            };
+            for r in 0..rows {
+                let mut sum = 0.;
+                for c in 0..cols {
+                    sum += match trans {
+                        CblasTranspose::NoTranspose => j.get(r, c),
+                        _ => j.get(c, r),
+                    } * u.get(c);
+                }
+                v.set(r, sum);
+            }
+            if let Some(jtj) = jtj {
+                for a in 0..3 {
+                    for b in 0..3 {
+                        jtj.set(a, b, (0..n).map(|i| j.get(i, a) * j.get(i, b)).sum());
+                    }
+                }
+            }
+            Value::Success
+        });
+
+        let params = MultilargeNlinearParameters::new();
+        let mut w = MultilargeNlinearWorkspace::new(&MultilargeNlinearType::trust(), &params, n, 3)
+            .unwrap();
+        let x = VectorF64::from_slice(&[1.0, 1.0, 0.0]).unwrap();
+        w.init(&x, fdf);
+
+        let mut iterations = 0;
+        let (status, _info) =
+            w.driver_with_callback(200, 1e-8, 1e-8, 1e-8, |_iter, _x, _f| iterations += 1);
+        assert_eq!(status, Value::Success);
+        assert!(iterations > 0);
+        assert!(w.fdf().unwrap().nevaldfu() > 0);
+
+        let x = w.position();
+        assert!((x.get(0) - 5.0).abs() < 1e-6);
+        assert!((x.get(1) - 1.5).abs() < 1e-6);
+        assert!((x.get(2) - 1.0).abs() < 1e-6);
+
+        let mut covar = MatrixF64::new(3, 3).unwrap();
+        assert_eq!(w.covar(&mut covar), Value::Success);
+    }
+}
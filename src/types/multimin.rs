@@ -0,0 +1,572 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+# Multidimensional Minimization
+
+This chapter describes routines for finding minima of arbitrary multidimensional functions. The
+library provides low level components for a variety of iterative minimizers and convergence tests.
+These can be combined by the user to achieve the desired solution, while providing full access to
+the intermediate steps of the algorithms. Each class of methods uses the same framework, so that
+you can switch between minimizers at runtime without needing to recompile your program. Each
+instance of a minimizer keeps track of its own state, allowing the minimizers to be used in
+multi-threaded programs. The minimization algorithms can be used to maximize a function by
+inverting its sign.
+
+## Overview
+
+The problem of multidimensional minimization requires finding a point x such that the scalar
+function,
+
+```text
+f(x_1, \dots, x_n)
+```
+
+takes a value which is lower than at any neighboring point. For smooth functions the gradient
+g = \nabla f vanishes at the minimum. In general there are no bracketing methods available for the
+minimization of n-dimensional functions. The algorithms proceed from an initial guess using a
+search algorithm which attempts to move in a downhill direction.
+
+Algorithms making use of the gradient of the function perform a one-dimensional line minimisation
+along this direction until the lowest point is found to a suitable tolerance. The search direction
+is then updated with local information from the function and its derivatives, and the whole process
+repeated until the true n-dimensional minimum is found.
+
+Algorithms which do not require the gradient of the function use different strategies. For example,
+the Nelder-Mead Simplex algorithm maintains n+1 trial parameter vectors as the vertices of a
+n-dimensional simplex. On each iteration it tries to improve the worst vertex of the simplex by
+geometrical transformations. The iterations are continued until the overall size of the simplex has
+decreased sufficiently.
+
+Both types of algorithms use a standard framework. The user provides a high-level driver for the
+algorithms, and the library provides the individual functions necessary for each of the steps. There
+are three main phases of the iteration. The steps are,
+
+- initialize minimizer state, `s`, for algorithm `T`
+- update `s` using the iteration `T`
+- test `s` for convergence, and repeat iteration if necessary
+
+The state for the minimizers which use the gradient is held in a `gsl_multimin_fdfminimizer`
+struct. The state for the minimizers which do not use the gradient is held in a
+`gsl_multimin_fminimizer` struct.
+
+## Caveats
+
+Note that the minimization algorithms can only search for one local minimum at a time. When there
+are several local minima in the search area, the first minimum to be found will be returned;
+however it is difficult to predict which of the minima this will be. In most cases, no error will
+be reported if you try to find a local minimum in an area where there is more than one.
+
+It is also important to note that the minimization algorithms find local minima; there is no way to
+determine whether a minimum is a global minimum of the function in question.
+!*/
+
+use ffi::FFI;
+use std::os::raw::c_void;
+use sys;
+
+ffi_wrapper!(
+    MultiMinFMinimizerType,
+    *const sys::gsl_multimin_fminimizer_type,
+    "The minimization algorithms described in this section use only the value of the function at
+    each evaluation point."
+);
+
+impl MultiMinFMinimizerType {
+    /// This is the Simplex algorithm of Nelder and Mead. It constructs n vectors p_i from the
+    /// starting vector x and the vector step_size as follows:
+    ///
+    /// ```text
+    /// p_0 = (x_0, x_1, ... , x_n)
+    /// p_1 = (x_0 + step_size_0, x_1, ... , x_n)
+    /// p_2 = (x_0, x_1 + step_size_1, ... , x_n)
+    /// ... = ...
+    /// p_n = (x_0, x_1, ... , x_n + step_size_n)
+    /// ```
+    ///
+    /// These vectors form the n+1 vertices of a simplex in n dimensions. On each iteration the
+    /// algorithm uses simple geometrical transformations to update the vector corresponding to
+    /// the highest function value. The geometric transformations are reflection, reflection
+    /// followed by expansion, contraction and multiple contraction. Using these transformations
+    /// the simplex moves through the space towards the minimum, where it contracts itself.
+    ///
+    /// After each iteration, the best vertex is returned. Note, that due to the nature of the
+    /// algorithm not every step improves the current best parameter vector. Usually several
+    /// iterations are required.
+    #[doc(alias = "gsl_multimin_fminimizer_nmsimplex")]
+    pub fn nmsimplex() -> MultiMinFMinimizerType {
+        ffi_wrap!(gsl_multimin_fminimizer_nmsimplex)
+    }
+
+    /// This is a variant of [`nmsimplex`](Self::nmsimplex) which is more efficient: the
+    /// size of the simplex is calculated as the RMS distance of each vertex from the center
+    /// rather than the mean distance, allowing a linear update of this quantity on each step. The
+    /// memory usage is O(n) for both algorithms.
+    #[doc(alias = "gsl_multimin_fminimizer_nmsimplex2")]
+    pub fn nmsimplex2() -> MultiMinFMinimizerType {
+        ffi_wrap!(gsl_multimin_fminimizer_nmsimplex2)
+    }
+
+    /// This is a variant of [`nmsimplex2`](Self::nmsimplex2) which initialises the simplex
+    /// around the starting point x using a randomly-oriented set of basis vectors instead of the
+    /// fixed coordinate axes. The final dimensions of the simplex are scaled along the coordinate
+    /// axes by the vector step_size. The randomisation uses a simple deterministic generator so
+    /// that repeated calls to `set` for a given solver object will vary the orientation in a
+    /// well-defined way.
+    #[doc(alias = "gsl_multimin_fminimizer_nmsimplex2rand")]
+    pub fn nmsimplex2rand() -> MultiMinFMinimizerType {
+        ffi_wrap!(gsl_multimin_fminimizer_nmsimplex2rand)
+    }
+}
+
+ffi_wrapper!(
+    MultiMinFMinimizer<'a>,
+    *mut sys::gsl_multimin_fminimizer,
+    gsl_multimin_fminimizer_free
+    ;inner_call: sys::gsl_multimin_function_struct => sys::gsl_multimin_function_struct { f: None, n: 0, params: std::ptr::null_mut() };
+    ;inner_closure: Option<Box<dyn FnMut(&::VectorF64) -> f64 + 'a>> => None;,
+    "This is a workspace for multidimensional minimization without derivatives."
+);
+
+impl<'a> MultiMinFMinimizer<'a> {
+    /// This function returns a pointer to a newly allocated instance of a minimizer of type `T`
+    /// for an `n`-dimension function.
+    ///
+    /// If there is insufficient memory to create the minimizer then the function returns a null
+    /// pointer and the error handler is invoked with an error code of `Value::NoMemory`.
+    #[doc(alias = "gsl_multimin_fminimizer_alloc")]
+    pub fn new(t: &MultiMinFMinimizerType, n: usize) -> Option<MultiMinFMinimizer<'a>> {
+        let ptr = unsafe { sys::gsl_multimin_fminimizer_alloc(t.unwrap_shared(), n) };
+
+        if ptr.is_null() {
+            None
+        } else {
+            Some(MultiMinFMinimizer::wrap(ptr))
+        }
+    }
+
+    /// This function initializes the minimizer `s` to minimize the function `f`, starting from
+    /// the initial point `x`. The size of the initial trial steps is given in vector
+    /// `step_size`. The precise meaning of this parameter depends on the method used.
+    #[doc(alias = "gsl_multimin_fminimizer_set")]
+    pub fn set<F: FnMut(&::VectorF64) -> f64 + 'a>(
+        &mut self,
+        f: F,
+        x: &::VectorF64,
+        step_size: &::VectorF64,
+    ) -> ::Value {
+        unsafe extern "C" fn inner_f<F: FnMut(&::VectorF64) -> f64>(
+            x: *const sys::gsl_vector,
+            params: *mut c_void,
+        ) -> f64 {
+            let f: &mut F = &mut *(params as *mut F);
+            f(&::VectorF64::soft_wrap(x as *mut _))
+        }
+
+        let mut f = Box::new(f);
+        self.inner_call = sys::gsl_multimin_function_struct {
+            f: Some(inner_f::<F>),
+            n: x.len(),
+            params: &mut *f as *mut F as *mut c_void,
+        };
+        self.inner_closure = Some(f);
+
+        ::Value::from(unsafe {
+            sys::gsl_multimin_fminimizer_set(
+                self.unwrap_unique(),
+                &mut self.inner_call,
+                x.unwrap_shared(),
+                step_size.unwrap_shared(),
+            )
+        })
+    }
+
+    /// Returns the minimizer type name.
+    #[doc(alias = "gsl_multimin_fminimizer_name")]
+    pub fn name(&self) -> String {
+        unsafe {
+            let tmp = sys::gsl_multimin_fminimizer_name(self.unwrap_shared());
+
+            String::from_utf8_lossy(::std::ffi::CStr::from_ptr(tmp).to_bytes()).to_string()
+        }
+    }
+
+    /// This function performs a single iteration of the minimizer `s`. If the iteration
+    /// encounters an unexpected problem then an error code will be returned. The error code
+    /// `Value::NoProgress` signifies that the minimizer is unable to improve on its current
+    /// estimate, either due to numerical difficulty or because a genuine local minimum has been
+    /// reached.
+    #[doc(alias = "gsl_multimin_fminimizer_iterate")]
+    pub fn iterate(&mut self) -> ::Value {
+        ::Value::from(unsafe { sys::gsl_multimin_fminimizer_iterate(self.unwrap_unique()) })
+    }
+
+    /// This function returns the current best estimate of the location of the minimum.
+    #[doc(alias = "gsl_multimin_fminimizer_x")]
+    pub fn x(&self) -> ::VectorF64 {
+        ::VectorF64::soft_wrap(unsafe { sys::gsl_multimin_fminimizer_x(self.unwrap_shared()) })
+    }
+
+    /// This function returns the value of the function at the current best estimate of the
+    /// location of the minimum.
+    #[doc(alias = "gsl_multimin_fminimizer_minimum")]
+    pub fn minimum(&self) -> f64 {
+        unsafe { sys::gsl_multimin_fminimizer_minimum(self.unwrap_shared()) }
+    }
+
+    /// This function returns the minimizer-specific characteristic size of the current best
+    /// estimate, to be used with [`test_size`](crate::multimin::test_size).
+    #[doc(alias = "gsl_multimin_fminimizer_size")]
+    pub fn size(&self) -> f64 {
+        unsafe { sys::gsl_multimin_fminimizer_size(self.unwrap_shared()) }
+    }
+}
+
+ffi_wrapper!(
+    MultiMinFdfMinimizerType,
+    *const sys::gsl_multimin_fdfminimizer_type,
+    "The minimization algorithms described in this section make use of both the value of the
+    function and its gradient. They differ in how the search direction is chosen for the line
+    minimization."
+);
+
+impl MultiMinFdfMinimizerType {
+    /// This is the Fletcher-Reeves conjugate gradient algorithm. The conjugate gradient algorithm
+    /// proceeds as a succession of line minimizations. The sequence of search directions is used
+    /// to build up an approximation to the curvature of the function in the neighborhood of the
+    /// minimum.
+    ///
+    /// An initial search direction p is chosen using the gradient, and line minimization is
+    /// carried out in that direction. The accuracy of the line minimization is specified by the
+    /// parameter `tol`. At the minimum along this line the function gradient g and the search
+    /// direction p are orthogonal. The search direction is then updated and the process repeated.
+    #[doc(alias = "gsl_multimin_fdfminimizer_conjugate_fr")]
+    pub fn conjugate_fr() -> MultiMinFdfMinimizerType {
+        ffi_wrap!(gsl_multimin_fdfminimizer_conjugate_fr)
+    }
+
+    /// This is the Polak-Ribiere conjugate gradient algorithm. It is similar to the
+    /// Fletcher-Reeves method, differing only in the choice of the coefficient beta. Both methods
+    /// work well when the evaluation point is close enough to the minimum of the objective
+    /// function that it is well approximated by a quadratic hypersurface.
+    #[doc(alias = "gsl_multimin_fdfminimizer_conjugate_pr")]
+    pub fn conjugate_pr() -> MultiMinFdfMinimizerType {
+        ffi_wrap!(gsl_multimin_fdfminimizer_conjugate_pr)
+    }
+
+    /// This is the vector Broyden-Fletcher-Goldfarb-Shanno (BFGS) conjugate gradient algorithm.
+    /// It is a quasi-Newton method which builds up an approximation to the second derivatives of
+    /// the function f using the difference between successive gradient vectors.
+    #[doc(alias = "gsl_multimin_fdfminimizer_vector_bfgs")]
+    pub fn vector_bfgs() -> MultiMinFdfMinimizerType {
+        ffi_wrap!(gsl_multimin_fdfminimizer_vector_bfgs)
+    }
+
+    /// This is a more efficient version of the BFGS algorithm which implements the line
+    /// minimization of Fletcher. It is the most efficient algorithm available and is recommended
+    /// for general use. The parameter `tol` is used as the `sigma` Wolfe condition for the line
+    /// minimization, a value of `0.1` is recommended.
+    #[doc(alias = "gsl_multimin_fdfminimizer_vector_bfgs2")]
+    pub fn vector_bfgs2() -> MultiMinFdfMinimizerType {
+        ffi_wrap!(gsl_multimin_fdfminimizer_vector_bfgs2)
+    }
+
+    /// The steepest descent algorithm follows the downhill gradient of the function at each step.
+    /// When a downhill step is successful the step-size is increased by a factor of two. If the
+    /// downhill step leads to a higher function value then the algorithm backtracks and the step
+    /// size is decreased using the parameter `tol`. A suitable value of `tol` for most
+    /// applications is 0.1. The steepest descent method is inefficient and is included only for
+    /// demonstration purposes.
+    #[doc(alias = "gsl_multimin_fdfminimizer_steepest_descent")]
+    pub fn steepest_descent() -> MultiMinFdfMinimizerType {
+        ffi_wrap!(gsl_multimin_fdfminimizer_steepest_descent)
+    }
+}
+
+struct MultiMinFdfClosures<'a> {
+    f: Box<dyn FnMut(&::VectorF64) -> f64 + 'a>,
+    df: Box<dyn FnMut(&::VectorF64, &mut ::VectorF64) + 'a>,
+    fdf: Box<dyn FnMut(&::VectorF64, &mut f64, &mut ::VectorF64) + 'a>,
+}
+
+ffi_wrapper!(
+    MultiMinFdfMinimizer<'a>,
+    *mut sys::gsl_multimin_fdfminimizer,
+    gsl_multimin_fdfminimizer_free
+    ;inner_call: sys::gsl_multimin_function_fdf_struct => sys::gsl_multimin_function_fdf_struct { f: None, df: None, fdf: None, n: 0, params: std::ptr::null_mut() };
+    ;inner_closures: Option<Box<MultiMinFdfClosures<'a>>> => None;,
+    "This is a workspace for multidimensional minimization using the gradient of the function."
+);
+
+impl<'a> MultiMinFdfMinimizer<'a> {
+    /// This function returns a pointer to a newly allocated instance of a minimizer of type `T`
+    /// for an `n`-dimension function.
+    ///
+    /// If there is insufficient memory to create the minimizer then the function returns a null
+    /// pointer and the error handler is invoked with an error code of `Value::NoMemory`.
+    #[doc(alias = "gsl_multimin_fdfminimizer_alloc")]
+    pub fn new(t: &MultiMinFdfMinimizerType, n: usize) -> Option<MultiMinFdfMinimizer<'a>> {
+        let ptr = unsafe { sys::gsl_multimin_fdfminimizer_alloc(t.unwrap_shared(), n) };
+
+        if ptr.is_null() {
+            None
+        } else {
+            Some(MultiMinFdfMinimizer::wrap(ptr))
+        }
+    }
+
+    /// This function initializes the minimizer `s` to minimize the function `f`, with gradient
+    /// `df`, starting from the initial point `x`. `fdf` must compute both the function value and
+    /// its gradient at the same point. The size of the first trial step is given by `step_size`.
+    /// The accuracy of the line minimization is specified by `tol`. The precise meaning of this
+    /// parameter depends on the method used. Typically the line minimization is considered
+    /// successful if the gradient of the function g is orthogonal to the current search direction
+    /// p to a relative accuracy of `tol`, where `dot(p, g) < tol |p| |g|`. A `tol` value of 0.1 is
+    /// suitable for most purposes, since line minimization only needs to be carried out
+    /// approximately.
+    #[doc(alias = "gsl_multimin_fdfminimizer_set")]
+    pub fn set<
+        F: FnMut(&::VectorF64) -> f64 + 'a,
+        DF: FnMut(&::VectorF64, &mut ::VectorF64) + 'a,
+        FDF: FnMut(&::VectorF64, &mut f64, &mut ::VectorF64) + 'a,
+    >(
+        &mut self,
+        f: F,
+        df: DF,
+        fdf: FDF,
+        x: &::VectorF64,
+        step_size: f64,
+        tol: f64,
+    ) -> ::Value {
+        unsafe extern "C" fn inner_f(x: *const sys::gsl_vector, params: *mut c_void) -> f64 {
+            let closures = &mut *(params as *mut MultiMinFdfClosures);
+            (closures.f)(&::VectorF64::soft_wrap(x as *mut _))
+        }
+
+        unsafe extern "C" fn inner_df(
+            x: *const sys::gsl_vector,
+            params: *mut c_void,
+            df: *mut sys::gsl_vector,
+        ) {
+            let closures = &mut *(params as *mut MultiMinFdfClosures);
+            (closures.df)(
+                &::VectorF64::soft_wrap(x as *mut _),
+                &mut ::VectorF64::soft_wrap(df),
+            );
+        }
+
+        unsafe extern "C" fn inner_fdf(
+            x: *const sys::gsl_vector,
+            params: *mut c_void,
+            f: *mut f64,
+            df: *mut sys::gsl_vector,
+        ) {
+            let closures = &mut *(params as *mut MultiMinFdfClosures);
+            (closures.fdf)(
+                &::VectorF64::soft_wrap(x as *mut _),
+                &mut *f,
+                &mut ::VectorF64::soft_wrap(df),
+            );
+        }
+
+        let mut closures = Box::new(MultiMinFdfClosures {
+            f: Box::new(f),
+            df: Box::new(df),
+            fdf: Box::new(fdf),
+        });
+        self.inner_call = sys::gsl_multimin_function_fdf_struct {
+            f: Some(inner_f),
+            df: Some(inner_df),
+            fdf: Some(inner_fdf),
+            n: x.len(),
+            params: &mut *closures as *mut MultiMinFdfClosures as *mut c_void,
+        };
+        self.inner_closures = Some(closures);
+
+        ::Value::from(unsafe {
+            sys::gsl_multimin_fdfminimizer_set(
+                self.unwrap_unique(),
+                &mut self.inner_call,
+                x.unwrap_shared(),
+                step_size,
+                tol,
+            )
+        })
+    }
+
+    /// Returns the minimizer type name.
+    #[doc(alias = "gsl_multimin_fdfminimizer_name")]
+    pub fn name(&self) -> String {
+        unsafe {
+            let tmp = sys::gsl_multimin_fdfminimizer_name(self.unwrap_shared());
+
+            String::from_utf8_lossy(::std::ffi::CStr::from_ptr(tmp).to_bytes()).to_string()
+        }
+    }
+
+    /// This function performs a single iteration of the minimizer `s`. If the iteration
+    /// encounters an unexpected problem then an error code will be returned. The error code
+    /// `Value::NoProgress` signifies that the minimizer is unable to improve on its current
+    /// estimate, either due to numerical difficulty or because a genuine local minimum has been
+    /// reached.
+    #[doc(alias = "gsl_multimin_fdfminimizer_iterate")]
+    pub fn iterate(&mut self) -> ::Value {
+        ::Value::from(unsafe { sys::gsl_multimin_fdfminimizer_iterate(self.unwrap_unique()) })
+    }
+
+    /// This function resets the minimizer `s` to use the current point as a new starting point.
+    #[doc(alias = "gsl_multimin_fdfminimizer_restart")]
+    pub fn restart(&mut self) -> ::Value {
+        ::Value::from(unsafe { sys::gsl_multimin_fdfminimizer_restart(self.unwrap_unique()) })
+    }
+
+    /// This function returns the current best estimate of the location of the minimum.
+    #[doc(alias = "gsl_multimin_fdfminimizer_x")]
+    pub fn x(&self) -> ::VectorF64 {
+        ::VectorF64::soft_wrap(unsafe { sys::gsl_multimin_fdfminimizer_x(self.unwrap_shared()) })
+    }
+
+    /// This function returns the last step taken by the minimizer.
+    #[doc(alias = "gsl_multimin_fdfminimizer_dx")]
+    pub fn dx(&self) -> ::VectorF64 {
+        ::VectorF64::soft_wrap(unsafe { sys::gsl_multimin_fdfminimizer_dx(self.unwrap_shared()) })
+    }
+
+    /// This function returns the gradient of the function at the current best estimate of the
+    /// location of the minimum.
+    #[doc(alias = "gsl_multimin_fdfminimizer_gradient")]
+    pub fn gradient(&self) -> ::VectorF64 {
+        ::VectorF64::soft_wrap(unsafe {
+            sys::gsl_multimin_fdfminimizer_gradient(self.unwrap_shared())
+        })
+    }
+
+    /// This function returns the value of the function at the current best estimate of the
+    /// location of the minimum.
+    #[doc(alias = "gsl_multimin_fdfminimizer_minimum")]
+    pub fn minimum(&self) -> f64 {
+        unsafe { sys::gsl_multimin_fdfminimizer_minimum(self.unwrap_shared()) }
+    }
+}
+
+#[cfg(any(test, doctest))]
+mod tests {
+    /// This doc block will be used to ensure that the closure can't be set everywhere!
+    ///
+    /// ```compile_fail
+    /// use rgsl::*;
+    /// use rgsl::types::multimin::{MultiMinFMinimizer, MultiMinFMinimizerType};
+    ///
+    /// fn set(min: &mut MultiMinFMinimizer) {
+    ///     let dummy = "lalal".to_owned();
+    ///     let x = VectorF64::from_slice(&[1.0, 1.0]).unwrap();
+    ///     let step = VectorF64::from_slice(&[0.1, 0.1]).unwrap();
+    ///     min.set(|x| {
+    ///         println!("==> {:?}", dummy);
+    ///         x.get(0) * x.get(0) + x.get(1) * x.get(1)
+    ///     }, &x, &step);
+    /// }
+    ///
+    /// let mut min = MultiMinFMinimizer::new(&MultiMinFMinimizerType::nmsimplex2(), 2).unwrap();
+    /// set(&mut min);
+    /// let status = min.iterate();
+    /// ```
+    ///
+    /// Same but a working version:
+    ///
+    /// ```
+    /// use rgsl::*;
+    /// use rgsl::types::multimin::{MultiMinFMinimizer, MultiMinFMinimizerType};
+    ///
+    /// fn set(min: &mut MultiMinFMinimizer) {
+    ///     let x = VectorF64::from_slice(&[1.0, 1.0]).unwrap();
+    ///     let step = VectorF64::from_slice(&[0.1, 0.1]).unwrap();
+    ///     min.set(|x| x.get(0) * x.get(0) + x.get(1) * x.get(1), &x, &step);
+    /// }
+    ///
+    /// let mut min = MultiMinFMinimizer::new(&MultiMinFMinimizerType::nmsimplex2(), 2).unwrap();
+    /// set(&mut min);
+    /// let status = min.iterate();
+    /// ```
+    use super::*;
+    use multimin::{test_gradient, test_size};
+    use VectorF64;
+
+    // Paraboloid centered on (1, 2), with a minimum value of 30.
+    fn my_f(v: &VectorF64) -> f64 {
+        let x = v.get(0);
+        let y = v.get(1);
+        10.0 * (x - 1.0) * (x - 1.0) + 20.0 * (y - 2.0) * (y - 2.0) + 30.0
+    }
+
+    fn my_df(v: &VectorF64, df: &mut VectorF64) {
+        let x = v.get(0);
+        let y = v.get(1);
+        df.set(0, 20.0 * (x - 1.0));
+        df.set(1, 40.0 * (y - 2.0));
+    }
+
+    fn my_fdf(v: &VectorF64, f: &mut f64, df: &mut VectorF64) {
+        *f = my_f(v);
+        my_df(v, df);
+    }
+
+    #[test]
+    fn test_multimin_fminimizer() {
+        let mut min = MultiMinFMinimizer::new(&MultiMinFMinimizerType::nmsimplex2(), 2).unwrap();
+        let x = VectorF64::from_slice(&[5.0, 7.0]).unwrap();
+        let step_size = VectorF64::from_slice(&[1.0, 1.0]).unwrap();
+        let mut evaluations = 0;
+        min.set(
+            |v| {
+                evaluations += 1;
+                my_f(v)
+            },
+            &x,
+            &step_size,
+        );
+
+        let mut status = ::Value::Continue;
+        let mut iter = 0;
+
+        while matches!(status, ::Value::Continue) && iter < 100 {
+            status = min.iterate();
+            if !matches!(status, ::Value::Success) {
+                break;
+            }
+            status = test_size(min.size(), 1e-4);
+            iter += 1;
+        }
+        assert!(matches!(status, ::Value::Success));
+        assert!((min.x().get(0) - 1.0).abs() < 1e-3);
+        assert!((min.x().get(1) - 2.0).abs() < 1e-3);
+        assert!((min.minimum() - 30.0).abs() < 1e-6);
+        drop(min);
+        assert!(evaluations > 0);
+    }
+
+    #[test]
+    fn test_multimin_fdfminimizer() {
+        let mut min =
+            MultiMinFdfMinimizer::new(&MultiMinFdfMinimizerType::vector_bfgs2(), 2).unwrap();
+        let x = VectorF64::from_slice(&[5.0, 7.0]).unwrap();
+        min.set(my_f, my_df, my_fdf, &x, 0.01, 1e-4);
+
+        let mut status = ::Value::Continue;
+        let mut iter = 0;
+
+        while matches!(status, ::Value::Continue) && iter < 100 {
+            status = min.iterate();
+            if !matches!(status, ::Value::Success) {
+                break;
+            }
+            status = test_gradient(&min.gradient(), 1e-3);
+            iter += 1;
+        }
+        assert!(matches!(status, ::Value::Success));
+        assert!((min.x().get(0) - 1.0).abs() < 1e-3);
+        assert!((min.x().get(1) - 2.0).abs() < 1e-3);
+        assert!(matches!(min.restart(), ::Value::Success));
+    }
+}
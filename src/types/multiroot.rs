@@ -139,7 +139,7 @@ ffi_wrapper!(
     *mut sys::gsl_multiroot_fsolver,
     gsl_multiroot_fsolver_free
     ;inner_call: sys::gsl_multiroot_function_struct => sys::gsl_multiroot_function_struct{ f: None, n: 0, params: std::ptr::null_mut() };
-    ;inner_closure: Option<Box<dyn Fn(&::VectorF64, &mut ::VectorF64, *mut c_void) -> ::Value + 'a>> => None;,
+    ;inner_closure: Option<Box<dyn FnMut(&::VectorF64, &mut ::VectorF64) -> ::Value + 'a>> => None;,
     "This is a workspace for multidimensional root-finding without derivatives."
 );
 
@@ -161,34 +161,35 @@ impl<'a> MultiRootFSolver<'a> {
     }
 
     /// This function initializes, or reinitializes, an existing solver `s` to use the multi
-    /// function `f` with `n` unknowns.
+    /// function `f` with `n` unknowns and the initial guess `x`.
+    ///
+    /// `f` must store the vector result f(x) in its second argument. Since the closure can be
+    /// `FnMut`, it can keep track of its own state (like the number of evaluations) through
+    /// its captured environment.
     #[doc(alias = "gsl_multiroot_fsolver_set")]
-    pub fn set<F: Fn(&::VectorF64, &mut ::VectorF64, *mut c_void) -> ::Value + 'a>(
+    pub fn set<F: FnMut(&::VectorF64, &mut ::VectorF64) -> ::Value + 'a>(
         &mut self,
         f: F,
         n: usize,
         x: &::VectorF64,
-        pp: *mut c_void,
     ) -> ::Value {
-        unsafe extern "C" fn inner_f<
-            A: Fn(&::VectorF64, &mut ::VectorF64, *mut c_void) -> ::Value,
-        >(
+        unsafe extern "C" fn inner_f<A: FnMut(&::VectorF64, &mut ::VectorF64) -> ::Value>(
             x: *const sys::gsl_vector,
             params: *mut c_void,
             f: *mut sys::gsl_vector,
         ) -> c_int {
-            let g: &A = &*(params as *const A);
+            let g: &mut A = &mut *(params as *mut A);
             let x_new = ::VectorF64::soft_wrap(x as *const _ as *mut _);
-            ::Value::into(g(&x_new, &mut ::VectorF64::soft_wrap(f), params))
+            ::Value::into(g(&x_new, &mut ::VectorF64::soft_wrap(f)))
         }
 
+        let mut f = Box::new(f);
         self.inner_call = sys::gsl_multiroot_function_struct {
             f: Some(inner_f::<F>),
             n,
-            // params: &f as *const _ as *mut _,
-            params: pp,
+            params: &mut *f as *mut F as *mut c_void,
         };
-        self.inner_closure = Some(Box::new(f));
+        self.inner_closure = Some(f);
 
         ::Value::from(unsafe {
             sys::gsl_multiroot_fsolver_set(
@@ -236,6 +237,236 @@ impl<'a> MultiRootFSolver<'a> {
     }
 }
 
+ffi_wrapper!(
+    MultiRootFdfSolverType,
+    *const sys::gsl_multiroot_fdfsolver_type,
+    "The multiroot algorithms described in this section make use of both the function and its
+    derivative. They require an initial guess for the location of the root, but there is no
+    absolute guarantee of convergence—the function must be suitable for this technique and the
+    initial guess must be sufficiently close to the root for it to work. When the conditions are
+    satisfied then convergence is quadratic."
+);
+
+impl MultiRootFdfSolverType {
+    /// This is a modified version of Powell’s Hybrid method as implemented in the HYBRJ algorithm
+    /// in MINPACK. Minpack was written by Jorge J. Moré, Burton S. Garbow and Kenneth E. Hillstrom.
+    /// The Hybrid algorithm retains the fast convergence of Newton’s method but will also reduce
+    /// the residual when Newton’s method is unreliable.
+    ///
+    /// The algorithm uses a generalized trust region to keep each step under control. In order to
+    /// be accepted a proposed new position x' must satisfy the condition |D (x' - x)| < \delta,
+    /// where D is a diagonal scaling matrix and \delta is the size of the trust region. The
+    /// components of D are computed internally, using the column norms of the Jacobian to estimate
+    /// the sensitivity of the residual to each component of x. This improves the behavior of the
+    /// algorithm for badly scaled functions.
+    #[doc(alias = "gsl_multiroot_fdfsolver_hybridsj")]
+    pub fn hybridsj() -> MultiRootFdfSolverType {
+        ffi_wrap!(gsl_multiroot_fdfsolver_hybridsj)
+    }
+
+    /// This algorithm is an unscaled version of [`hybridsj`](Self::hybridsj). The steps are
+    /// controlled by a spherical trust region |x' - x| < \delta, instead of a generalized
+    /// region. This can be useful if the generalized region estimated by
+    /// [`hybridsj`](Self::hybridsj) is inappropriate.
+    #[doc(alias = "gsl_multiroot_fdfsolver_hybridj")]
+    pub fn hybridj() -> MultiRootFdfSolverType {
+        ffi_wrap!(gsl_multiroot_fdfsolver_hybridj)
+    }
+
+    /// Newton’s Method is the standard root-polishing algorithm. The algorithm begins with an
+    /// initial guess for the location of the solution. On each iteration a linear approximation
+    /// to the function F is used to estimate the step which will zero all the components of the
+    /// residual. The iteration is defined by the following sequence,
+    ///
+    ///```text
+    ///x \to x' = x - J^{-1} f(x)
+    ///```
+    ///
+    /// where the Jacobian matrix J is computed from the derivative functions provided by f. The
+    /// step dx is obtained by solving the linear system, `J dx = - f(x)` using LU decomposition.
+    /// If the Jacobian matrix is singular, an error code of `Value::Domain` is returned.
+    #[doc(alias = "gsl_multiroot_fdfsolver_newton")]
+    pub fn newton() -> MultiRootFdfSolverType {
+        ffi_wrap!(gsl_multiroot_fdfsolver_newton)
+    }
+
+    /// This is a modified version of Newton’s method which attempts to improve global convergence
+    /// by requiring every step to reduce the Euclidean norm of the residual, |f(x)|. If the Newton
+    /// step leads to an increase in the norm then a reduced step of relative size,
+    ///
+    ///```text
+    ///t = (\sqrt(1 + 6 r) - 1) / (3 r)
+    ///```
+    ///
+    /// is proposed, with r being the ratio of norms |f(x')|^2/|f(x)|^2. This procedure is repeated
+    /// until a suitable step size is found.
+    #[doc(alias = "gsl_multiroot_fdfsolver_gnewton")]
+    pub fn gnewton() -> MultiRootFdfSolverType {
+        ffi_wrap!(gsl_multiroot_fdfsolver_gnewton)
+    }
+}
+
+struct MultiRootFdfClosures<'a> {
+    f: Box<dyn FnMut(&::VectorF64, &mut ::VectorF64) -> ::Value + 'a>,
+    df: Box<dyn FnMut(&::VectorF64, &mut ::MatrixF64) -> ::Value + 'a>,
+    fdf: Box<dyn FnMut(&::VectorF64, &mut ::VectorF64, &mut ::MatrixF64) -> ::Value + 'a>,
+}
+
+ffi_wrapper!(
+    MultiRootFdfSolver<'a>,
+    *mut sys::gsl_multiroot_fdfsolver,
+    gsl_multiroot_fdfsolver_free
+    ;inner_call: sys::gsl_multiroot_function_fdf_struct => sys::gsl_multiroot_function_fdf_struct{ f: None, df: None, fdf: None, n: 0, params: std::ptr::null_mut() };
+    ;inner_closures: Option<Box<MultiRootFdfClosures<'a>>> => None;,
+    "This is a workspace for multidimensional root-finding with an analytic Jacobian matrix."
+);
+
+impl<'a> MultiRootFdfSolver<'a> {
+    /// This function returns a pointer to a newly allocated instance of a derivative solver of
+    /// type `T` with `n` unknowns.
+    ///
+    /// If there is insufficient memory to create the solver then the function returns a null
+    /// pointer and the error handler is invoked with an error code of `Value::NoMemory`.
+    #[doc(alias = "gsl_multiroot_fdfsolver_alloc")]
+    pub fn new(t: &MultiRootFdfSolverType, n: usize) -> Option<MultiRootFdfSolver<'a>> {
+        let ptr = unsafe { sys::gsl_multiroot_fdfsolver_alloc(t.unwrap_shared(), n) };
+
+        if ptr.is_null() {
+            None
+        } else {
+            Some(MultiRootFdfSolver::wrap(ptr))
+        }
+    }
+
+    /// This function initializes, or reinitializes, an existing solver `s` to use the function
+    /// `f`, the Jacobian `df` and the combined evaluation `fdf`, with the initial guess `x`.
+    ///
+    /// `df` must store the n-by-n Jacobian matrix J_{ij} = \partial f_i(x) / \partial x_j in its
+    /// matrix argument, and `fdf` must compute both the function values and the Jacobian at the
+    /// same point.
+    #[doc(alias = "gsl_multiroot_fdfsolver_set")]
+    pub fn set<
+        F: FnMut(&::VectorF64, &mut ::VectorF64) -> ::Value + 'a,
+        DF: FnMut(&::VectorF64, &mut ::MatrixF64) -> ::Value + 'a,
+        FDF: FnMut(&::VectorF64, &mut ::VectorF64, &mut ::MatrixF64) -> ::Value + 'a,
+    >(
+        &mut self,
+        f: F,
+        df: DF,
+        fdf: FDF,
+        x: &::VectorF64,
+    ) -> ::Value {
+        unsafe extern "C" fn inner_f(
+            x: *const sys::gsl_vector,
+            params: *mut c_void,
+            f: *mut sys::gsl_vector,
+        ) -> c_int {
+            let closures = &mut *(params as *mut MultiRootFdfClosures);
+            (closures.f)(
+                &::VectorF64::soft_wrap(x as *mut _),
+                &mut ::VectorF64::soft_wrap(f),
+            )
+            .into()
+        }
+
+        unsafe extern "C" fn inner_df(
+            x: *const sys::gsl_vector,
+            params: *mut c_void,
+            df: *mut sys::gsl_matrix,
+        ) -> c_int {
+            let closures = &mut *(params as *mut MultiRootFdfClosures);
+            (closures.df)(
+                &::VectorF64::soft_wrap(x as *mut _),
+                &mut ::MatrixF64::soft_wrap(df),
+            )
+            .into()
+        }
+
+        unsafe extern "C" fn inner_fdf(
+            x: *const sys::gsl_vector,
+            params: *mut c_void,
+            f: *mut sys::gsl_vector,
+            df: *mut sys::gsl_matrix,
+        ) -> c_int {
+            let closures = &mut *(params as *mut MultiRootFdfClosures);
+            (closures.fdf)(
+                &::VectorF64::soft_wrap(x as *mut _),
+                &mut ::VectorF64::soft_wrap(f),
+                &mut ::MatrixF64::soft_wrap(df),
+            )
+            .into()
+        }
+
+        let mut closures = Box::new(MultiRootFdfClosures {
+            f: Box::new(f),
+            df: Box::new(df),
+            fdf: Box::new(fdf),
+        });
+        self.inner_call = sys::gsl_multiroot_function_fdf_struct {
+            f: Some(inner_f),
+            df: Some(inner_df),
+            fdf: Some(inner_fdf),
+            n: x.len(),
+            params: &mut *closures as *mut MultiRootFdfClosures as *mut c_void,
+        };
+        self.inner_closures = Some(closures);
+
+        ::Value::from(unsafe {
+            sys::gsl_multiroot_fdfsolver_set(
+                self.unwrap_unique(),
+                &mut self.inner_call,
+                x.unwrap_shared(),
+            )
+        })
+    }
+
+    /// Returns the solver type name.
+    #[doc(alias = "gsl_multiroot_fdfsolver_name")]
+    pub fn name(&self) -> String {
+        unsafe {
+            let tmp = sys::gsl_multiroot_fdfsolver_name(self.unwrap_shared());
+
+            String::from_utf8_lossy(::std::ffi::CStr::from_ptr(tmp).to_bytes()).to_string()
+        }
+    }
+
+    /// This function performs a single iteration of the solver `s`. If the iteration encounters
+    /// an unexpected problem then an error code will be returned,
+    ///
+    /// `Value::BadFunction`
+    /// the iteration encountered a singular point where the function or its derivative
+    /// evaluated to Inf or NaN.
+    ///
+    /// `Value::NoProgress`
+    /// the iteration is not making any progress, preventing the algorithm from continuing.
+    ///
+    /// The solver maintains a current best estimate of the root `s->x` and its function value
+    /// `s->f` at all times.
+    #[doc(alias = "gsl_multiroot_fdfsolver_iterate")]
+    pub fn iterate(&mut self) -> ::Value {
+        ::Value::from(unsafe { sys::gsl_multiroot_fdfsolver_iterate(self.unwrap_unique()) })
+    }
+
+    /// This function returns the current estimate of the root for the solver `s`, given by `s->x`.
+    #[doc(alias = "gsl_multiroot_fdfsolver_root")]
+    pub fn root(&self) -> ::VectorF64 {
+        ::VectorF64::soft_wrap(unsafe { sys::gsl_multiroot_fdfsolver_root(self.unwrap_shared()) })
+    }
+
+    /// This function returns the last step `dx` taken by the solver `s`, given by `s->dx`.
+    #[doc(alias = "gsl_multiroot_fdfsolver_dx")]
+    pub fn dx(&self) -> ::VectorF64 {
+        ::VectorF64::soft_wrap(unsafe { sys::gsl_multiroot_fdfsolver_dx(self.unwrap_shared()) })
+    }
+
+    /// This function returns the function value `f(x)` at the current estimate of the root for
+    /// the solver `s`, given by `s->f`.
+    #[doc(alias = "gsl_multiroot_fdfsolver_f")]
+    pub fn f(&self) -> ::VectorF64 {
+        ::VectorF64::soft_wrap(unsafe { sys::gsl_multiroot_fdfsolver_f(self.unwrap_shared()) })
+    }
+}
+
 #[cfg(any(test, doctest))]
 mod tests {
     /// This doc block will be used to ensure that the closure can't be set everywhere!
@@ -278,6 +509,7 @@ mod tests {
     ///
     use super::*;
     use multiroot::test_residual;
+    use MatrixF64;
     use VectorF64;
 
     /// checking a test function
@@ -345,4 +577,42 @@ mod tests {
         }
         assert!(matches!(status, ::Value::Success))
     }
+
+    fn rosenbrock_df(x: &VectorF64, df: &mut MatrixF64) -> ::Value {
+        df.set(0, 0, -1.0);
+        df.set(0, 1, 0.0);
+        df.set(1, 0, 1.0);
+        df.set(1, 1, -2.0 * x.get(1));
+        ::Value::Success
+    }
+
+    fn rosenbrock_fdf(x: &VectorF64, f: &mut VectorF64, df: &mut MatrixF64) -> ::Value {
+        rosenbrock_f(x, f);
+        rosenbrock_df(x, df)
+    }
+
+    #[test]
+    fn test_multiroot_fdfsolver() {
+        let mut multi_root =
+            MultiRootFdfSolver::new(&MultiRootFdfSolverType::gnewton(), 2).unwrap();
+        let guess_value = VectorF64::from_slice(&[-10.0, -5.0]).unwrap();
+        multi_root.set(rosenbrock_f, rosenbrock_df, rosenbrock_fdf, &guess_value);
+
+        let max_iter: usize = 100;
+        let mut iter = 0;
+        let mut status = ::Value::Continue;
+
+        while matches!(status, ::Value::Continue) && iter < max_iter {
+            status = multi_root.iterate();
+
+            if !matches!(status, ::Value::Success) {
+                break;
+            }
+
+            status = test_residual(&multi_root.f(), 1e-6);
+            iter += 1;
+        }
+        assert!(matches!(status, ::Value::Success));
+        assert!((multi_root.root().get(0) - 1.0).abs() < 1e-6);
+    }
 }
@@ -2,7 +2,11 @@
 // A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
 //
 
+use crate::Value;
+use std::convert::TryFrom;
 use std::default::Default;
+use std::mem::MaybeUninit;
+use std::ops::{Add, Div, Mul, Neg, Sub};
 
 /// The error handling form of the special functions always calculate an error estimate along with the value of the result.
 /// Therefore, structures are provided for amalgamating a value and error estimate.
@@ -38,6 +42,106 @@ impl From<::sys::gsl_sf_result> for Result {
     }
 }
 
+// The arithmetic operators propagate the errors to first order, like the special functions do
+// internally: the absolute errors are added for sums, the relative errors for products, and a
+// rounding error of 2 epsilon |val| is added to the result.
+
+fn rounding(val: f64) -> f64 {
+    2. * f64::EPSILON * val.abs()
+}
+
+impl Add for Result {
+    type Output = Result;
+
+    fn add(self, other: Result) -> Result {
+        let val = self.val + other.val;
+        Result {
+            val,
+            err: self.err + other.err + rounding(val),
+        }
+    }
+}
+
+impl Sub for Result {
+    type Output = Result;
+
+    fn sub(self, other: Result) -> Result {
+        let val = self.val - other.val;
+        Result {
+            val,
+            err: self.err + other.err + rounding(val),
+        }
+    }
+}
+
+/// The product is computed with [`multiply_err_e`](crate::elementary_operations::multiply_err_e).
+/// As for the special functions, an overflow is reported to the GSL error handler.
+impl Mul for Result {
+    type Output = Result;
+
+    fn mul(self, other: Result) -> Result {
+        ::elementary_operations::multiply_err_e(self.val, self.err, other.val, other.err).1
+    }
+}
+
+impl Div for Result {
+    type Output = Result;
+
+    fn div(self, other: Result) -> Result {
+        let val = self.val / other.val;
+        Result {
+            val,
+            err: (self.err + val.abs() * other.err) / other.val.abs() + rounding(val),
+        }
+    }
+}
+
+impl Neg for Result {
+    type Output = Result;
+
+    fn neg(self) -> Result {
+        Result {
+            val: -self.val,
+            err: self.err,
+        }
+    }
+}
+
+impl From<Result> for ResultE10 {
+    fn from(v: Result) -> ResultE10 {
+        ResultE10 {
+            val: v.val,
+            err: v.err,
+            e10: 0,
+        }
+    }
+}
+
+/// Fails with `Value::OverFlow` or `Value::UnderFlow` if the value doesn't fit in a `f64`. See
+/// [`ResultE10::result_smash`].
+///
+/// The range is checked before calling GSL, so the error handler isn't invoked in this case.
+impl TryFrom<ResultE10> for Result {
+    type Error = Value;
+
+    fn try_from(v: ResultE10) -> ::std::result::Result<Result, Value> {
+        if v.val != 0. && v.val.is_finite() {
+            // Same bounds as `gsl_sf_result_smash_e`: log10(DBL_MAX) and log10(DBL_MIN).
+            let l10 = v.e10 as f64 + v.val.abs().log10();
+            if l10 > f64::MAX.log10() {
+                return Err(Value::OverFlow);
+            }
+            if l10 < f64::MIN_POSITIVE.log10() {
+                return Err(Value::UnderFlow);
+            }
+        }
+        match v.result_smash() {
+            (Value::Success, r) => Ok(r),
+            (e, _) => Err(e),
+        }
+    }
+}
+
 /// In some cases, an overflow or underflow can be detected and handled by a function.
 /// In this case, it may be possible to return a scaling exponent as well as an error/value pair in order to save the result from exceeding the dynamic range of the built-in types.
 #[derive(Clone, Copy, Debug)]
@@ -66,6 +170,134 @@ impl ResultE10 {
     }
 }
 
+impl ResultE10 {
+    /// This function converts the scaled value to a [`Result`], with the value val * 10^e10.
+    /// Returns `Value::OverFlow` or `Value::UnderFlow` if the value is out of the range of `f64`.
+    #[doc(alias = "gsl_sf_result_smash_e")]
+    pub fn result_smash(&self) -> (Value, Result) {
+        let re = sys::gsl_sf_result_e10 {
+            val: self.val,
+            err: self.err,
+            e10: self.e10,
+        };
+        let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+        let ret = unsafe { sys::gsl_sf_result_smash_e(&re, result.as_mut_ptr()) };
+
+        (Value::from(ret), unsafe { result.assume_init() }.into())
+    }
+
+    /// Returns the same value with a mantissa 1 <= |val| < 10, so that the arithmetic operators
+    /// can't overflow. Zero and non-finite values are returned unchanged.
+    fn normalized(self) -> ResultE10 {
+        if self.val == 0. || !self.val.is_finite() || (1. ..10.).contains(&self.val.abs()) {
+            return self;
+        }
+        let mut shift = self.val.abs().log10().floor() as i32;
+        // 10^-shift overflows for subnormal values, so the scaling is done in two steps.
+        let scale =
+            |x: f64, shift: i32| x * 10f64.powi(-shift / 2) * 10f64.powi(-shift + shift / 2);
+        let mut val = scale(self.val, shift);
+        // log10 can be off by one ulp around the powers of 10.
+        if val.abs() >= 10. {
+            shift += 1;
+            val = scale(self.val, shift);
+        } else if val.abs() < 1. {
+            shift -= 1;
+            val = scale(self.val, shift);
+        }
+        ResultE10 {
+            val,
+            err: scale(self.err, shift) + rounding(val),
+            e10: self.e10 + shift,
+        }
+    }
+
+    /// Returns the mantissas of `self` and `other` expressed with the same exponent, the larger
+    /// one.
+    fn aligned(self, other: ResultE10) -> (Result, Result, i32) {
+        let (a, b) = (self.normalized(), other.normalized());
+        let e10 = a.e10.max(b.e10);
+        let rescale = |r: ResultE10| {
+            let scale = 10f64.powi(r.e10 - e10);
+            Result {
+                val: r.val * scale,
+                err: r.err * scale,
+            }
+        };
+        (rescale(a), rescale(b), e10)
+    }
+
+    fn with_e10(r: Result, e10: i32) -> ResultE10 {
+        ResultE10 {
+            val: r.val,
+            err: r.err,
+            e10,
+        }
+    }
+}
+
+impl Add for ResultE10 {
+    type Output = ResultE10;
+
+    fn add(self, other: ResultE10) -> ResultE10 {
+        let (a, b, e10) = self.aligned(other);
+        ResultE10::with_e10(a + b, e10)
+    }
+}
+
+impl Sub for ResultE10 {
+    type Output = ResultE10;
+
+    fn sub(self, other: ResultE10) -> ResultE10 {
+        let (a, b, e10) = self.aligned(other);
+        ResultE10::with_e10(a - b, e10)
+    }
+}
+
+impl Mul for ResultE10 {
+    type Output = ResultE10;
+
+    fn mul(self, other: ResultE10) -> ResultE10 {
+        let (a, b) = (self.normalized(), other.normalized());
+        let r = Result {
+            val: a.val,
+            err: a.err,
+        } * Result {
+            val: b.val,
+            err: b.err,
+        };
+        ResultE10::with_e10(r, a.e10 + b.e10).normalized()
+    }
+}
+
+impl Div for ResultE10 {
+    type Output = ResultE10;
+
+    fn div(self, other: ResultE10) -> ResultE10 {
+        let (a, b) = (self.normalized(), other.normalized());
+        let r = Result {
+            val: a.val,
+            err: a.err,
+        } / Result {
+            val: b.val,
+            err: b.err,
+        };
+        ResultE10::with_e10(r, a.e10 - b.e10).normalized()
+    }
+}
+
+impl Neg for ResultE10 {
+    type Output = ResultE10;
+
+    fn neg(self) -> ResultE10 {
+        ResultE10 {
+            val: -self.val,
+            err: self.err,
+            e10: self.e10,
+        }
+    }
+}
+
 impl From<::sys::gsl_sf_result_e10> for ResultE10 {
     fn from(v: ::sys::gsl_sf_result_e10) -> Self {
         Self {
@@ -75,3 +307,84 @@ impl From<::sys::gsl_sf_result_e10> for ResultE10 {
         }
     }
 }
+
+#[cfg(any(test, doctest))]
+mod tests {
+    use super::*;
+    use crate::gamma_beta::beta::beta_e;
+    use crate::gamma_beta::gamma::gamma_e;
+
+    #[test]
+    fn test_arithmetic() {
+        let (a, b) = (2.5, 3.5);
+        let r = gamma_e(a).1 * gamma_e(b).1 / gamma_e(a + b).1;
+        let expected = beta_e(a, b).1;
+        assert!((r.val - expected.val).abs() <= r.err + expected.err);
+        assert!(r.err > 0. && r.err < 1e-13);
+
+        let x = Result { val: 1., err: 0.1 };
+        let y = Result { val: 2., err: 0.2 };
+        let s = x + y;
+        assert_eq!(s.val, 3.);
+        assert!((s.err - 0.3).abs() < 1e-12);
+        assert_eq!((x - y).val, -1.);
+        assert!(((x * y).err - 0.4).abs() < 1e-12);
+        assert!(((x / y).err - 0.1).abs() < 1e-12);
+        assert_eq!((-x).val, -1.);
+        assert_eq!((-x).err, 0.1);
+    }
+
+    #[test]
+    fn test_e10() {
+        let big = ResultE10 {
+            val: 2.,
+            err: 1e-15,
+            e10: 400,
+        };
+        let small = ResultE10 {
+            val: 5.,
+            err: 1e-15,
+            e10: -400,
+        };
+        let p = big * small;
+        assert_eq!(p.e10, 1);
+        assert!((p.val - 1.).abs() < 1e-14);
+        let r = Result::try_from(p).unwrap();
+        assert!((r.val - 10.).abs() < 1e-13);
+
+        assert_eq!(Result::try_from(big).unwrap_err(), Value::OverFlow);
+        assert_eq!(
+            Result::try_from(-small * small).unwrap_err(),
+            Value::UnderFlow
+        );
+
+        let q = big / small;
+        assert!((q.val - 4.).abs() < 1e-14);
+        assert_eq!(q.e10, 799);
+
+        let s = big + ResultE10::from(Result { val: 1., err: 0. });
+        assert_eq!(s.e10, 400);
+        assert_eq!(s.val, 2.);
+
+        let d = big - big;
+        assert_eq!(d.val, 0.);
+        assert_eq!((-big).val, -2.);
+
+        let n = ResultE10::from(Result {
+            val: 12345.,
+            err: 1.,
+        })
+        .normalized();
+        assert_eq!(n.e10, 4);
+        assert!((n.val - 1.2345).abs() < 1e-15);
+        assert!((n.err - 1e-4).abs() < 1e-15);
+        let n = ResultE10 {
+            val: 5e-320,
+            err: 0.,
+            e10: 0,
+        }
+        .normalized();
+        assert_eq!(n.e10, -320);
+        assert!((n.val - 5.).abs() < 1e-2);
+    }
+}
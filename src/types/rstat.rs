@@ -38,6 +38,117 @@ impl RStatQuantileWorkspace {
     pub fn get(&mut self) -> f64 {
         unsafe { sys::gsl_rstat_quantile_get(self.unwrap_unique()) }
     }
+
+    /// Merges the observations accumulated in `other` into `self`. Both workspaces must estimate
+    /// the same quantile, otherwise `Value::Invalid` is returned.
+    ///
+    /// The P^2 sketch cannot be merged exactly: the result is exact when one of the workspaces
+    /// holds at most 5 observations (which are still stored verbatim). Otherwise, the estimate
+    /// of the workspace with the most observations is kept.
+    pub fn merge(&mut self, other: &RStatQuantileWorkspace) -> Value {
+        unsafe { merge_quantile(self.unwrap_unique(), other.unwrap_shared()) }
+    }
+
+    /// Returns a copy of the current state of the workspace, which can be given back to
+    /// [`restore`](Self::restore) to resume the computation later.
+    pub fn snapshot(&self) -> RStatQuantileState {
+        RStatQuantileState::from(unsafe { &*self.unwrap_shared() })
+    }
+
+    /// Restores a state previously returned by [`snapshot`](Self::snapshot).
+    pub fn restore(&mut self, state: &RStatQuantileState) {
+        state.write(unsafe { &mut *self.unwrap_unique() });
+    }
+}
+
+/// Running estimation of several quantiles at once, with one [`RStatQuantileWorkspace`] per
+/// quantile. Like the single quantile workspace, it only needs constant memory whatever the
+/// number of observations.
+///
+/// ```text
+/// let mut latencies = RStatQuantiles::new(&[0.5, 0.9, 0.99, 0.999]).unwrap();
+/// latencies.add_slice(&samples);
+/// let p99 = latencies.get(0.99).unwrap();
+/// ```
+pub struct RStatQuantiles {
+    workspaces: Vec<(f64, RStatQuantileWorkspace)>,
+}
+
+impl RStatQuantiles {
+    /// Allocates a workspace estimating the quantiles `p`, which must all be in [0, 1].
+    /// Duplicated values are only tracked once. Returns `None` if a quantile is out of range or
+    /// if the allocation failed.
+    pub fn new(p: &[f64]) -> Option<Self> {
+        if p.iter().any(|p| !(0. ..=1.).contains(p)) {
+            return None;
+        }
+        let mut p = p.to_vec();
+        p.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        p.dedup();
+        let workspaces = p
+            .into_iter()
+            .map(|p| RStatQuantileWorkspace::new(p).map(|w| (p, w)))
+            .collect::<Option<_>>()?;
+        Some(RStatQuantiles { workspaces })
+    }
+
+    /// Returns the estimated quantiles, in increasing order.
+    pub fn quantiles(&self) -> Vec<f64> {
+        self.workspaces.iter().map(|(p, _)| *p).collect()
+    }
+
+    /// Adds the observation `x` to the estimation of every quantile.
+    pub fn add(&mut self, x: f64) -> Value {
+        for (_, w) in self.workspaces.iter_mut() {
+            let ret = w.add(x);
+            if ret != Value::Success {
+                return ret;
+            }
+        }
+        Value::Success
+    }
+
+    /// Adds all the observations of `data`.
+    pub fn add_slice(&mut self, data: &[f64]) -> Value {
+        for &x in data {
+            let ret = self.add(x);
+            if ret != Value::Success {
+                return ret;
+            }
+        }
+        Value::Success
+    }
+
+    /// Returns the current estimate of the quantile `q`, or `None` if `q` was not given to
+    /// [`new`](Self::new).
+    pub fn get(&mut self, q: f64) -> Option<f64> {
+        self.workspaces
+            .iter_mut()
+            .find(|(p, _)| *p == q)
+            .map(|(_, w)| w.get())
+    }
+
+    /// Returns the current estimates of all the quantiles, as `(quantile, estimate)` pairs in
+    /// increasing order of quantile.
+    pub fn get_all(&mut self) -> Vec<(f64, f64)> {
+        self.workspaces
+            .iter_mut()
+            .map(|(p, w)| (*p, w.get()))
+            .collect()
+    }
+
+    /// Resets the workspace to its initial state, removing all the observations.
+    #[cfg(feature = "v2_2")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
+    pub fn reset(&mut self) -> Value {
+        for (_, w) in self.workspaces.iter_mut() {
+            let ret = w.reset();
+            if ret != Value::Success {
+                return ret;
+            }
+        }
+        Value::Success
+    }
 }
 
 ffi_wrapper!(
@@ -123,4 +234,387 @@ impl RStatWorkspace {
     pub fn reset(&mut self) -> Value {
         Value::from(unsafe { sys::gsl_rstat_reset(self.unwrap_unique()) })
     }
+
+    /// Merges the observations accumulated in `other` into `self`, as if all of them had been
+    /// added to `self`. This allows to accumulate statistics in several workspaces, one per
+    /// thread for example, and to combine them at the end.
+    ///
+    /// The minimum, maximum, mean and central moments are combined exactly (up to rounding),
+    /// so the variance, skewness and kurtosis are the same as with a single workspace. See
+    /// [`RStatQuantileWorkspace::merge`] for the median.
+    pub fn merge(&mut self, other: &RStatWorkspace) -> Value {
+        let (a, b) = unsafe { (&mut *self.unwrap_unique(), &*other.unwrap_shared()) };
+        if b.n == 0 {
+            return Value::Success;
+        }
+        if a.n == 0 {
+            a.min = b.min;
+            a.max = b.max;
+        } else {
+            a.min = a.min.min(b.min);
+            a.max = a.max.max(b.max);
+        }
+
+        let (na, nb) = (a.n as f64, b.n as f64);
+        let n = na + nb;
+        let delta = b.mean - a.mean;
+        let delta2 = delta * delta;
+
+        let M4 = a.M4
+            + b.M4
+            + delta2 * delta2 * na * nb * (na * na - na * nb + nb * nb) / (n * n * n)
+            + 6. * delta2 * (na * na * b.M2 + nb * nb * a.M2) / (n * n)
+            + 4. * delta * (na * b.M3 - nb * a.M3) / n;
+        let M3 = a.M3
+            + b.M3
+            + delta2 * delta * na * nb * (na - nb) / (n * n)
+            + 3. * delta * (na * b.M2 - nb * a.M2) / n;
+        let M2 = a.M2 + b.M2 + delta2 * na * nb / n;
+
+        a.mean += delta * nb / n;
+        a.M2 = M2;
+        a.M3 = M3;
+        a.M4 = M4;
+        a.n += b.n;
+
+        unsafe { merge_quantile(a.median_workspace_p, b.median_workspace_p) }
+    }
+
+    /// Returns a copy of the current state of the workspace, including the median estimator.
+    /// It can be serialized with [`RStatState::to_bytes`] to checkpoint a long computation.
+    pub fn snapshot(&self) -> RStatState {
+        let w = unsafe { &*self.unwrap_shared() };
+        RStatState {
+            min: w.min,
+            max: w.max,
+            mean: w.mean,
+            M2: w.M2,
+            M3: w.M3,
+            M4: w.M4,
+            n: w.n,
+            median: RStatQuantileState::from(unsafe { &*w.median_workspace_p }),
+        }
+    }
+
+    /// Restores a state previously returned by [`snapshot`](Self::snapshot). Further calls to
+    /// [`add`](Self::add) continue the computation from this state.
+    pub fn restore(&mut self, state: &RStatState) {
+        let w = unsafe { &mut *self.unwrap_unique() };
+        w.min = state.min;
+        w.max = state.max;
+        w.mean = state.mean;
+        w.M2 = state.M2;
+        w.M3 = state.M3;
+        w.M4 = state.M4;
+        w.n = state.n;
+        state.median.write(unsafe { &mut *w.median_workspace_p });
+    }
+
+    /// Allocates a new workspace initialized with `state`.
+    pub fn from_state(state: &RStatState) -> Option<Self> {
+        let mut w = Self::new()?;
+        w.restore(state);
+        Some(w)
+    }
+}
+
+// checker:ignore
+#[doc(alias = "gsl_rstat_quantile_add")]
+unsafe fn merge_quantile(
+    dst: *mut sys::gsl_rstat_quantile_workspace,
+    src: *const sys::gsl_rstat_quantile_workspace,
+) -> Value {
+    let src = *src;
+    if (*dst).p != src.p {
+        return Value::Invalid;
+    }
+    // While a workspace has at most 5 observations, they are stored as-is in `q`.
+    let raw = if src.n <= 5 {
+        &src.q[..src.n]
+    } else {
+        let prev = *dst;
+        if prev.n > 5 && prev.n >= src.n {
+            return Value::Success;
+        }
+        *dst = src;
+        if prev.n > 5 {
+            return Value::Success;
+        }
+        return merge_quantile_raw(dst, &prev.q[..prev.n]);
+    };
+    merge_quantile_raw(dst, raw)
+}
+
+// checker:ignore
+#[doc(alias = "gsl_rstat_quantile_add")]
+unsafe fn merge_quantile_raw(dst: *mut sys::gsl_rstat_quantile_workspace, raw: &[f64]) -> Value {
+    for &x in raw {
+        let ret = Value::from(sys::gsl_rstat_quantile_add(x, dst));
+        if ret != Value::Success {
+            return ret;
+        }
+    }
+    Value::Success
+}
+
+/// State of a [`RStatQuantileWorkspace`], used to checkpoint and resume a computation.
+///
+/// The fields are the ones of the P^2 algorithm: the marker heights `q`, their actual and
+/// desired positions `npos` and `np`, and the increments of the desired positions `dnp`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RStatQuantileState {
+    /// The quantile being estimated.
+    pub p: f64,
+    pub q: [f64; 5],
+    pub npos: [i32; 5],
+    pub np: [f64; 5],
+    pub dnp: [f64; 5],
+    /// Number of observations added.
+    pub n: usize,
+}
+
+impl RStatQuantileState {
+    /// Size in bytes of the output of [`to_bytes`](Self::to_bytes).
+    pub const BYTES: usize = 8 * 17 + 4 * 5 + 8;
+
+    fn write(&self, w: &mut sys::gsl_rstat_quantile_workspace) {
+        w.p = self.p;
+        w.q = self.q;
+        w.npos = self.npos;
+        w.np = self.np;
+        w.dnp = self.dnp;
+        w.n = self.n;
+    }
+
+    /// Serializes the state in a portable (little endian) binary format.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(Self::BYTES);
+        self.write_bytes(&mut out);
+        out
+    }
+
+    /// Deserializes a state written by [`to_bytes`](Self::to_bytes). Returns `None` if `bytes`
+    /// doesn't have the expected length.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != Self::BYTES {
+            return None;
+        }
+        Some(Self::read_bytes(&mut Bytes(bytes)))
+    }
+
+    fn write_bytes(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.p.to_le_bytes());
+        for x in self.q.iter().chain(self.np.iter()).chain(self.dnp.iter()) {
+            out.extend_from_slice(&x.to_le_bytes());
+        }
+        for x in self.npos.iter() {
+            out.extend_from_slice(&x.to_le_bytes());
+        }
+        out.extend_from_slice(&(self.n as u64).to_le_bytes());
+    }
+
+    fn read_bytes(b: &mut Bytes<'_>) -> Self {
+        let p = b.f64();
+        let q = [b.f64(), b.f64(), b.f64(), b.f64(), b.f64()];
+        let np = [b.f64(), b.f64(), b.f64(), b.f64(), b.f64()];
+        let dnp = [b.f64(), b.f64(), b.f64(), b.f64(), b.f64()];
+        let npos = [b.i32(), b.i32(), b.i32(), b.i32(), b.i32()];
+        let n = b.u64() as usize;
+        RStatQuantileState {
+            p,
+            q,
+            npos,
+            np,
+            dnp,
+            n,
+        }
+    }
+}
+
+impl From<&sys::gsl_rstat_quantile_workspace> for RStatQuantileState {
+    fn from(w: &sys::gsl_rstat_quantile_workspace) -> Self {
+        RStatQuantileState {
+            p: w.p,
+            q: w.q,
+            npos: w.npos,
+            np: w.np,
+            dnp: w.dnp,
+            n: w.n,
+        }
+    }
+}
+
+/// State of a [`RStatWorkspace`], used to checkpoint and resume a computation.
+///
+/// `M2`, `M3` and `M4` are the sums of the 2nd, 3rd and 4th powers of the deviations from
+/// the mean.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RStatState {
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    pub M2: f64,
+    pub M3: f64,
+    pub M4: f64,
+    /// Number of observations added.
+    pub n: usize,
+    /// State of the median estimator.
+    pub median: RStatQuantileState,
+}
+
+impl RStatState {
+    /// Size in bytes of the output of [`to_bytes`](Self::to_bytes).
+    pub const BYTES: usize = 8 * 7 + RStatQuantileState::BYTES;
+
+    /// Serializes the state in a portable (little endian) binary format.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(Self::BYTES);
+        for x in [self.min, self.max, self.mean, self.M2, self.M3, self.M4].iter() {
+            out.extend_from_slice(&x.to_le_bytes());
+        }
+        out.extend_from_slice(&(self.n as u64).to_le_bytes());
+        self.median.write_bytes(&mut out);
+        out
+    }
+
+    /// Deserializes a state written by [`to_bytes`](Self::to_bytes). Returns `None` if `bytes`
+    /// doesn't have the expected length.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != Self::BYTES {
+            return None;
+        }
+        let b = &mut Bytes(bytes);
+        Some(RStatState {
+            min: b.f64(),
+            max: b.f64(),
+            mean: b.f64(),
+            M2: b.f64(),
+            M3: b.f64(),
+            M4: b.f64(),
+            n: b.u64() as usize,
+            median: RStatQuantileState::read_bytes(b),
+        })
+    }
+}
+
+/// Reads little endian numbers from a buffer whose length has already been checked.
+struct Bytes<'a>(&'a [u8]);
+
+impl Bytes<'_> {
+    fn take<const N: usize>(&mut self) -> [u8; N] {
+        let mut out = [0; N];
+        out.copy_from_slice(&self.0[..N]);
+        self.0 = &self.0[N..];
+        out
+    }
+
+    fn f64(&mut self) -> f64 {
+        f64::from_le_bytes(self.take())
+    }
+
+    fn i32(&mut self) -> i32 {
+        i32::from_le_bytes(self.take())
+    }
+
+    fn u64(&mut self) -> u64 {
+        u64::from_le_bytes(self.take())
+    }
+}
+
+#[cfg(any(test, doctest))]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() <= 1e-10 * b.abs().max(1.), "{} != {}", a, b);
+    }
+
+    #[test]
+    fn test_merge() {
+        let data: Vec<f64> = (0..100)
+            .map(|i| ((i * 37) % 101) as f64 / 7. - 3.)
+            .collect();
+        let mut all = RStatWorkspace::new().unwrap();
+        for &x in &data {
+            all.add(x);
+        }
+
+        for &split in &[0, 3, 40, 97, 100] {
+            let mut a = RStatWorkspace::new().unwrap();
+            let mut b = RStatWorkspace::new().unwrap();
+            for &x in &data[..split] {
+                a.add(x);
+            }
+            for &x in &data[split..] {
+                b.add(x);
+            }
+            assert_eq!(a.merge(&b), Value::Success);
+            assert_eq!(a.n(), all.n());
+            assert_eq!(a.min(), all.min());
+            assert_eq!(a.max(), all.max());
+            assert_close(a.mean(), all.mean());
+            assert_close(a.variance(), all.variance());
+            assert_close(a.skew(), all.skew());
+            assert_close(a.kurtosis(), all.kurtosis());
+            // The median sketches only merge exactly when the observations are added in order.
+            if split == 0 || split >= 95 {
+                assert_close(a.median(), all.median());
+            }
+        }
+
+        let mut q = RStatQuantileWorkspace::new(0.5).unwrap();
+        assert_eq!(
+            q.merge(&RStatQuantileWorkspace::new(0.9).unwrap()),
+            Value::Invalid
+        );
+    }
+
+    #[test]
+    fn test_quantiles() {
+        assert!(RStatQuantiles::new(&[0.5, 1.5]).is_none());
+
+        let mut q = RStatQuantiles::new(&[0.99, 0.5, 0.9, 0.5]).unwrap();
+        assert_eq!(q.quantiles(), vec![0.5, 0.9, 0.99]);
+        let data: Vec<f64> = (0..10_000).map(|i| ((i * 7919) % 10_000) as f64).collect();
+        assert_eq!(q.add_slice(&data), Value::Success);
+
+        assert!((q.get(0.5).unwrap() - 5000.).abs() < 100.);
+        assert!((q.get(0.9).unwrap() - 9000.).abs() < 100.);
+        assert!((q.get(0.99).unwrap() - 9900.).abs() < 100.);
+        assert_eq!(q.get(0.1), None);
+        let all = q.get_all();
+        assert_eq!(all.len(), 3);
+        assert_eq!(all[1], (0.9, q.get(0.9).unwrap()));
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let mut w = RStatWorkspace::new().unwrap();
+        for i in 0..20 {
+            w.add((i as f64).sin());
+        }
+        let state = w.snapshot();
+        assert_eq!(state.n, 20);
+        let bytes = state.to_bytes();
+        assert_eq!(bytes.len(), RStatState::BYTES);
+        let restored = RStatState::from_bytes(&bytes).unwrap();
+        assert_eq!(restored, state);
+        assert_eq!(RStatState::from_bytes(&bytes[1..]), None);
+
+        let mut r = RStatWorkspace::from_state(&restored).unwrap();
+        for i in 20..50 {
+            w.add((i as f64).sin());
+            r.add((i as f64).sin());
+        }
+        assert_eq!(r.snapshot(), w.snapshot());
+        assert_eq!(r.median(), w.median());
+
+        let mut q = RStatQuantileWorkspace::new(0.9).unwrap();
+        for i in 0..10 {
+            q.add(i as f64);
+        }
+        let mut q2 = RStatQuantileWorkspace::new(0.5).unwrap();
+        q2.restore(&RStatQuantileState::from_bytes(&q.snapshot().to_bytes()).unwrap());
+        assert_eq!(q2.get(), q.get());
+    }
 }
@@ -0,0 +1,190 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+# Sparse Linear Algebra
+
+This chapter describes functions for solving sparse linear systems A x = b with iterative
+methods. Iterative methods only require the ability to compute matrix-vector products with A,
+so the matrix never has to be factored and stays sparse during the whole computation.
+
+## GMRES
+
+The Generalized Minimum Residual Method (GMRES) is an iterative method for solving nonsymmetric
+linear systems. It constructs an orthonormal basis of the Krylov subspace of dimension m and
+finds the approximate solution in this subspace which minimizes the residual norm
+||b - A x||. The subspace dimension m can be set when allocating the solver; when the
+solution has not converged after m steps, the method is restarted from the current estimate.
+
+```text
+let mut solver = IterSolver::new(&IterSolverType::gmres(), n, n).unwrap();
+let status = solver.solve(&a, &b, 1e-6, &mut x, 100);
+```
+!*/
+
+use crate::{SpMatrixF64, Value, VectorF64};
+use ffi::FFI;
+
+ffi_wrapper!(
+    IterSolverType,
+    *const sys::gsl_splinalg_itersolve_type,
+    "Type of the iterative sparse linear solver."
+);
+
+impl IterSolverType {
+    /// This specifies the Generalized Minimum Residual Method (GMRES). This is a projection method
+    /// using K = A and L = A K where K is the m-th Krylov subspace.
+    #[doc(alias = "gsl_splinalg_itersolve_gmres")]
+    pub fn gmres() -> IterSolverType {
+        ffi_wrap!(gsl_splinalg_itersolve_gmres)
+    }
+}
+
+ffi_wrapper!(
+    IterSolver,
+    *mut sys::gsl_splinalg_itersolve,
+    gsl_splinalg_itersolve_free
+);
+
+impl IterSolver {
+    /// This function allocates a workspace for the iterative solution of n-by-n sparse matrix
+    /// systems. The iterative solver type is specified by `t`. The argument `m` specifies the
+    /// size of the solution Krylov subspace for methods which need it. It is ignored by other
+    /// methods. When `m` is 0, the dimension of the subspace is chosen automatically
+    /// (`min(n, 10)` for GMRES).
+    #[doc(alias = "gsl_splinalg_itersolve_alloc")]
+    pub fn new(t: &IterSolverType, n: usize, m: usize) -> Option<IterSolver> {
+        let s = unsafe { sys::gsl_splinalg_itersolve_alloc(t.unwrap_shared(), n, m) };
+        if s.is_null() {
+            None
+        } else {
+            Some(Self::wrap(s))
+        }
+    }
+
+    /// This function returns the name of the iterative solver.
+    #[doc(alias = "gsl_splinalg_itersolve_name")]
+    pub fn name(&self) -> String {
+        unsafe {
+            let tmp = sys::gsl_splinalg_itersolve_name(self.unwrap_shared());
+
+            String::from_utf8_lossy(::std::ffi::CStr::from_ptr(tmp).to_bytes()).to_string()
+        }
+    }
+
+    /// This function performs one iteration of the iterative method for the sparse linear system
+    /// A x = b. The sparse matrix `A` must be in compressed column format. On input, `x` must be
+    /// set to an initial guess for the solution. On output, `x` is updated to give the current
+    /// solution estimate. The parameter `tol` specifies the relative tolerance between the
+    /// residual norm and norm of b in order to check for convergence. When the following
+    /// condition is satisfied:
+    ///
+    /// ```text
+    /// || A x - b || <= tol * || b ||
+    /// ```
+    ///
+    /// the method has converged, the function returns `Value::Success` and the final solution
+    /// is provided in `x`. Otherwise, the function returns `Value::Continue` to signal that
+    /// more iterations are required.
+    #[doc(alias = "gsl_splinalg_itersolve_iterate")]
+    pub fn iterate(
+        &mut self,
+        A: &SpMatrixF64,
+        b: &VectorF64,
+        tol: f64,
+        x: &mut VectorF64,
+    ) -> Value {
+        Value::from(unsafe {
+            sys::gsl_splinalg_itersolve_iterate(
+                A.unwrap_shared(),
+                b.unwrap_shared(),
+                tol,
+                x.unwrap_unique(),
+                self.unwrap_unique(),
+            )
+        })
+    }
+
+    /// This function returns the current residual norm ||r|| = ||A x - b||, which is updated
+    /// after each call to [`iterate`](Self::iterate).
+    #[doc(alias = "gsl_splinalg_itersolve_normr")]
+    pub fn normr(&self) -> f64 {
+        unsafe { sys::gsl_splinalg_itersolve_normr(self.unwrap_shared()) }
+    }
+
+    /// This function calls [`iterate`](Self::iterate) until the method converges or `max_iter`
+    /// iterations have been performed. On input, `x` must be set to an initial guess for the
+    /// solution.
+    ///
+    /// Returns `Value::Success` on convergence and `Value::MaxIteration` if the method did not
+    /// converge within `max_iter` iterations. Any other error returned by
+    /// [`iterate`](Self::iterate) is passed through.
+    #[doc(alias = "gsl_splinalg_itersolve_iterate")]
+    pub fn solve(
+        &mut self,
+        A: &SpMatrixF64,
+        b: &VectorF64,
+        tol: f64,
+        x: &mut VectorF64,
+        max_iter: usize,
+    ) -> Value {
+        for _ in 0..max_iter {
+            match self.iterate(A, b, tol, x) {
+                Value::Continue => {}
+                status => return status,
+            }
+        }
+        Value::MaxIteration
+    }
+}
+
+#[cfg(any(test, doctest))]
+mod tests {
+    use super::*;
+    use crate::SpMatrixFormat;
+
+    // Solve the 1D Poisson equation -u''(x) = -pi^2 sin(pi x) on [0, 1] with u(0) = u(1) = 0,
+    // discretized with finite differences. The exact solution is u(x) = sin(pi x).
+    #[test]
+    fn test_gmres() {
+        let n = 50;
+        let h = 1. / (n + 1) as f64;
+        let pi = ::std::f64::consts::PI;
+
+        let mut a = SpMatrixF64::new(n, n).unwrap();
+        let mut b = VectorF64::new(n).unwrap();
+        for i in 0..n {
+            let xi = (i + 1) as f64 * h;
+            a.set(i, i, -2. / (h * h));
+            if i > 0 {
+                a.set(i, i - 1, 1. / (h * h));
+            }
+            if i + 1 < n {
+                a.set(i, i + 1, 1. / (h * h));
+            }
+            b.set(i, -pi * pi * (pi * xi).sin());
+        }
+        let c = a.compress(SpMatrixFormat::Csc).unwrap();
+
+        let mut solver = IterSolver::new(&IterSolverType::gmres(), n, n).unwrap();
+        assert_eq!(solver.name(), "gmres");
+        let mut x = VectorF64::new(n).unwrap();
+        assert_eq!(solver.solve(&c, &b, 1e-6, &mut x, 100), Value::Success);
+        assert!(solver.normr() <= 1e-6 * crate::blas::level1::dnrm2(&b));
+
+        for i in 0..n {
+            let xi = (i + 1) as f64 * h;
+            assert!((x.get(i) - (pi * xi).sin()).abs() < 1e-3);
+        }
+
+        let mut y = VectorF64::new(n).unwrap();
+        assert_eq!(
+            crate::spblas::dgemv(crate::CblasTranspose::NoTranspose, 1., &c, &x, 0., &mut y),
+            Value::Success
+        );
+        for i in 0..n {
+            assert!((y.get(i) - b.get(i)).abs() < 1e-4 * crate::blas::level1::dnrm2(&b));
+        }
+    }
+}
@@ -0,0 +1,398 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+# Sparse Matrices
+
+Sparse matrices are matrices in which most of the elements are zero. Only the nonzero elements
+are stored, which saves memory and makes operations such as matrix-vector products much faster
+for large problems.
+
+A sparse matrix can be stored in one of three formats (see
+[`SpMatrixFormat`](crate::SpMatrixFormat)):
+
+* the coordinate (COO, or triplet) format stores each nonzero element together with its row and
+  column index. It is the only format in which elements can be added, so matrices are assembled
+  in this format with [`set`](SpMatrixF64::set).
+* the compressed sparse column (CSC) format stores the elements column by column with an array of
+  column pointers.
+* the compressed sparse row (CSR) format stores the elements row by row with an array of row
+  pointers.
+
+Once assembled, a COO matrix is converted to a compressed format with
+[`compress`](SpMatrixF64::compress), which is required by the sparse BLAS routines and the
+iterative solvers.
+
+```text
+let mut m = SpMatrixF64::new(5, 4).unwrap();
+m.set(0, 2, 3.1);
+m.set(1, 0, 4.6);
+let csc = m.compress(SpMatrixFormat::Csc).unwrap();
+for (i, j, x) in csc.iter() {
+    println!("A({}, {}) = {}", i, j, x);
+}
+```
+!*/
+
+use crate::paste::paste;
+use crate::{SpMatrixFormat, Value};
+use ffi::FFI;
+use std::marker::PhantomData;
+use std::os::raw::c_int;
+use types::{MatrixF32, MatrixF64, MatrixI32, MatrixU32};
+use types::{VectorF32, VectorF64, VectorI32, VectorU32};
+
+/// Iterator over the nonzero elements of a sparse matrix, yielding `(row, column, value)`.
+///
+/// COO matrices are iterated in storage order, CSC matrices column by column and CSR matrices
+/// row by row.
+pub struct SpMatrixIter<'a, T> {
+    format: SpMatrixFormat,
+    i: *const c_int,
+    p: *const c_int,
+    data: *const T,
+    nz: usize,
+    k: usize,
+    outer: usize,
+    phantom: PhantomData<&'a T>,
+}
+
+impl<'a, T: Copy> Iterator for SpMatrixIter<'a, T> {
+    type Item = (usize, usize, T);
+
+    fn next(&mut self) -> Option<(usize, usize, T)> {
+        if self.k >= self.nz {
+            return None;
+        }
+        let k = self.k;
+        self.k += 1;
+        unsafe {
+            let inner = *self.i.add(k) as usize;
+            let value = *self.data.add(k);
+            if self.format == SpMatrixFormat::Coo {
+                return Some((inner, *self.p.add(k) as usize, value));
+            }
+            while *self.p.add(self.outer + 1) as usize <= k {
+                self.outer += 1;
+            }
+            if self.format == SpMatrixFormat::Csc {
+                Some((inner, self.outer, value))
+            } else {
+                Some((self.outer, inner, value))
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let left = self.nz - self.k;
+        (left, Some(left))
+    }
+}
+
+macro_rules! gsl_spmatrix {
+    ($rust_name:ident, $name:ident, $rust_ty:ident, $mat_name:ident, $vec_name:ident) => (
+paste! {
+ffi_wrapper!($rust_name, *mut sys::$name, [<$name _free>]);
+
+impl $rust_name {
+    #[doc = "Creates a new " $rust_name " of size `n1`-by-`n2` in COO format."]
+    #[doc(alias = $name _alloc)]
+    pub fn new(n1: usize, n2: usize) -> Option<$rust_name> {
+        let tmp = unsafe { sys::[<$name _alloc>](n1, n2) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Self::wrap(tmp))
+        }
+    }
+
+    /// Creates a new sparse matrix of size `n1`-by-`n2` in the given `format`, with room for
+    /// `nzmax` nonzero elements. The storage grows automatically when more elements are added
+    /// to a COO matrix.
+    #[doc(alias = $name _alloc_nzmax)]
+    pub fn new_with_nzmax(
+        n1: usize,
+        n2: usize,
+        nzmax: usize,
+        format: SpMatrixFormat,
+    ) -> Option<$rust_name> {
+        let tmp = unsafe { sys::[<$name _alloc_nzmax>](n1, n2, nzmax, format.into()) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Self::wrap(tmp))
+        }
+    }
+
+    /// Returns the number of rows of the matrix.
+    pub fn size1(&self) -> usize {
+        unsafe { (*self.unwrap_shared()).size1 }
+    }
+
+    /// Returns the number of columns of the matrix.
+    pub fn size2(&self) -> usize {
+        unsafe { (*self.unwrap_shared()).size2 }
+    }
+
+    /// Returns the storage format of the matrix.
+    pub fn format(&self) -> SpMatrixFormat {
+        SpMatrixFormat::from(unsafe { (*self.unwrap_shared()).sptype })
+    }
+
+    /// This function reallocates the storage space of the matrix to `nzmax` elements. It is
+    /// called automatically when elements are added to a COO matrix which is full.
+    #[doc(alias = $name _realloc)]
+    pub fn realloc(&mut self, nzmax: usize) -> Value {
+        Value::from(unsafe { sys::[<$name _realloc>](nzmax, self.unwrap_unique()) })
+    }
+
+    /// This function returns the number of nonzero elements in the matrix.
+    #[doc(alias = $name _nnz)]
+    pub fn nnz(&self) -> usize {
+        unsafe { sys::[<$name _nnz>](self.unwrap_shared()) }
+    }
+
+    /// This function sets (or resets) all the elements of the matrix to zero. For CSC and CSR
+    /// matrices, the cached array of nonzero elements is cleared but the structure is kept.
+    #[doc(alias = $name _set_zero)]
+    pub fn set_zero(&mut self) -> Value {
+        Value::from(unsafe { sys::[<$name _set_zero>](self.unwrap_unique()) })
+    }
+
+    /// This function returns element (i,j) of the matrix. The matrix may be in any storage
+    /// format. Elements which are not stored are zero.
+    #[doc(alias = $name _get)]
+    pub fn get(&self, i: usize, j: usize) -> $rust_ty {
+        unsafe { sys::[<$name _get>](self.unwrap_shared(), i, j) }
+    }
+
+    /// This function sets element (i,j) of the matrix to `x`. The matrix must be in COO
+    /// format; if the element already exists, its value is replaced.
+    #[doc(alias = $name _set)]
+    pub fn set(&mut self, i: usize, j: usize, x: $rust_ty) -> Value {
+        Value::from(unsafe { sys::[<$name _set>](self.unwrap_unique(), i, j, x) })
+    }
+
+    /// This function returns a copy of the COO matrix converted to the given `format`.
+    #[doc(alias = $name _compress)]
+    pub fn compress(&self, format: SpMatrixFormat) -> Option<$rust_name> {
+        let tmp = unsafe { sys::[<$name _compress>](self.unwrap_shared(), format.into()) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Self::wrap(tmp))
+        }
+    }
+
+    /// This function copies the elements of `src` into the matrix. The two matrices must have
+    /// the same dimensions and storage format.
+    #[doc(alias = $name _memcpy)]
+    pub fn copy_from(&mut self, src: &$rust_name) -> Value {
+        Value::from(unsafe { sys::[<$name _memcpy>](self.unwrap_unique(), src.unwrap_shared()) })
+    }
+
+    /// This function returns true if the two matrices have the same dimensions, storage format
+    /// and elements.
+    #[doc(alias = $name _equal)]
+    pub fn equal(&self, other: &$rust_name) -> bool {
+        unsafe { sys::[<$name _equal>](self.unwrap_shared(), other.unwrap_shared()) == 1 }
+    }
+
+    /// Returns `(Value, min, max)`, the minimum and maximum stored elements of the matrix.
+    #[doc(alias = $name _minmax)]
+    pub fn minmax(&self) -> (Value, $rust_ty, $rust_ty) {
+        let mut min = 0 as _;
+        let mut max = 0 as _;
+        let ret = unsafe { sys::[<$name _minmax>](self.unwrap_shared(), &mut min, &mut max) };
+        (Value::from(ret), min, max)
+    }
+
+    /// Returns `(Value, imin, jmin)`, the indices of the minimum stored element of the matrix.
+    #[cfg(feature = "v2_6")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
+    #[doc(alias = $name _min_index)]
+    pub fn min_index(&self) -> (Value, usize, usize) {
+        let mut imin = 0;
+        let mut jmin = 0;
+        let ret = unsafe { sys::[<$name _min_index>](self.unwrap_shared(), &mut imin, &mut jmin) };
+        (Value::from(ret), imin, jmin)
+    }
+
+    /// This function multiplies all the elements of the matrix by the constant factor `x`.
+    #[doc(alias = $name _scale)]
+    pub fn scale(&mut self, x: $rust_ty) -> Value {
+        Value::from(unsafe { sys::[<$name _scale>](self.unwrap_unique(), x) })
+    }
+
+    /// This function scales the columns of the matrix by the elements of `x`, whose length must
+    /// be the number of columns.
+    #[cfg(feature = "v2_6")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
+    #[doc(alias = $name _scale_columns)]
+    pub fn scale_columns(&mut self, x: &$vec_name) -> Value {
+        Value::from(unsafe { sys::[<$name _scale_columns>](self.unwrap_unique(), x.unwrap_shared()) })
+    }
+
+    /// This function scales the rows of the matrix by the elements of `x`, whose length must be
+    /// the number of rows.
+    #[cfg(feature = "v2_6")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
+    #[doc(alias = $name _scale_rows)]
+    pub fn scale_rows(&mut self, x: &$vec_name) -> Value {
+        Value::from(unsafe { sys::[<$name _scale_rows>](self.unwrap_unique(), x.unwrap_shared()) })
+    }
+
+    /// This function computes the sum `self = a + b`. The three matrices must have the same
+    /// dimensions and be stored in the same compressed format.
+    #[doc(alias = $name _add)]
+    pub fn add(&mut self, a: &$rust_name, b: &$rust_name) -> Value {
+        Value::from(unsafe {
+            sys::[<$name _add>](self.unwrap_unique(), a.unwrap_shared(), b.unwrap_shared())
+        })
+    }
+
+    /// This function adds the sparse matrix to the dense matrix `a`, computing `a = a + self`.
+    #[doc(alias = $name _dense_add)]
+    pub fn dense_add(&self, a: &mut $mat_name) -> Value {
+        Value::from(unsafe { sys::[<$name _dense_add>](a.unwrap_unique(), self.unwrap_shared()) })
+    }
+
+    /// This function subtracts the sparse matrix from the dense matrix `a`, computing
+    /// `a = a - self`.
+    #[cfg(feature = "v2_7")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
+    #[doc(alias = $name _dense_sub)]
+    pub fn dense_sub(&self, a: &mut $mat_name) -> Value {
+        Value::from(unsafe { sys::[<$name _dense_sub>](a.unwrap_unique(), self.unwrap_shared()) })
+    }
+
+    /// This function converts the dense matrix `a` into the sparse matrix, which must be in
+    /// COO format and have the same dimensions. Only the nonzero elements of `a` are stored.
+    #[doc(alias = $name _d2sp)]
+    pub fn d2sp(&mut self, a: &$mat_name) -> Value {
+        Value::from(unsafe { sys::[<$name _d2sp>](self.unwrap_unique(), a.unwrap_shared()) })
+    }
+
+    /// This function converts the sparse matrix into the dense matrix `a`, which must have the
+    /// same dimensions.
+    #[doc(alias = $name _sp2d)]
+    pub fn sp2d(&self, a: &mut $mat_name) -> Value {
+        Value::from(unsafe { sys::[<$name _sp2d>](a.unwrap_unique(), self.unwrap_shared()) })
+    }
+
+    /// This function returns the 1-norm of the matrix, the maximum absolute column sum.
+    #[cfg(feature = "v2_7")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
+    #[doc(alias = $name _norm1)]
+    pub fn norm1(&self) -> $rust_ty {
+        unsafe { sys::[<$name _norm1>](self.unwrap_shared()) }
+    }
+
+    /// This function replaces the matrix by its transpose in place. COO matrices are transposed
+    /// by swapping the row and column indices, while CSC matrices become CSR matrices and vice
+    /// versa.
+    #[doc(alias = $name _transpose)]
+    pub fn transpose(&mut self) -> Value {
+        Value::from(unsafe { sys::[<$name _transpose>](self.unwrap_unique()) })
+    }
+
+    /// This function returns the transpose of the matrix as a new matrix in the same storage
+    /// format.
+    #[doc(alias = $name _transpose_memcpy)]
+    pub fn transpose_memcpy(&self) -> Option<$rust_name> {
+        let mut dest = $rust_name::new_with_nzmax(
+            self.size2(),
+            self.size1(),
+            ::std::cmp::max(self.nnz(), 1),
+            self.format(),
+        )?;
+        let ret = unsafe {
+            sys::[<$name _transpose_memcpy>](dest.unwrap_unique(), self.unwrap_shared())
+        };
+        if Value::from(ret) == Value::Success {
+            Some(dest)
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over the stored elements of the matrix as `(row, column, value)`.
+    pub fn iter(&self) -> SpMatrixIter<'_, $rust_ty> {
+        let ptr = self.unwrap_shared();
+        unsafe {
+            SpMatrixIter {
+                format: self.format(),
+                i: (*ptr).i,
+                p: (*ptr).p,
+                data: (*ptr).data,
+                nz: self.nnz(),
+                k: 0,
+                outer: 0,
+                phantom: PhantomData,
+            }
+        }
+    }
+}
+} // end of paste! block
+    ); // end of the gsl_spmatrix macro
+}
+
+gsl_spmatrix!(SpMatrixF32, gsl_spmatrix_float, f32, MatrixF32, VectorF32);
+gsl_spmatrix!(SpMatrixF64, gsl_spmatrix, f64, MatrixF64, VectorF64);
+gsl_spmatrix!(SpMatrixI32, gsl_spmatrix_int, i32, MatrixI32, VectorI32);
+gsl_spmatrix!(SpMatrixU32, gsl_spmatrix_uint, u32, MatrixU32, VectorU32);
+
+#[cfg(any(test, doctest))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spmatrix() {
+        let mut m = SpMatrixF64::new(5, 4).unwrap();
+        m.set(0, 2, 3.1);
+        m.set(0, 3, 4.6);
+        m.set(1, 0, 1.0);
+        m.set(1, 2, 7.2);
+        m.set(3, 0, 2.1);
+        m.set(3, 1, 2.9);
+        m.set(3, 3, 8.5);
+        m.set(4, 0, 4.1);
+        assert_eq!(m.nnz(), 8);
+        assert_eq!(m.format(), SpMatrixFormat::Coo);
+        assert_eq!(m.get(3, 3), 8.5);
+        assert_eq!(m.get(2, 2), 0.);
+        assert_eq!(m.minmax(), (Value::Success, 1.0, 8.5));
+
+        let csc = m.compress(SpMatrixFormat::Csc).unwrap();
+        let csr = m.compress(SpMatrixFormat::Csr).unwrap();
+        let mut elements: Vec<_> = csc.iter().collect();
+        assert_eq!(elements.len(), 8);
+        assert_eq!(elements[0], (1, 0, 1.0));
+        elements.sort_by_key(|a| (a.0, a.1));
+        assert_eq!(elements, csr.iter().collect::<Vec<_>>());
+        for &(i, j, x) in elements.iter() {
+            assert_eq!(m.get(i, j), x);
+        }
+
+        let mut dense = MatrixF64::new(5, 4).unwrap();
+        assert_eq!(csr.sp2d(&mut dense), Value::Success);
+        assert_eq!(dense.get(0, 3), 4.6);
+        let mut back = SpMatrixF64::new(5, 4).unwrap();
+        assert_eq!(back.d2sp(&dense), Value::Success);
+        assert_eq!(back.nnz(), 8);
+
+        let t = csc.transpose_memcpy().unwrap();
+        assert_eq!((t.size1(), t.size2()), (4, 5));
+        assert_eq!(t.get(2, 0), 3.1);
+
+        let mut sum = SpMatrixF64::new_with_nzmax(5, 4, 1, SpMatrixFormat::Csc).unwrap();
+        assert_eq!(sum.add(&csc, &csc), Value::Success);
+        assert_eq!(sum.get(3, 1), 5.8);
+        assert_eq!(sum.scale(0.5), Value::Success);
+        assert!(sum.equal(&csc));
+    }
+}